@@ -45,6 +45,19 @@ fn rand_dt() -> (i32, u8, u8, u8, u8, u8) {
     (y, m, d, hh, mm, ss)
 }
 
+fn rand_millis() -> i64 {
+    fastrand::i64(datealgo::RD_SECONDS_MIN * 1000..=datealgo::RD_SECONDS_MAX * 1000 + 999)
+}
+
+fn rand_nanos() -> i128 {
+    fastrand::i128(datealgo::RD_SECONDS_MIN as i128 * 1_000_000_000..=datealgo::RD_SECONDS_MAX as i128 * 1_000_000_000 + 999_999_999)
+}
+
+fn rand_dt_nanos() -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (y, m, d, hh, mm, ss) = rand_dt();
+    (y, m, d, hh, mm, ss, fastrand::u32(0..=999_999_999))
+}
+
 fn rand_ym() -> (i32, u8) {
     let y = rand_year();
     let m = fastrand::u8(1..=12);
@@ -67,6 +80,34 @@ fn rand_dtn() -> (i32, u8, u8, u8, u8, u8, u32) {
     (y, m, d, hh, mm, ss, n)
 }
 
+fn rand_julian_rd() -> i32 {
+    fastrand::i32(datealgo::JULIAN_RD_MIN..=datealgo::JULIAN_RD_MAX)
+}
+
+fn rand_julian_date() -> (i32, u8, u8) {
+    datealgo::rd_to_julian_date(rand_julian_rd())
+}
+
+fn rand_ordinal() -> (i32, u16) {
+    datealgo::rd_to_ordinal(rand_rd())
+}
+
+fn rand_rd_weekday() -> (i32, u8) {
+    (fastrand::i32(datealgo::RD_MIN + 7..=datealgo::RD_MAX - 7), fastrand::u8(1..=7))
+}
+
+fn rand_ym_weekday() -> (i32, u8, u8) {
+    (rand_year(), fastrand::u8(1..=12), fastrand::u8(1..=7))
+}
+
+fn rand_date_months() -> ((i32, u8, u8), i32) {
+    (rand_date(), fastrand::i32(-1200..=1200))
+}
+
+fn rand_date_pair() -> ((i32, u8, u8), (i32, u8, u8)) {
+    (rand_date(), rand_date())
+}
+
 fn rand_iwd() -> (i32, u8, u8) {
     datealgo::rd_to_isoweekdate(rand_rd())
 }
@@ -87,12 +128,67 @@ fn bench_basic(c: &mut Criterion) {
     c.bench_function("date_to_weekday", |b| {
         b.iter_custom(bencher(rand_date, |d| datealgo::date_to_weekday(black_box(d))))
     });
+    c.bench_function("weekday_on_or_after", |b| {
+        b.iter_custom(bencher(rand_rd_weekday, |(rd, wd)| {
+            datealgo::weekday_on_or_after(black_box(rd), black_box(wd))
+        }))
+    });
+    c.bench_function("weekday_on_or_before", |b| {
+        b.iter_custom(bencher(rand_rd_weekday, |(rd, wd)| {
+            datealgo::weekday_on_or_before(black_box(rd), black_box(wd))
+        }))
+    });
+    c.bench_function("next_weekday", |b| {
+        b.iter_custom(bencher(rand_rd_weekday, |(rd, wd)| {
+            datealgo::next_weekday(black_box(rd), black_box(wd))
+        }))
+    });
+    c.bench_function("prev_weekday", |b| {
+        b.iter_custom(bencher(rand_rd_weekday, |(rd, wd)| {
+            datealgo::prev_weekday(black_box(rd), black_box(wd))
+        }))
+    });
+    c.bench_function("nth_weekday_of_month", |b| {
+        b.iter_custom(bencher(rand_ym_weekday, |(y, m, wd)| {
+            datealgo::nth_weekday_of_month(black_box(y), black_box(m), black_box(wd), black_box(3))
+        }))
+    });
+    c.bench_function("last_weekday_of_month", |b| {
+        b.iter_custom(bencher(rand_ym_weekday, |(y, m, wd)| {
+            datealgo::last_weekday_of_month(black_box(y), black_box(m), black_box(wd))
+        }))
+    });
     c.bench_function("next_date", |b| {
         b.iter_custom(bencher(rand_date, |d| datealgo::next_date(black_box(d))))
     });
     c.bench_function("prev_date", |b| {
         b.iter_custom(bencher(rand_date, |d| datealgo::prev_date(black_box(d))))
     });
+    c.bench_function("add_months", |b| {
+        b.iter_custom(bencher(rand_date_months, |(d, n)| {
+            datealgo::add_months(black_box(d), black_box(n), datealgo::DayOverflow::Clamp)
+        }))
+    });
+    c.bench_function("add_years", |b| {
+        b.iter_custom(bencher(rand_date_months, |(d, n)| {
+            datealgo::add_years(black_box(d), black_box(n), datealgo::DayOverflow::Clamp)
+        }))
+    });
+    c.bench_function("months_between", |b| {
+        b.iter_custom(bencher(rand_date_pair, |(x, y)| {
+            datealgo::months_between(black_box(x), black_box(y))
+        }))
+    });
+    c.bench_function("whole_years_between", |b| {
+        b.iter_custom(bencher(rand_date_pair, |(x, y)| {
+            datealgo::whole_years_between(black_box(x), black_box(y))
+        }))
+    });
+    c.bench_function("date_diff_ymd", |b| {
+        b.iter_custom(bencher(rand_date_pair, |(x, y)| {
+            datealgo::date_diff_ymd(black_box(x), black_box(y))
+        }))
+    });
     c.bench_function("secs_to_dhms", |b| {
         b.iter_custom(bencher(rand_secs, |s| datealgo::secs_to_dhms(black_box(s))))
     });
@@ -105,6 +201,18 @@ fn bench_basic(c: &mut Criterion) {
     c.bench_function("datetime_to_secs", |b| {
         b.iter_custom(bencher(rand_dt, |dt| datealgo::datetime_to_secs(black_box(dt))))
     });
+    c.bench_function("millis_to_datetime", |b| {
+        b.iter_custom(bencher(rand_millis, |ms| datealgo::millis_to_datetime(black_box(ms))))
+    });
+    c.bench_function("datetime_to_millis", |b| {
+        b.iter_custom(bencher(rand_dt_nanos, |dt| datealgo::datetime_to_millis(black_box(dt))))
+    });
+    c.bench_function("nanos_to_datetime", |b| {
+        b.iter_custom(bencher(rand_nanos, |ns| datealgo::nanos_to_datetime(black_box(ns))))
+    });
+    c.bench_function("datetime_to_nanos", |b| {
+        b.iter_custom(bencher(rand_dt_nanos, |dt| datealgo::datetime_to_nanos(black_box(dt))))
+    });
     c.bench_function("is_leap_year", |b| {
         b.iter_custom(bencher(rand_year, |y| datealgo::is_leap_year(black_box(y))))
     });
@@ -126,6 +234,38 @@ fn bench_basic(c: &mut Criterion) {
     c.bench_function("isoweeks_in_year", |b| {
         b.iter_custom(bencher(rand_year, |y| datealgo::isoweeks_in_year(black_box(y))))
     });
+    c.bench_function("days_in_year", |b| {
+        b.iter_custom(bencher(rand_year, |y| datealgo::days_in_year(black_box(y))))
+    });
+    c.bench_function("rd_to_ordinal", |b| {
+        b.iter_custom(bencher(rand_rd, |rd| datealgo::rd_to_ordinal(black_box(rd))))
+    });
+    c.bench_function("ordinal_to_rd", |b| {
+        b.iter_custom(bencher(rand_ordinal, |o| datealgo::ordinal_to_rd(black_box(o))))
+    });
+    c.bench_function("date_to_ordinal", |b| {
+        b.iter_custom(bencher(rand_date, |d| datealgo::date_to_ordinal(black_box(d))))
+    });
+    c.bench_function("ordinal_to_date", |b| {
+        b.iter_custom(bencher(rand_ordinal, |o| datealgo::ordinal_to_date(black_box(o))))
+    });
+    c.bench_function("rd_to_julian_date", |b| {
+        b.iter_custom(bencher(rand_julian_rd, |rd| datealgo::rd_to_julian_date(black_box(rd))))
+    });
+    c.bench_function("julian_date_to_rd", |b| {
+        b.iter_custom(bencher(rand_julian_date, |d| datealgo::julian_date_to_rd(black_box(d))))
+    });
+    c.bench_function("rd_to_historical_date", |b| {
+        b.iter_custom(bencher(rand_julian_rd, |rd| {
+            datealgo::rd_to_historical_date(black_box(rd), datealgo::reform::GREGORIAN)
+        }))
+    });
+    c.bench_function("historical_date_to_rd", |b| {
+        b.iter_custom(bencher(
+            || datealgo::rd_to_historical_date(rand_julian_rd(), datealgo::reform::GREGORIAN),
+            |d| datealgo::historical_date_to_rd(black_box(d), datealgo::reform::GREGORIAN),
+        ))
+    });
     c.bench_function("systemtime_to_secs", |b| {
         b.iter_custom(bencher(rand_st, |st| datealgo::systemtime_to_secs(black_box(st))))
     });
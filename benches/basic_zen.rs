@@ -70,6 +70,19 @@ fn rand_dt() -> (i32, u8, u8, u8, u8, u8) {
     (y, m, d, hh, mm, ss)
 }
 
+fn rand_millis() -> i64 {
+    fastrand::i64(datealgo::RD_SECONDS_MIN * 1000..=datealgo::RD_SECONDS_MAX * 1000 + 999)
+}
+
+fn rand_nanos() -> i128 {
+    fastrand::i128(datealgo::RD_SECONDS_MIN as i128 * 1_000_000_000..=datealgo::RD_SECONDS_MAX as i128 * 1_000_000_000 + 999_999_999)
+}
+
+fn rand_dt_nanos() -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (y, m, d, hh, mm, ss) = rand_dt();
+    (y, m, d, hh, mm, ss, fastrand::u32(0..=999_999_999))
+}
+
 fn rand_ym() -> (i32, u8) {
     let y = rand_year();
     let m = fastrand::u8(1..=12);
@@ -92,6 +105,34 @@ fn rand_dtn() -> (i32, u8, u8, u8, u8, u8, u32) {
     (y, m, d, hh, mm, ss, n)
 }
 
+fn rand_julian_rd() -> i32 {
+    fastrand::i32(datealgo::JULIAN_RD_MIN..=datealgo::JULIAN_RD_MAX)
+}
+
+fn rand_julian_date() -> (i32, u8, u8) {
+    datealgo::rd_to_julian_date(rand_julian_rd())
+}
+
+fn rand_ordinal() -> (i32, u16) {
+    datealgo::rd_to_ordinal(rand_rd())
+}
+
+fn rand_rd_weekday() -> (i32, u8) {
+    (fastrand::i32(datealgo::RD_MIN + 7..=datealgo::RD_MAX - 7), fastrand::u8(1..=7))
+}
+
+fn rand_ym_weekday() -> (i32, u8, u8) {
+    (rand_year(), fastrand::u8(1..=12), fastrand::u8(1..=7))
+}
+
+fn rand_date_months() -> ((i32, u8, u8), i32) {
+    (rand_date(), fastrand::i32(-1200..=1200))
+}
+
+fn rand_date_pair() -> ((i32, u8, u8), (i32, u8, u8)) {
+    (rand_date(), rand_date())
+}
+
 fn rand_iwd() -> (i32, u8, u8) {
     datealgo::rd_to_isoweekdate(rand_rd())
 }
@@ -122,6 +163,44 @@ fn bench_basic(suite: &mut Suite) {
         let inputs = seeded_inputs(rand_date);
         bench_from_inputs(group, "date_to_weekday", inputs, datealgo::date_to_weekday);
     });
+    suite.group("weekday_on_or_after", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_rd_weekday);
+        bench_from_inputs(group, "weekday_on_or_after", inputs, |(rd, wd)| {
+            datealgo::weekday_on_or_after(rd, wd)
+        });
+    });
+    suite.group("weekday_on_or_before", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_rd_weekday);
+        bench_from_inputs(group, "weekday_on_or_before", inputs, |(rd, wd)| {
+            datealgo::weekday_on_or_before(rd, wd)
+        });
+    });
+    suite.group("next_weekday", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_rd_weekday);
+        bench_from_inputs(group, "next_weekday", inputs, |(rd, wd)| datealgo::next_weekday(rd, wd));
+    });
+    suite.group("prev_weekday", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_rd_weekday);
+        bench_from_inputs(group, "prev_weekday", inputs, |(rd, wd)| datealgo::prev_weekday(rd, wd));
+    });
+    suite.group("nth_weekday_of_month", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_ym_weekday);
+        bench_from_inputs(group, "nth_weekday_of_month", inputs, |(y, m, wd)| {
+            datealgo::nth_weekday_of_month(y, m, wd, 3)
+        });
+    });
+    suite.group("last_weekday_of_month", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_ym_weekday);
+        bench_from_inputs(group, "last_weekday_of_month", inputs, |(y, m, wd)| {
+            datealgo::last_weekday_of_month(y, m, wd)
+        });
+    });
     suite.group("next_date", |group| {
         configure_basic_group(group);
         let inputs = seeded_inputs(rand_date);
@@ -132,6 +211,35 @@ fn bench_basic(suite: &mut Suite) {
         let inputs = seeded_inputs(rand_date);
         bench_from_inputs(group, "prev_date", inputs, datealgo::prev_date);
     });
+    suite.group("add_months", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date_months);
+        bench_from_inputs(group, "add_months", inputs, |(d, n)| {
+            datealgo::add_months(d, n, datealgo::DayOverflow::Clamp)
+        });
+    });
+    suite.group("add_years", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date_months);
+        bench_from_inputs(group, "add_years", inputs, |(d, n)| {
+            datealgo::add_years(d, n, datealgo::DayOverflow::Clamp)
+        });
+    });
+    suite.group("months_between", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date_pair);
+        bench_from_inputs(group, "months_between", inputs, |(a, b)| datealgo::months_between(a, b));
+    });
+    suite.group("whole_years_between", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date_pair);
+        bench_from_inputs(group, "whole_years_between", inputs, |(a, b)| datealgo::whole_years_between(a, b));
+    });
+    suite.group("date_diff_ymd", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date_pair);
+        bench_from_inputs(group, "date_diff_ymd", inputs, |(a, b)| datealgo::date_diff_ymd(a, b));
+    });
     suite.group("secs_to_dhms", |group| {
         configure_basic_group(group);
         let inputs = seeded_inputs(rand_secs);
@@ -152,6 +260,26 @@ fn bench_basic(suite: &mut Suite) {
         let inputs = seeded_inputs(rand_dt);
         bench_from_inputs(group, "datetime_to_secs", inputs, datealgo::datetime_to_secs);
     });
+    suite.group("millis_to_datetime", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_millis);
+        bench_from_inputs(group, "millis_to_datetime", inputs, datealgo::millis_to_datetime);
+    });
+    suite.group("datetime_to_millis", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_dt_nanos);
+        bench_from_inputs(group, "datetime_to_millis", inputs, datealgo::datetime_to_millis);
+    });
+    suite.group("nanos_to_datetime", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_nanos);
+        bench_from_inputs(group, "nanos_to_datetime", inputs, datealgo::nanos_to_datetime);
+    });
+    suite.group("datetime_to_nanos", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_dt_nanos);
+        bench_from_inputs(group, "datetime_to_nanos", inputs, datealgo::datetime_to_nanos);
+    });
     suite.group("is_leap_year", |group| {
         configure_basic_group(group);
         let inputs = seeded_inputs(rand_year);
@@ -189,6 +317,55 @@ fn bench_basic(suite: &mut Suite) {
         let inputs = seeded_inputs(rand_year);
         bench_from_inputs(group, "isoweeks_in_year", inputs, datealgo::isoweeks_in_year);
     });
+    suite.group("days_in_year", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_year);
+        bench_from_inputs(group, "days_in_year", inputs, datealgo::days_in_year);
+    });
+    suite.group("rd_to_ordinal", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_rd);
+        bench_from_inputs(group, "rd_to_ordinal", inputs, datealgo::rd_to_ordinal);
+    });
+    suite.group("ordinal_to_rd", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_ordinal);
+        bench_from_inputs(group, "ordinal_to_rd", inputs, datealgo::ordinal_to_rd);
+    });
+    suite.group("date_to_ordinal", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_date);
+        bench_from_inputs(group, "date_to_ordinal", inputs, datealgo::date_to_ordinal);
+    });
+    suite.group("ordinal_to_date", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_ordinal);
+        bench_from_inputs(group, "ordinal_to_date", inputs, datealgo::ordinal_to_date);
+    });
+    suite.group("rd_to_julian_date", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_julian_rd);
+        bench_from_inputs(group, "rd_to_julian_date", inputs, datealgo::rd_to_julian_date);
+    });
+    suite.group("julian_date_to_rd", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_julian_date);
+        bench_from_inputs(group, "julian_date_to_rd", inputs, datealgo::julian_date_to_rd);
+    });
+    suite.group("rd_to_historical_date", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(rand_julian_rd);
+        bench_from_inputs(group, "rd_to_historical_date", inputs, |rd| {
+            datealgo::rd_to_historical_date(rd, datealgo::reform::GREGORIAN)
+        });
+    });
+    suite.group("historical_date_to_rd", |group| {
+        configure_basic_group(group);
+        let inputs = seeded_inputs(|| datealgo::rd_to_historical_date(rand_julian_rd(), datealgo::reform::GREGORIAN));
+        bench_from_inputs(group, "historical_date_to_rd", inputs, |d| {
+            datealgo::historical_date_to_rd(d, datealgo::reform::GREGORIAN)
+        });
+    });
     suite.group("systemtime_to_secs", |group| {
         configure_basic_group(group);
         let inputs = seeded_inputs(rand_st);
@@ -235,6 +235,28 @@ fn bench_isoweekdate_to_date(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_rd_to_julian_date(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_rd_to_julian_date");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_rd, |rd| datealgo::rd_to_julian_date(black_box(rd))))
+    });
+    group.bench_function("jdn", |b| {
+        b.iter_custom(bencher(rand_rd, |rd| jdn::rd_to_julian_date(black_box(rd))))
+    });
+    group.finish();
+}
+
+fn bench_julian_date_to_rd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_julian_date_to_rd");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_date, |d| datealgo::julian_date_to_rd(black_box(d))))
+    });
+    group.bench_function("jdn", |b| {
+        b.iter_custom(bencher(rand_date, |d| jdn::julian_date_to_rd(black_box(d))))
+    });
+    group.finish();
+}
+
 fn bench_systemtime_to_secs(c: &mut Criterion) {
     let mut group = c.benchmark_group("compare_systemtime_to_secs");
     group.bench_function("datealgo", |b| {
@@ -294,6 +316,56 @@ fn bench_datetime_to_systemtime(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_format_rfc3339(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_format_rfc3339");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_dtn, |dtn| format_rfc3339(black_box(dtn))))
+    });
+    group.bench_function("time", |b| {
+        b.iter_custom(bencher(rand_dtn, |dtn| time::format_rfc3339(black_box(dtn))))
+    });
+    group.bench_function("chrono", |b| {
+        b.iter_custom(bencher(rand_dtn, |dtn| chrono::format_rfc3339(black_box(dtn))))
+    });
+    group.finish();
+}
+
+fn bench_parse_rfc3339(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_parse_rfc3339");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_rfc3339, |s| parse_rfc3339(black_box(s))))
+    });
+    group.bench_function("time", |b| {
+        b.iter_custom(bencher(rand_rfc3339, |s| time::parse_rfc3339(black_box(s))))
+    });
+    group.bench_function("chrono", |b| {
+        b.iter_custom(bencher(rand_rfc3339, |s| chrono::parse_rfc3339(black_box(s))))
+    });
+    group.finish();
+}
+
+fn bench_gregorian_easter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_gregorian_easter");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_year, |y| datealgo::easter::gregorian_easter(black_box(y))))
+    });
+    group.bench_function("meeus", |b| {
+        b.iter_custom(bencher(rand_year, |y| meeus::gregorian_easter(black_box(y))))
+    });
+    group.finish();
+}
+
+fn bench_orthodox_easter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare_orthodox_easter");
+    group.bench_function("datealgo", |b| {
+        b.iter_custom(bencher(rand_year, |y| datealgo::easter::orthodox_easter(black_box(y))))
+    });
+    group.bench_function("meeus", |b| {
+        b.iter_custom(bencher(rand_year, |y| meeus::orthodox_easter(black_box(y))))
+    });
+    group.finish();
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().sample_size(5000).measurement_time(Duration::from_secs(10));
@@ -312,9 +384,15 @@ criterion_group!(
         bench_days_in_month,
         bench_date_to_isoweekdate,
         bench_isoweekdate_to_date,
+        bench_rd_to_julian_date,
+        bench_julian_date_to_rd,
         bench_systemtime_to_secs,
         bench_secs_to_systemtime,
         bench_systemtime_to_datetime,
         bench_datetime_to_systemtime,
+        bench_format_rfc3339,
+        bench_parse_rfc3339,
+        bench_gregorian_easter,
+        bench_orthodox_easter,
 );
 criterion_main!(benches);
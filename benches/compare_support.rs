@@ -64,6 +64,23 @@ fn rand_iwd() -> (i32, u8, u8) {
     datealgo::rd_to_isoweekdate(rand_rd())
 }
 
+#[inline]
+fn format_rfc3339(dtn: (i32, u8, u8, u8, u8, u8, u32)) -> ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize) {
+    let mut out = [0u8; datealgo::iso8601::RFC3339_MAX_LEN];
+    let len = datealgo::iso8601::format_rfc3339(dtn, 9, None, &mut out).unwrap();
+    (out, len)
+}
+
+fn rand_rfc3339() -> ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize) {
+    format_rfc3339(rand_dtn())
+}
+
+#[allow(clippy::type_complexity)]
+#[inline]
+fn parse_rfc3339((buf, len): ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize)) -> ((i32, u8, u8, u8, u8, u8, u32), i32) {
+    datealgo::iso8601::parse_rfc3339(&buf[..len]).unwrap()
+}
+
 mod datealgo_alt {
     const YEAR_OFFSET: i32 = 3670 * 400;
     const DAY_OFFSET: i32 = 3670 * 146097 + 719468;
@@ -701,6 +718,38 @@ mod chrono {
             d.nanosecond(),
         )
     }
+
+    #[inline]
+    pub fn format_rfc3339((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize) {
+        use std::io::Write;
+        let dt = chrono::NaiveDate::from_ymd_opt(y, m as u32, d as u32)
+            .unwrap()
+            .and_hms_nano_opt(hh as u32, mm as u32, ss as u32, nsec)
+            .unwrap();
+        let mut out = [0u8; datealgo::iso8601::RFC3339_MAX_LEN];
+        let mut w = &mut out[..];
+        write!(w, "{}", dt.format("%Y-%m-%dT%H:%M:%S%.9fZ")).unwrap();
+        let len = datealgo::iso8601::RFC3339_MAX_LEN - w.len();
+        (out, len)
+    }
+
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn parse_rfc3339((buf, len): ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize)) -> ((i32, u8, u8, u8, u8, u8, u32), i32) {
+        let d = chrono::DateTime::parse_from_rfc3339(std::str::from_utf8(&buf[..len]).unwrap()).unwrap();
+        (
+            (
+                d.year(),
+                d.month() as u8,
+                d.day() as u8,
+                d.hour() as u8,
+                d.minute() as u8,
+                d.second() as u8,
+                d.nanosecond(),
+            ),
+            d.offset().local_minus_utc(),
+        )
+    }
 }
 
 mod fasttime {
@@ -809,6 +858,32 @@ mod time {
             d.nanosecond(),
         )
     }
+
+    #[inline]
+    pub fn format_rfc3339((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize) {
+        let dt = time::Date::from_calendar_date(y, time::Month::try_from(m).unwrap(), d)
+            .unwrap()
+            .with_hms_nano(hh, mm, ss, nsec)
+            .unwrap()
+            .assume_utc();
+        let mut out = [0u8; datealgo::iso8601::RFC3339_MAX_LEN];
+        let len = dt.format_into(&mut &mut out[..], &time::format_description::well_known::Rfc3339).unwrap();
+        (out, len)
+    }
+
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn parse_rfc3339((buf, len): ([u8; datealgo::iso8601::RFC3339_MAX_LEN], usize)) -> ((i32, u8, u8, u8, u8, u8, u32), i32) {
+        let d = time::OffsetDateTime::parse(
+            std::str::from_utf8(&buf[..len]).unwrap(),
+            &time::format_description::well_known::Rfc3339,
+        )
+        .unwrap();
+        (
+            (d.year(), d.month() as u8, d.day(), d.hour(), d.minute(), d.second(), d.nanosecond()),
+            d.offset().whole_seconds(),
+        )
+    }
 }
 
 mod hinnant {
@@ -856,3 +931,60 @@ mod hinnant {
         ((y + (m <= 2) as u32) as i32, m as u8, d as u8)
     }
 }
+
+mod meeus {
+    pub fn gregorian_easter(y: i32) -> (i32, u8, u8) {
+        let a = y % 19;
+        let b = y / 100;
+        let c = y % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+        (y, (n / 31) as u8, (n % 31 + 1) as u8)
+    }
+
+    pub fn orthodox_easter(y: i32) -> (i32, u8, u8) {
+        let a = y % 4;
+        let b = y % 7;
+        let c = y % 19;
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        // days of March, moved from the Julian to the Gregorian calendar
+        let n = d + e + 22 + y / 100 - y / 400 - 2;
+        if n > 61 {
+            (y, 5, (n - 61) as u8)
+        } else if n > 31 {
+            (y, 4, (n - 31) as u8)
+        } else {
+            (y, 3, n as u8)
+        }
+    }
+}
+
+mod jdn {
+    /// Unix epoch as a Julian Day Number
+    const UNIX_EPOCH_JDN: i32 = 2440588;
+
+    pub fn rd_to_julian_date(n: i32) -> (i32, u8, u8) {
+        let f = n + UNIX_EPOCH_JDN + 1401;
+        let e = 4 * f + 3;
+        let g = e % 1461 / 4;
+        let h = 5 * g + 2;
+        let d = h % 153 / 5 + 1;
+        let m = (h / 153 + 2) % 12 + 1;
+        let y = e / 1461 - 4716 + (14 - m) / 12;
+        (y, m as u8, d as u8)
+    }
+
+    pub fn julian_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+        let (m, d) = (m as i32, d as i32);
+        367 * y - 7 * (y + 5001 + (m - 9) / 7) / 4 + 275 * m / 9 + d + 1729777 - UNIX_EPOCH_JDN
+    }
+}
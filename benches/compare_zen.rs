@@ -129,6 +129,26 @@ fn bench_isoweekdate_to_date(suite: &mut Suite) {
     });
 }
 
+fn bench_rd_to_julian_date(suite: &mut Suite) {
+    suite.group("compare_rd_to_julian_date", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_rd);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), datealgo::rd_to_julian_date);
+        bench_from_inputs(group, "jdn", inputs, jdn::rd_to_julian_date);
+    });
+}
+
+fn bench_julian_date_to_rd(suite: &mut Suite) {
+    suite.group("compare_julian_date_to_rd", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_date);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), datealgo::julian_date_to_rd);
+        bench_from_inputs(group, "jdn", inputs, jdn::julian_date_to_rd);
+    });
+}
+
 fn bench_systemtime_to_datetime(suite: &mut Suite) {
     suite.group("compare_systemtime_to_datetime", |group| {
         configure_compare_group(group);
@@ -155,6 +175,48 @@ fn bench_datetime_to_systemtime(suite: &mut Suite) {
     });
 }
 
+fn bench_format_rfc3339(suite: &mut Suite) {
+    suite.group("compare_format_rfc3339", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_dtn);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), format_rfc3339);
+        bench_from_inputs(group, "time", Arc::clone(&inputs), time::format_rfc3339);
+        bench_from_inputs(group, "chrono", inputs, chrono::format_rfc3339);
+    });
+}
+
+fn bench_parse_rfc3339(suite: &mut Suite) {
+    suite.group("compare_parse_rfc3339", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_rfc3339);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), parse_rfc3339);
+        bench_from_inputs(group, "time", Arc::clone(&inputs), time::parse_rfc3339);
+        bench_from_inputs(group, "chrono", inputs, chrono::parse_rfc3339);
+    });
+}
+
+fn bench_gregorian_easter(suite: &mut Suite) {
+    suite.group("compare_gregorian_easter", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_year);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), datealgo::easter::gregorian_easter);
+        bench_from_inputs(group, "meeus", inputs, meeus::gregorian_easter);
+    });
+}
+
+fn bench_orthodox_easter(suite: &mut Suite) {
+    suite.group("compare_orthodox_easter", |group| {
+        configure_compare_group(group);
+
+        let inputs = seeded_inputs(rand_year);
+        bench_from_inputs(group, "datealgo", Arc::clone(&inputs), datealgo::easter::orthodox_easter);
+        bench_from_inputs(group, "meeus", inputs, meeus::orthodox_easter);
+    });
+}
+
 zenbench::main!(
     bench_rd_to_date,
     bench_date_to_rd,
@@ -162,6 +224,12 @@ zenbench::main!(
     bench_prev_date,
     bench_date_to_isoweekdate,
     bench_isoweekdate_to_date,
+    bench_rd_to_julian_date,
+    bench_julian_date_to_rd,
     bench_systemtime_to_datetime,
     bench_datetime_to_systemtime,
+    bench_format_rfc3339,
+    bench_parse_rfc3339,
+    bench_gregorian_easter,
+    bench_orthodox_easter,
 );
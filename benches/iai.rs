@@ -24,6 +24,36 @@ fn iai_date_to_weekday() -> u8 {
     datealgo::date_to_weekday(black_box((2023, 5, 12)))
 }
 
+#[library_benchmark]
+fn iai_weekday_on_or_after() -> i32 {
+    datealgo::weekday_on_or_after(black_box(19489), black_box(4))
+}
+
+#[library_benchmark]
+fn iai_weekday_on_or_before() -> i32 {
+    datealgo::weekday_on_or_before(black_box(19489), black_box(4))
+}
+
+#[library_benchmark]
+fn iai_next_weekday() -> i32 {
+    datealgo::next_weekday(black_box(19489), black_box(4))
+}
+
+#[library_benchmark]
+fn iai_prev_weekday() -> i32 {
+    datealgo::prev_weekday(black_box(19489), black_box(4))
+}
+
+#[library_benchmark]
+fn iai_nth_weekday_of_month() -> Option<u8> {
+    datealgo::nth_weekday_of_month(black_box(2023), black_box(5), black_box(5), black_box(3))
+}
+
+#[library_benchmark]
+fn iai_last_weekday_of_month() -> u8 {
+    datealgo::last_weekday_of_month(black_box(2023), black_box(5), black_box(1))
+}
+
 #[library_benchmark]
 fn iai_next_date() -> (i32, u8, u8) {
     datealgo::next_date(black_box((2023, 5, 12)))
@@ -34,6 +64,31 @@ fn iai_prev_date() -> (i32, u8, u8) {
     datealgo::prev_date(black_box((2023, 5, 12)))
 }
 
+#[library_benchmark]
+fn iai_add_months() -> Option<(i32, u8, u8)> {
+    datealgo::add_months(black_box((2023, 1, 31)), black_box(13), datealgo::DayOverflow::Clamp)
+}
+
+#[library_benchmark]
+fn iai_add_years() -> Option<(i32, u8, u8)> {
+    datealgo::add_years(black_box((2024, 2, 29)), black_box(1), datealgo::DayOverflow::Clamp)
+}
+
+#[library_benchmark]
+fn iai_months_between() -> i32 {
+    datealgo::months_between(black_box((1990, 5, 12)), black_box((2023, 7, 11)))
+}
+
+#[library_benchmark]
+fn iai_whole_years_between() -> i32 {
+    datealgo::whole_years_between(black_box((1990, 5, 12)), black_box((2023, 7, 11)))
+}
+
+#[library_benchmark]
+fn iai_date_diff_ymd() -> (i32, i8, i8) {
+    datealgo::date_diff_ymd(black_box((1990, 5, 12)), black_box((2023, 7, 11)))
+}
+
 #[library_benchmark]
 fn iai_secs_to_dhms() -> (i32, u8, u8, u8) {
     datealgo::secs_to_dhms(black_box(1684574678i64))
@@ -54,6 +109,26 @@ fn iai_datetime_to_secs() -> i64 {
     datealgo::datetime_to_secs(black_box((2023, 5, 20, 12, 34, 56)))
 }
 
+#[library_benchmark]
+fn iai_millis_to_datetime() -> (i32, u8, u8, u8, u8, u8, u32) {
+    datealgo::millis_to_datetime(black_box(1684574678123i64))
+}
+
+#[library_benchmark]
+fn iai_datetime_to_millis() -> i64 {
+    datealgo::datetime_to_millis(black_box((2023, 5, 20, 12, 34, 56, 123_456_789)))
+}
+
+#[library_benchmark]
+fn iai_nanos_to_datetime() -> (i32, u8, u8, u8, u8, u8, u32) {
+    datealgo::nanos_to_datetime(black_box(1684574678123456789i128))
+}
+
+#[library_benchmark]
+fn iai_datetime_to_nanos() -> i128 {
+    datealgo::datetime_to_nanos(black_box((2023, 5, 20, 12, 34, 56, 123_456_789)))
+}
+
 #[library_benchmark]
 fn iai_is_leap_year() -> bool {
     datealgo::is_leap_year(black_box(2000))
@@ -64,6 +139,41 @@ fn iai_days_in_month() -> u8 {
     datealgo::days_in_month(black_box(2000), black_box(2))
 }
 
+#[library_benchmark]
+fn iai_days_in_year() -> u16 {
+    datealgo::days_in_year(black_box(2000))
+}
+
+#[library_benchmark]
+fn iai_rd_to_ordinal() -> (i32, u16) {
+    datealgo::rd_to_ordinal(black_box(19489))
+}
+
+#[library_benchmark]
+fn iai_ordinal_to_rd() -> i32 {
+    datealgo::ordinal_to_rd(black_box((2023, 132)))
+}
+
+#[library_benchmark]
+fn iai_date_to_ordinal() -> (i32, u16) {
+    datealgo::date_to_ordinal(black_box((2023, 5, 12)))
+}
+
+#[library_benchmark]
+fn iai_ordinal_to_date() -> (i32, u8, u8) {
+    datealgo::ordinal_to_date(black_box((2023, 132)))
+}
+
+#[library_benchmark]
+fn iai_rd_to_julian_date() -> (i32, u8, u8) {
+    datealgo::rd_to_julian_date(black_box(19489))
+}
+
+#[library_benchmark]
+fn iai_julian_date_to_rd() -> i32 {
+    datealgo::julian_date_to_rd(black_box((2023, 4, 29)))
+}
+
 #[library_benchmark]
 fn iai_systemtime_to_secs() -> Option<(i64, u32)> {
     datealgo::systemtime_to_secs(black_box(UNIX_EPOCH + Duration::from_secs(1684574678)))
@@ -91,14 +201,36 @@ library_benchmark_group!(
         iai_date_to_rd,
         iai_rd_to_weekday,
         iai_date_to_weekday,
+        iai_weekday_on_or_after,
+        iai_weekday_on_or_before,
+        iai_next_weekday,
+        iai_prev_weekday,
+        iai_nth_weekday_of_month,
+        iai_last_weekday_of_month,
         iai_next_date,
         iai_prev_date,
+        iai_add_months,
+        iai_add_years,
+        iai_months_between,
+        iai_whole_years_between,
+        iai_date_diff_ymd,
         iai_secs_to_dhms,
         iai_dhms_to_secs,
         iai_secs_to_datetime,
         iai_datetime_to_secs,
+        iai_millis_to_datetime,
+        iai_datetime_to_millis,
+        iai_nanos_to_datetime,
+        iai_datetime_to_nanos,
         iai_is_leap_year,
         iai_days_in_month,
+        iai_days_in_year,
+        iai_rd_to_ordinal,
+        iai_ordinal_to_rd,
+        iai_date_to_ordinal,
+        iai_ordinal_to_date,
+        iai_rd_to_julian_date,
+        iai_julian_date_to_rd,
         iai_systemtime_to_secs,
         iai_secs_to_systemtime,
         iai_systemtime_to_datetime,
@@ -0,0 +1,284 @@
+//! ASN.1 time types
+//!
+//! ASN.1 has two types for points in time, `UTCTime` and `GeneralizedTime`,
+//! both of which are used in X.509 certificates for the `notBefore` and
+//! `notAfter` validity times. The functions in this module implement the
+//! Distinguished Encoding Rules (DER) of
+//! [X.690](https://www.itu.int/rec/T-REC-X.690) for them, which allow only one
+//! encoding for each time: UTC with a `Z` suffix, seconds always present, and
+//! for `GeneralizedTime` a fraction only when it is not zero, using a period
+//! and without trailing zeros. Anything else is rejected.
+//!
+//! `UTCTime` has a two digit year, which is interpreted as being between 1950
+//! and 2049 as required by [RFC 5280](https://www.rfc-editor.org/rfc/rfc5280#section-4.1.2.5).
+//! Certificates must use `UTCTime` for times in those years and
+//! `GeneralizedTime` without a fraction for times after.
+
+use crate::iso8601::{digits1, digits2, digits4, NOT_DIGITS};
+use crate::util::write_2;
+use crate::{datetime_to_secs, secs_to_datetime, validate_datetime, ParseError};
+
+/// Length of a `UTCTime` written by [format_utc_time]
+pub const UTC_TIME_LEN: usize = 13;
+
+/// Maximum length of a `GeneralizedTime` written by [format_generalized_time]
+pub const GENERALIZED_TIME_MAX_LEN: usize = 25;
+
+/// First second representable as `UTCTime`, `500101000000Z`
+pub const UTC_TIME_MIN: i64 = -631152000;
+
+/// Last second representable as `UTCTime`, `491231235959Z`
+pub const UTC_TIME_MAX: i64 = 2524607999;
+
+/// Write month, day, hours, minutes and seconds at the given position
+#[inline]
+const fn write_mdhms(out: &mut [u8], i: usize, (m, d, hh, mm, ss): (u8, u8, u8, u8, u8)) {
+    write_2(out, i, m);
+    write_2(out, i + 2, d);
+    write_2(out, i + 4, hh);
+    write_2(out, i + 6, mm);
+    write_2(out, i + 8, ss);
+}
+
+/// Parse month, day, hours, minutes and seconds at the given position
+#[inline]
+const fn parse_mdhms(s: &[u8], i: usize) -> Result<(u8, u8, u8, u8, u8), ParseError> {
+    let m = digits2(s, i);
+    let d = digits2(s, i + 2);
+    let hh = digits2(s, i + 4);
+    let mm = digits2(s, i + 6);
+    let ss = digits2(s, i + 8);
+    if (m | d | hh | mm | ss) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    Ok((m as u8, d as u8, hh as u8, mm as u8, ss as u8))
+}
+
+/// Validate a parsed date and time and convert it to seconds
+#[inline]
+const fn to_secs(y: i32, (m, d, hh, mm, ss): (u8, u8, u8, u8, u8)) -> Result<i64, ParseError> {
+    match validate_datetime((y, m, d, hh, mm, ss)) {
+        Ok(()) => Ok(datetime_to_secs((y, m, d, hh, mm, ss))),
+        Err(_) => Err(ParseError::OutOfRange),
+    }
+}
+
+/// Parse a DER `UTCTime`
+///
+/// Given the contents of a DER encoded `UTCTime`, such as `230507092438Z`,
+/// returns seconds counting from Unix epoch (January 1st, 1970). Two digit
+/// years from `50` to `99` are in the 1900s and from `00` to `49` in the
+/// 2000s.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not exactly
+/// `YYMMDDhhmmssZ`, such as when the seconds are missing or an offset is
+/// given instead of `Z`, and [ParseError::OutOfRange] if the date or time is
+/// not valid.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::asn1::parse_utc_time;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_utc_time(b"230507092438Z"), Ok(1683451478));
+/// assert_eq!(parse_utc_time(b"700101000000Z"), Ok(0));
+/// assert_eq!(parse_utc_time(b"2305070924Z"), Err(ParseError::InvalidFormat));
+/// assert_eq!(parse_utc_time(b"230507092438+0000"), Err(ParseError::InvalidFormat));
+/// assert_eq!(parse_utc_time(b"230229092438Z"), Err(ParseError::OutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are parsed two at a time using SIMD within a register, with the
+/// result validated by [validate_datetime] before converting with
+/// [datetime_to_secs].
+#[inline]
+pub const fn parse_utc_time(s: &[u8]) -> Result<i64, ParseError> {
+    if s.len() != UTC_TIME_LEN || s[12] != b'Z' {
+        return Err(ParseError::InvalidFormat);
+    }
+    let yy = digits2(s, 0);
+    let fields = match parse_mdhms(s, 2) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    if yy & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    let y = if yy < 50 { 2000 + yy as i32 } else { 1900 + yy as i32 };
+    to_secs(y, fields)
+}
+
+/// Format seconds as a DER `UTCTime`
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) writes the
+/// contents of a DER encoded `UTCTime`, such as `230507092438Z`, into the
+/// output buffer.
+///
+/// # Panics
+///
+/// Seconds must be between [UTC_TIME_MIN] and [UTC_TIME_MAX]. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::asn1::{format_utc_time, UTC_TIME_LEN};
+///
+/// let mut out = [0u8; UTC_TIME_LEN];
+/// format_utc_time(1683451478, &mut out);
+/// assert_eq!(&out, b"230507092438Z");
+/// ```
+///
+/// # Algorithm
+///
+/// Uses [secs_to_datetime], with digits written directly at fixed positions.
+#[inline]
+pub const fn format_utc_time(secs: i64, out: &mut [u8; UTC_TIME_LEN]) {
+    debug_assert!(secs >= UTC_TIME_MIN && secs <= UTC_TIME_MAX, "given seconds is out of range");
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    write_2(out, 0, (y % 100) as u8);
+    write_mdhms(out, 2, (m, d, hh, mm, ss));
+    out[12] = b'Z';
+}
+
+/// Parse a DER `GeneralizedTime`
+///
+/// Given the contents of a DER encoded `GeneralizedTime`, such as
+/// `20230507092438.5Z`, returns seconds counting from Unix epoch (January 1st,
+/// 1970) and nanoseconds.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not exactly
+/// `YYYYMMDDhhmmss[.f]Z`, such as when the seconds are missing, an offset is
+/// given instead of `Z`, or the fraction is empty, uses a comma or has
+/// trailing zeros. Returns [ParseError::OutOfRange] if the date or time is not
+/// valid, or the fraction has more than nine digits.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::asn1::parse_generalized_time;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_generalized_time(b"20230507092438Z"), Ok((1683451478, 0)));
+/// assert_eq!(parse_generalized_time(b"20230507092438.25Z"), Ok((1683451478, 250_000_000)));
+/// assert_eq!(parse_generalized_time(b"20230507092438.250Z"), Err(ParseError::InvalidFormat));
+/// assert_eq!(parse_generalized_time(b"20230507092438.0Z"), Err(ParseError::InvalidFormat));
+/// assert_eq!(parse_generalized_time(b"202305070924Z"), Err(ParseError::InvalidFormat));
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are parsed two or four at a time using SIMD within a register, with
+/// the result validated by [validate_datetime] before converting with
+/// [datetime_to_secs].
+#[inline]
+pub const fn parse_generalized_time(s: &[u8]) -> Result<(i64, u32), ParseError> {
+    let len = s.len();
+    if len < 15 || s[len - 1] != b'Z' || (len > 15 && s[14] != b'.') || len == 16 {
+        return Err(ParseError::InvalidFormat);
+    }
+    let y = digits4(s, 0);
+    let fields = match parse_mdhms(s, 4) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    if y & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    if len > GENERALIZED_TIME_MAX_LEN {
+        return Err(ParseError::OutOfRange);
+    }
+    let mut nsec = 0;
+    let mut i = 15;
+    while i < len - 1 {
+        let v = digits1(s, i);
+        if v & NOT_DIGITS != 0 {
+            return Err(ParseError::InvalidFormat);
+        }
+        nsec = nsec * 10 + v;
+        i += 1;
+    }
+    if len > 15 && s[len - 2] == b'0' {
+        return Err(ParseError::InvalidFormat);
+    }
+    if len > 15 {
+        let mut i = len - 16;
+        while i < 9 {
+            nsec *= 10;
+            i += 1;
+        }
+    }
+    match to_secs(y as i32, fields) {
+        Ok(secs) => Ok((secs, nsec)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Format seconds and nanoseconds as a DER `GeneralizedTime`
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and nanoseconds
+/// writes the contents of a DER encoded `GeneralizedTime`, such as
+/// `20230507092438.5Z`, into the output buffer and returns the number of bytes
+/// written. The fraction is written without trailing zeros, and left out if
+/// the nanoseconds are zero, as needed for certificates.
+///
+/// # Panics
+///
+/// Seconds must be between `-62167219200` and `253402300799`, which are the
+/// first and last seconds of years `0` and `9999`. Nanoseconds must be between
+/// `0` and `999_999_999`. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::asn1::{format_generalized_time, GENERALIZED_TIME_MAX_LEN};
+///
+/// let mut out = [0u8; GENERALIZED_TIME_MAX_LEN];
+/// let len = format_generalized_time((1683451478, 0), &mut out);
+/// assert_eq!(&out[..len], b"20230507092438Z");
+/// let len = format_generalized_time((1683451478, 250_000_000), &mut out);
+/// assert_eq!(&out[..len], b"20230507092438.25Z");
+/// ```
+///
+/// # Algorithm
+///
+/// Uses [secs_to_datetime], with digits written directly at fixed positions
+/// and the fraction written from the end.
+#[inline]
+pub const fn format_generalized_time((secs, nsec): (i64, u32), out: &mut [u8; GENERALIZED_TIME_MAX_LEN]) -> usize {
+    debug_assert!(secs >= -62167219200 && secs <= 253402300799, "given seconds is out of range");
+    debug_assert!(nsec <= 999_999_999, "given nanoseconds is out of range");
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    let y = y as u32;
+    write_2(out, 0, (y / 100) as u8);
+    write_2(out, 2, (y % 100) as u8);
+    write_mdhms(out, 4, (m, d, hh, mm, ss));
+    if nsec == 0 {
+        out[14] = b'Z';
+        return 15;
+    }
+    let mut f = nsec;
+    let mut digits = 9;
+    while f % 10 == 0 {
+        f /= 10;
+        digits -= 1;
+    }
+    out[14] = b'.';
+    let mut i = 14 + digits;
+    while i > 14 {
+        out[i] = b'0' + (f % 10) as u8;
+        f /= 10;
+        i -= 1;
+    }
+    out[15 + digits] = b'Z';
+    16 + digits
+}
@@ -0,0 +1,341 @@
+//! MS-DOS, FAT and ZIP timestamps
+//!
+//! MS-DOS packs a date and a time into two 16-bit words, which are still used
+//! by the FAT file systems and the ZIP file format:
+//!
+//! - Date: bits 15–9 are the year since 1980, bits 8–5 the month and bits 4–0
+//!   the day.
+//! - Time: bits 15–11 are the hour, bits 10–5 the minute and bits 4–0 the
+//!   second divided by two.
+//!
+//! This gives a range from 1980-01-01 to 2107-12-31 with a resolution of two
+//! seconds. The timestamps are in local time with no indication of the time
+//! zone. Words read from disk often contain invalid values, such as all zeros
+//! for a missing date, so decoding returns a [DateError] for them instead of
+//! panicking.
+//!
+//! ZIP files may contain more precise timestamps in extra fields. The NTFS
+//! extra field (`0x000a`) stores Windows FILETIME values and the extended
+//! timestamp extra field (`0x5455`) stores 32-bit Unix time, both in UTC.
+
+use crate::epoch::{filetime_to_secs, secs_to_filetime};
+use crate::{consts, datetime_to_secs, days_in_month, secs_to_datetime, DateError, NANOS_IN_SEC};
+
+/// Minimum year representable in a DOS date
+pub const DOS_YEAR_MIN: i32 = 1980;
+
+/// Maximum year representable in a DOS date
+pub const DOS_YEAR_MAX: i32 = 2107;
+
+/// Convert DOS date and time words to year, month, day, hours, minutes and
+/// seconds
+///
+/// Given a `(date, time)` tuple of DOS date and time words returns a `(year,
+/// month, day, hours, minutes, seconds)` tuple. Seconds are always even.
+///
+/// # Errors
+///
+/// Returns [DateError] describing the first invalid field if the month is not
+/// between `1` and `12`, the day is not between `1` and the number of days in
+/// the month, the hour is not between `0` and `23`, the minute is not between
+/// `0` and `59` or the seconds are not between `0` and `58`. A date word of
+/// zero, commonly used for a missing date, has month `0` and returns
+/// [DateError::MonthOutOfRange].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::dos_to_datetime;
+/// use datealgo::DateError;
+///
+/// assert_eq!(dos_to_datetime((0x0021, 0x0000)), Ok((1980, 1, 1, 0, 0, 0)));
+/// assert_eq!(dos_to_datetime((0x56a7, 0x4b1d)), Ok((2023, 5, 7, 9, 24, 58)));
+/// assert_eq!(dos_to_datetime((0x0000, 0x0000)), Err(DateError::MonthOutOfRange));
+/// assert_eq!(dos_to_datetime((0x005e, 0x0000)), Err(DateError::DayOutOfRange { max: 29 }));
+/// assert_eq!(dos_to_datetime((0x0021, 0x001e)), Err(DateError::SecondOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Bit field extraction followed by bounds checks on each field.
+#[inline]
+pub const fn dos_to_datetime((date, time): (u16, u16)) -> Result<(i32, u8, u8, u8, u8, u8), DateError> {
+    let y = (date >> 9) as i32 + DOS_YEAR_MIN;
+    let m = ((date >> 5) & 0xf) as u8;
+    let d = (date & 0x1f) as u8;
+    let hh = (time >> 11) as u8;
+    let mm = ((time >> 5) & 0x3f) as u8;
+    let ss = ((time & 0x1f) * 2) as u8;
+    if m < consts::MONTH_MIN || m > consts::MONTH_MAX {
+        return Err(DateError::MonthOutOfRange);
+    }
+    let max = days_in_month(y, m);
+    if d < consts::DAY_MIN || d > max {
+        return Err(DateError::DayOutOfRange { max });
+    }
+    if hh > consts::HOUR_MAX {
+        return Err(DateError::HourOutOfRange);
+    }
+    if mm > consts::MINUTE_MAX {
+        return Err(DateError::MinuteOutOfRange);
+    }
+    if ss > consts::SECOND_MAX {
+        return Err(DateError::SecondOutOfRange);
+    }
+    Ok((y, m, d, hh, mm, ss))
+}
+
+/// Convert year, month, day, hours, minutes and seconds to DOS date and time
+/// words
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns a
+/// `(date, time)` tuple of DOS date and time words. Odd seconds are rounded
+/// down, as done by MS-DOS and most ZIP tools.
+///
+/// # Panics
+///
+/// Year must be between [DOS_YEAR_MIN] and [DOS_YEAR_MAX]. Month must be
+/// between `1` and `12`. Day must be between `1` and the number of days in the
+/// month in question. Hours must be between `0` and `23`. Minutes must be
+/// between `0` and `59`. Seconds must be between `0` and `59`. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks. Use
+/// [checked_datetime_to_dos] for input that has not been validated.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::datetime_to_dos;
+///
+/// assert_eq!(datetime_to_dos((1980, 1, 1, 0, 0, 0)), (0x0021, 0x0000));
+/// assert_eq!(datetime_to_dos((2023, 5, 7, 9, 24, 58)), (0x56a7, 0x4b1d));
+/// assert_eq!(datetime_to_dos((2023, 5, 7, 9, 24, 59)), (0x56a7, 0x4b1d));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple shifts and bitwise or.
+#[inline]
+pub const fn datetime_to_dos((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> (u16, u16) {
+    debug_assert!(y >= DOS_YEAR_MIN && y <= DOS_YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+    let date = (((y - DOS_YEAR_MIN) as u16) << 9) | ((m as u16) << 5) | d as u16;
+    let time = ((hh as u16) << 11) | ((mm as u16) << 5) | (ss as u16 / 2);
+    (date, time)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to DOS date and time
+/// words, checking bounds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns a
+/// `(date, time)` tuple of DOS date and time words. This is the checked
+/// counterpart of [datetime_to_dos].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [DOS_YEAR_MIN] and [DOS_YEAR_MAX],
+/// month is not between `1` and `12`, day is not between `1` and the number of
+/// days in the month in question, hours is not between `0` and `23`, minutes is
+/// not between `0` and `59` or seconds is not between `0` and `59`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::checked_datetime_to_dos;
+///
+/// assert_eq!(checked_datetime_to_dos((2023, 5, 7, 9, 24, 58)), Some((0x56a7, 0x4b1d)));
+/// assert_eq!(checked_datetime_to_dos((1979, 12, 31, 23, 59, 59)), None);
+/// assert_eq!(checked_datetime_to_dos((2023, 2, 29, 0, 0, 0)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [datetime_to_dos].
+#[inline]
+pub const fn checked_datetime_to_dos((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> Option<(u16, u16)> {
+    if y >= DOS_YEAR_MIN && y <= DOS_YEAR_MAX && crate::validate_datetime((y, m, d, hh, mm, ss)).is_ok() {
+        Some(datetime_to_dos((y, m, d, hh, mm, ss)))
+    } else {
+        None
+    }
+}
+
+/// Convert DOS date and time words to seconds
+///
+/// Given a `(date, time)` tuple of DOS date and time words returns the seconds
+/// counting from Unix epoch (January 1st, 1970) for the same local date and
+/// time.
+///
+/// # Errors
+///
+/// Returns [DateError] describing the first invalid field, same as
+/// [dos_to_datetime].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::dos_to_secs;
+///
+/// assert_eq!(dos_to_secs((0x0021, 0x0000)), Ok(315532800));
+/// assert_eq!(dos_to_secs((0x56a7, 0x4b1d)), Ok(1683451498));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn dos_to_secs((date, time): (u16, u16)) -> Result<i64, DateError> {
+    match dos_to_datetime((date, time)) {
+        Ok(dt) => Ok(datetime_to_secs(dt)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Convert seconds to DOS date and time words
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns a
+/// `(date, time)` tuple of DOS date and time words for the same local date and
+/// time. Odd seconds are rounded down.
+///
+/// # Panics
+///
+/// The date must be between 1980-01-01 and 2107-12-31. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::secs_to_dos;
+///
+/// assert_eq!(secs_to_dos(315532800), (0x0021, 0x0000));
+/// assert_eq!(secs_to_dos(1683451499), (0x56a7, 0x4b1d));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn secs_to_dos(secs: i64) -> (u16, u16) {
+    datetime_to_dos(secs_to_datetime(secs))
+}
+
+/// Convert ZIP NTFS extra field timestamp to year, month, day, hours, minutes,
+/// seconds and nanoseconds
+///
+/// Given a modification, access or creation time from the NTFS extra field
+/// (`0x000a`) of a ZIP file, which is a Windows FILETIME, returns a `(year,
+/// month, day, hours, minutes, seconds, nanoseconds)` tuple in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::zip_ntfs_to_datetime;
+///
+/// assert_eq!(zip_ntfs_to_datetime(133279056781234567), (2023, 5, 7, 4, 1, 18, 123_456_700));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of [filetime_to_secs] and [secs_to_datetime].
+#[inline]
+pub const fn zip_ntfs_to_datetime(ft: u64) -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (secs, nsecs) = filetime_to_secs(ft);
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    (y, m, d, hh, mm, ss, nsecs)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to ZIP
+/// NTFS extra field timestamp
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple in
+/// UTC returns the Windows FILETIME to store in the NTFS extra field
+/// (`0x000a`) of a ZIP file. Nanoseconds are truncated to 100 nanosecond
+/// intervals.
+///
+/// # Panics
+///
+/// The date must be between 1601-01-01 and 60056-05-28, and each field must be
+/// within its normal range. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::datetime_to_zip_ntfs;
+///
+/// assert_eq!(datetime_to_zip_ntfs((2023, 5, 7, 4, 1, 18, 123_456_789)), 133279056781234567);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of [datetime_to_secs] and [secs_to_filetime].
+#[inline]
+pub const fn datetime_to_zip_ntfs((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> u64 {
+    debug_assert!(nsec < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    secs_to_filetime((datetime_to_secs((y, m, d, hh, mm, ss)), nsec))
+}
+
+/// Convert ZIP extended timestamp to year, month, day, hours, minutes and
+/// seconds
+///
+/// Given a modification, access or creation time from the extended timestamp
+/// extra field (`0x5455`) of a ZIP file, which is a signed 32-bit Unix time,
+/// returns a `(year, month, day, hours, minutes, seconds)` tuple in UTC. Some
+/// tools write the field as unsigned to extend the range past 2038, in which
+/// case the value should be converted with [secs_to_datetime] instead.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::zip_unix_to_datetime;
+///
+/// assert_eq!(zip_unix_to_datetime(1683432078), (2023, 5, 7, 4, 1, 18));
+/// assert_eq!(zip_unix_to_datetime(i32::MIN), (1901, 12, 13, 20, 45, 52));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn zip_unix_to_datetime(t: i32) -> (i32, u8, u8, u8, u8, u8) {
+    secs_to_datetime(t as i64)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to ZIP extended
+/// timestamp
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple in UTC returns
+/// the signed 32-bit Unix time to store in the extended timestamp extra field
+/// (`0x5455`) of a ZIP file.
+///
+/// # Panics
+///
+/// The time must be between 1901-12-13 20:45:52 and 2038-01-19 03:14:07, and
+/// each field must be within its normal range. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::dos::datetime_to_zip_unix;
+///
+/// assert_eq!(datetime_to_zip_unix((2023, 5, 7, 4, 1, 18)), 1683432078);
+/// assert_eq!(datetime_to_zip_unix((2038, 1, 19, 3, 14, 7)), i32::MAX);
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn datetime_to_zip_unix((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> i32 {
+    let secs = datetime_to_secs((y, m, d, hh, mm, ss));
+    debug_assert!(secs >= i32::MIN as i64 && secs <= i32::MAX as i64, "given datetime is out of range");
+    secs as i32
+}
@@ -0,0 +1,381 @@
+//! Easter computus and movable feasts
+//!
+//! Western churches celebrate Easter on the first Sunday after the
+//! ecclesiastical full moon on or after March 21st, computed in the Gregorian
+//! calendar. Eastern Orthodox churches use the same rule computed in the
+//! Julian calendar, which gives a different date in most years. Both are
+//! provided here, with the Orthodox Easter converted to a Gregorian date.
+//!
+//! The other movable feasts of the Western church year are a fixed number of
+//! days from Easter Sunday. The offsets are available as constants, for use
+//! with the Rata Die of either Easter, and the most common feasts also as
+//! functions:
+//!
+//! ```
+//! use datealgo::easter::{gregorian_easter_rd, orthodox_easter_rd, EASTER_MONDAY};
+//! use datealgo::rd_to_date;
+//!
+//! assert_eq!(rd_to_date(gregorian_easter_rd(2023) + EASTER_MONDAY), (2023, 4, 10));
+//! assert_eq!(rd_to_date(orthodox_easter_rd(2023) + EASTER_MONDAY), (2023, 4, 17));
+//! ```
+
+use crate::{date_to_rd, rd_to_date, JULIAN_YEAR_MAX, JULIAN_YEAR_MIN, YEAR_MAX, YEAR_MIN, YEAR_OFFSET};
+
+/// Days from Easter Sunday to Ash Wednesday
+pub const ASH_WEDNESDAY: i32 = -46;
+/// Days from Easter Sunday to Palm Sunday
+pub const PALM_SUNDAY: i32 = -7;
+/// Days from Easter Sunday to Maundy Thursday
+pub const MAUNDY_THURSDAY: i32 = -3;
+/// Days from Easter Sunday to Good Friday
+pub const GOOD_FRIDAY: i32 = -2;
+/// Days from Easter Sunday to Holy Saturday
+pub const HOLY_SATURDAY: i32 = -1;
+/// Days from Easter Sunday to Easter Monday
+pub const EASTER_MONDAY: i32 = 1;
+/// Days from Easter Sunday to Ascension Day
+pub const ASCENSION: i32 = 39;
+/// Days from Easter Sunday to Pentecost
+pub const PENTECOST: i32 = 49;
+/// Days from Easter Sunday to Whit Monday
+pub const WHIT_MONDAY: i32 = 50;
+/// Days from Easter Sunday to Corpus Christi
+pub const CORPUS_CHRISTI: i32 = 60;
+
+/// Length of the Gregorian Easter cycle in years, added to keep the year
+/// positive while leaving the result unchanged
+const GREGORIAN_CYCLE: i32 = 5_700_000;
+
+/// Multiple of the 532 year Julian Easter cycle, added to keep the year
+/// positive while leaving the result unchanged
+const JULIAN_CYCLES: i32 = 532 * 2760;
+
+/// Calculate Gregorian Easter Sunday as a day of March, continuing to April
+/// from `32`
+#[inline]
+const fn gregorian_march_day(y: i32) -> u32 {
+    let y = (y + GREGORIAN_CYCLE) as u32;
+    // golden number and century
+    let g = y % 19;
+    let c = y / 100 + 1;
+    // solar and lunar corrections
+    let x = 3 * c / 4 - 12;
+    let z = (8 * c + 5) / 25 - 5;
+    // epact, with a multiple of 30 added to keep it unsigned
+    let e = (11 * g + 31 + z + 60000 - x) % 30;
+    let e = e + ((e == 25 && g > 10) || e == 24) as u32;
+    // paschal full moon
+    let n = 44 - e;
+    let n = if n < 21 { n + 30 } else { n };
+    // following sunday
+    let d = 5 * y / 4 - x - 10;
+    n + 7 - (d + n) % 7
+}
+
+/// Calculate Julian Easter Sunday as a day of March in the Gregorian
+/// calendar, continuing to April from `32` and May from `62`, which may be
+/// outside of those months for years far from the present
+#[inline]
+const fn julian_march_day(y: i32) -> i32 {
+    // difference between the calendars from March onwards
+    let c = (y + YEAR_OFFSET) as u32 / 100;
+    let diff = (c - c / 4) as i32 - (YEAR_OFFSET / 100 - YEAR_OFFSET / 400) - 2;
+    let y = (y + JULIAN_CYCLES) as u32;
+    // paschal full moon
+    let d = (19 * (y % 19) + 15) % 30;
+    // following sunday
+    let e = (2 * (y % 4) + 4 * (y % 7) + 34 - d) % 7;
+    (22 + d + e) as i32 + diff
+}
+
+/// Calculate Western Easter Sunday
+///
+/// Given a year returns the `(year, month, day)` tuple of Easter Sunday as
+/// celebrated by the Western churches, computed in the Gregorian calendar.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::gregorian_easter;
+///
+/// assert_eq!(gregorian_easter(2023), (2023, 4, 9));
+/// assert_eq!(gregorian_easter(2024), (2024, 3, 31));
+/// assert_eq!(gregorian_easter(1818), (1818, 3, 22));
+/// assert_eq!(gregorian_easter(2038), (2038, 4, 25));
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm from Donald Knuth, "The Art of Computer Programming", Volume 1,
+/// section 1.3.2, exercise 14, which derives from the one by Aloysius Lilius
+/// and Christopher Clavius. The year is first moved forward by the 5,700,000
+/// year cycle of Gregorian Easter dates, which keeps all the arithmetic
+/// unsigned and exact for negative years, without changing the result.
+#[inline]
+pub const fn gregorian_easter(y: i32) -> (i32, u8, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let n = gregorian_march_day(y);
+    if n > 31 {
+        (y, 4, (n - 31) as u8)
+    } else {
+        (y, 3, n as u8)
+    }
+}
+
+/// Calculate Rata Die of Western Easter Sunday
+///
+/// Given a year returns the Rata Die of Easter Sunday as celebrated by the
+/// Western churches, computed in the Gregorian calendar.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::gregorian_easter_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(gregorian_easter_rd(2023), date_to_rd((2023, 4, 9)));
+/// assert_eq!(gregorian_easter_rd(1970), date_to_rd((1970, 3, 29)));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [gregorian_easter], with the day of March added to the Rata Die of
+/// March 1st given by [date_to_rd].
+#[inline]
+pub const fn gregorian_easter_rd(y: i32) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    date_to_rd((y, 3, 1)) + gregorian_march_day(y) as i32 - 1
+}
+
+/// Calculate Orthodox Easter Sunday
+///
+/// Given a year returns the `(year, month, day)` tuple of Easter Sunday as
+/// celebrated by the Eastern Orthodox churches, computed in the Julian
+/// calendar and given as a Gregorian date. Between the years 1900 and 2099 it
+/// is always between April 4th and May 8th.
+///
+/// As the Julian calendar drifts away from the Gregorian calendar by three
+/// days every four centuries, the result moves later in the Gregorian year
+/// over time. For years far in the future, the result is in a later Gregorian
+/// year than the one given, and for years far in the past in an earlier one.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::orthodox_easter;
+///
+/// assert_eq!(orthodox_easter(2023), (2023, 4, 16));
+/// assert_eq!(orthodox_easter(2024), (2024, 5, 5));
+/// assert_eq!(orthodox_easter(2025), (2025, 4, 20));
+/// ```
+///
+/// # Algorithm
+///
+/// Julian computus as given by Jean Meeus, "Astronomical Algorithms", with
+/// the year first moved forward by a multiple of the 532 year cycle of Julian
+/// Easter dates to keep the arithmetic unsigned. The difference between the
+/// calendars is then added, and if the result is not between March and May,
+/// it is converted with [orthodox_easter_rd] and [rd_to_date] instead.
+#[inline]
+pub const fn orthodox_easter(y: i32) -> (i32, u8, u8) {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    let n = julian_march_day(y);
+    if n > 92 || n < 1 {
+        rd_to_date(date_to_rd((y, 3, 1)) + n - 1)
+    } else if n > 61 {
+        (y, 5, (n - 61) as u8)
+    } else if n > 31 {
+        (y, 4, (n - 31) as u8)
+    } else {
+        (y, 3, n as u8)
+    }
+}
+
+/// Calculate Rata Die of Orthodox Easter Sunday
+///
+/// Given a year returns the Rata Die of Easter Sunday as celebrated by the
+/// Eastern Orthodox churches, computed in the Julian calendar.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::orthodox_easter_rd;
+/// use datealgo::{date_to_rd, julian_date_to_rd};
+///
+/// assert_eq!(orthodox_easter_rd(2023), date_to_rd((2023, 4, 16)));
+/// assert_eq!(orthodox_easter_rd(2023), julian_date_to_rd((2023, 4, 3)));
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [orthodox_easter], with the day of March added to the Rata Die of
+/// March 1st given by [date_to_rd].
+#[inline]
+pub const fn orthodox_easter_rd(y: i32) -> i32 {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    date_to_rd((y, 3, 1)) + julian_march_day(y) - 1
+}
+
+/// Calculate Rata Die of Ash Wednesday
+///
+/// Given a year returns the Rata Die of Ash Wednesday, the first day of Lent,
+/// 46 days before Western Easter Sunday.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::ash_wednesday_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(ash_wednesday_rd(2023), date_to_rd((2023, 2, 22)));
+/// ```
+///
+/// # Algorithm
+///
+/// [ASH_WEDNESDAY] added to [gregorian_easter_rd].
+#[inline]
+pub const fn ash_wednesday_rd(y: i32) -> i32 {
+    gregorian_easter_rd(y) + ASH_WEDNESDAY
+}
+
+/// Calculate Rata Die of Good Friday
+///
+/// Given a year returns the Rata Die of Good Friday, two days before Western
+/// Easter Sunday.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::good_friday_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(good_friday_rd(2023), date_to_rd((2023, 4, 7)));
+/// ```
+///
+/// # Algorithm
+///
+/// [GOOD_FRIDAY] added to [gregorian_easter_rd].
+#[inline]
+pub const fn good_friday_rd(y: i32) -> i32 {
+    gregorian_easter_rd(y) + GOOD_FRIDAY
+}
+
+/// Calculate Rata Die of Ascension Day
+///
+/// Given a year returns the Rata Die of Ascension Day, the Thursday 39 days
+/// after Western Easter Sunday.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::ascension_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(ascension_rd(2023), date_to_rd((2023, 5, 18)));
+/// ```
+///
+/// # Algorithm
+///
+/// [ASCENSION] added to [gregorian_easter_rd].
+#[inline]
+pub const fn ascension_rd(y: i32) -> i32 {
+    gregorian_easter_rd(y) + ASCENSION
+}
+
+/// Calculate Rata Die of Pentecost
+///
+/// Given a year returns the Rata Die of Pentecost, or Whit Sunday, the
+/// seventh Sunday after Western Easter Sunday.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::pentecost_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(pentecost_rd(2023), date_to_rd((2023, 5, 28)));
+/// ```
+///
+/// # Algorithm
+///
+/// [PENTECOST] added to [gregorian_easter_rd].
+#[inline]
+pub const fn pentecost_rd(y: i32) -> i32 {
+    gregorian_easter_rd(y) + PENTECOST
+}
+
+/// Calculate Rata Die of Corpus Christi
+///
+/// Given a year returns the Rata Die of Corpus Christi, the Thursday 60 days
+/// after Western Easter Sunday.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::easter::corpus_christi_rd;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(corpus_christi_rd(2023), date_to_rd((2023, 6, 8)));
+/// ```
+///
+/// # Algorithm
+///
+/// [CORPUS_CHRISTI] added to [gregorian_easter_rd].
+#[inline]
+pub const fn corpus_christi_rd(y: i32) -> i32 {
+    gregorian_easter_rd(y) + CORPUS_CHRISTI
+}
@@ -0,0 +1,597 @@
+//! Timestamps counted from other epochs
+//!
+//! Conversions between Unix time and the timestamp formats of various
+//! protocols, operating systems and applications, each of which counts time
+//! from its own epoch in its own unit. Unix time is represented as a `(seconds,
+//! nanoseconds)` tuple, same as in [systemtime_to_secs](crate::systemtime_to_secs),
+//! so it can be further converted with [secs_to_datetime](crate::secs_to_datetime)
+//! and [datetime_to_secs].
+//!
+//! None of these formats account for leap seconds. GPS time, which does, is
+//! available in the [gnss](crate::gnss) module.
+//!
+//! When converting to a format with a coarser resolution than nanoseconds, the
+//! nanoseconds are truncated, which floors the result also for times before
+//! the epoch. Floating point formats are rounded to the nearest nanosecond when
+//! converting back, with ties rounded to even.
+
+use crate::{datetime_to_secs, MICROS_IN_SEC, NANOS_IN_SEC, RD_SECONDS_MAX, RD_SECONDS_MIN, SECS_IN_DAY};
+
+/// Unix time of the NTP prime epoch, 1900-01-01 00:00:00 UTC
+pub const NTP_EPOCH: i64 = datetime_to_secs((1900, 1, 1, 0, 0, 0));
+
+/// Unix time of the Windows FILETIME epoch, 1601-01-01 00:00:00 UTC
+pub const FILETIME_EPOCH: i64 = datetime_to_secs((1601, 1, 1, 0, 0, 0));
+
+/// Unix time of the .NET `DateTime` epoch, 0001-01-01 00:00:00
+pub const DOTNET_EPOCH: i64 = datetime_to_secs((1, 1, 1, 0, 0, 0));
+
+/// Unix time of the Apple Cocoa epoch, 2001-01-01 00:00:00 UTC
+pub const COCOA_EPOCH: i64 = datetime_to_secs((2001, 1, 1, 0, 0, 0));
+
+/// Unix time of the classic Mac OS and HFS epoch, 1904-01-01 00:00:00
+pub const HFS_EPOCH: i64 = datetime_to_secs((1904, 1, 1, 0, 0, 0));
+
+/// Unix time of the PostgreSQL epoch, 2000-01-01 00:00:00 UTC
+pub const POSTGRES_EPOCH: i64 = datetime_to_secs((2000, 1, 1, 0, 0, 0));
+
+/// Unix time of the Excel 1900 date system epoch, 1899-12-30 00:00:00
+///
+/// Serial `0` in Excel is displayed as 1900-01-00, but as Excel considers
+/// 1900 to be a leap year, all serials from 1900-03-01 onwards count from
+/// 1899-12-30.
+pub const EXCEL_EPOCH: i64 = datetime_to_secs((1899, 12, 30, 0, 0, 0));
+
+/// Number of 100 nanosecond ticks in a second, used by FILETIME and .NET
+const TICKS_IN_SEC: u64 = 10_000_000;
+
+/// Number of nanoseconds in a tick
+const NANOS_IN_TICK: u32 = 100;
+
+/// Number of nanoseconds in a microsecond
+const NANOS_IN_MICRO: u32 = 1000;
+
+/// Convert a floating point count of units since an epoch to seconds and
+/// nanoseconds, rounding to the nearest nanosecond
+#[inline]
+const fn float_to_secs(value: f64, nanos_in_unit: u64, epoch: i64) -> (i64, u32) {
+    debug_assert!(value.is_finite(), "given value is not finite");
+    let whole = value as i64;
+    let whole = if whole as f64 > value { whole - 1 } else { whole };
+    // the scaled fraction is exact for binary fractions of up to 16 bits, which
+    // makes exact ties common, so they are rounded to even to stay unbiased
+    let scaled = (value - whole as f64) * nanos_in_unit as f64;
+    let nanos = scaled as u64;
+    let rem = scaled - nanos as f64;
+    let nanos = if rem > 0.5 || (rem == 0.5 && nanos % 2 == 1) {
+        nanos + 1
+    } else {
+        nanos
+    };
+    // rounding may carry over to the next unit
+    let whole = whole + (nanos / nanos_in_unit) as i64;
+    let nanos = nanos % nanos_in_unit;
+    let secs = epoch + whole * (nanos_in_unit / NANOS_IN_SEC) as i64 + (nanos / NANOS_IN_SEC) as i64;
+    debug_assert!(secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX, "given value is out of range");
+    (secs, (nanos % NANOS_IN_SEC) as u32)
+}
+
+/// Convert seconds and nanoseconds to a floating point count of units since an
+/// epoch
+#[inline]
+const fn secs_to_float((secs, nsecs): (i64, u32), nanos_in_unit: u64, epoch: i64) -> f64 {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(nsecs < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    let secs_in_unit = (nanos_in_unit / NANOS_IN_SEC) as i64;
+    let secs = secs - epoch;
+    let whole = secs.div_euclid(secs_in_unit);
+    let nanos = secs.rem_euclid(secs_in_unit) as u64 * NANOS_IN_SEC + nsecs as u64;
+    whole as f64 + nanos as f64 / nanos_in_unit as f64
+}
+
+/// Convert an NTP timestamp to seconds and nanoseconds
+///
+/// Given a 64-bit NTP timestamp, with seconds in the upper 32 bits and the
+/// binary fraction of a second in the lower 32 bits, and the NTP era it
+/// belongs to, returns seconds and nanoseconds counting from Unix epoch
+/// (January 1st, 1970). Era `0` started on 1900-01-01 and era `1` starts on
+/// 2036-02-07. The era is usually not transmitted and has to be inferred with
+/// [resolve_ntp_era].
+///
+/// # Panics
+///
+/// The result must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::ntp_to_secs;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(ntp_to_secs(0, 0), (datetime_to_secs((1900, 1, 1, 0, 0, 0)), 0));
+/// assert_eq!(ntp_to_secs(0x83aa7e80_80000000, 0), (0, 500_000_000));
+/// assert_eq!(ntp_to_secs(0, 1), (datetime_to_secs((2036, 2, 7, 6, 28, 16)), 0));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple shifts and addition. The fraction is truncated to nanoseconds.
+#[inline]
+pub const fn ntp_to_secs(ts: u64, era: i32) -> (i64, u32) {
+    let secs = ((era as i64) << 32) + (ts >> 32) as i64 + NTP_EPOCH;
+    debug_assert!(secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX, "given timestamp is out of range");
+    let nsecs = ((ts & 0xffff_ffff) * NANOS_IN_SEC) >> 32;
+    (secs, nsecs as u32)
+}
+
+/// Convert seconds and nanoseconds to an NTP timestamp
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns an `(era, timestamp)` tuple, where `timestamp` is a 64-bit NTP
+/// timestamp with seconds in the upper 32 bits and the binary fraction of a
+/// second in the lower 32 bits.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_ntp;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(secs_to_ntp((0, 500_000_000)), (0, 0x83aa7e80_80000000));
+/// assert_eq!(secs_to_ntp((datetime_to_secs((2036, 2, 7, 6, 28, 16)), 0)), (1, 0));
+/// assert_eq!(secs_to_ntp((datetime_to_secs((1899, 12, 31, 23, 59, 59)), 0)), (-1, 0xffffffff_00000000));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple shifts and addition. The fraction is rounded up, so that converting
+/// back with [ntp_to_secs] returns the original nanoseconds.
+#[inline]
+pub const fn secs_to_ntp((secs, nsecs): (i64, u32)) -> (i32, u64) {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(nsecs < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    let secs = secs - NTP_EPOCH;
+    let frac = ((nsecs as u64) << 32).div_ceil(NANOS_IN_SEC);
+    ((secs >> 32) as i32, ((secs as u64) << 32) | frac)
+}
+
+/// Resolve the NTP era of a timestamp against a reference time
+///
+/// Given a 64-bit NTP timestamp and a reference time in seconds counting from
+/// Unix epoch (January 1st, 1970) returns the NTP era that places the
+/// timestamp closest to the reference. The result is correct as long as the
+/// actual time is within 68 years of the reference, which is usually the
+/// current system time or the build date of the software.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::{resolve_ntp_era, ntp_to_secs};
+/// use datealgo::datetime_to_secs;
+///
+/// let reference = datetime_to_secs((2030, 1, 1, 0, 0, 0));
+/// assert_eq!(resolve_ntp_era(0xfff00000_00000000, reference), 0);
+/// assert_eq!(resolve_ntp_era(0x00100000_00000000, reference), 1);
+/// assert_eq!(ntp_to_secs(0x00100000_00000000, 1), (datetime_to_secs((2036, 2, 19, 9, 44, 32)), 0));
+/// ```
+///
+/// # Algorithm
+///
+/// The wrapping difference of the 32-bit seconds to the reference is
+/// interpreted as signed, as recommended by RFC 5905.
+#[inline]
+pub const fn resolve_ntp_era(ts: u64, reference: i64) -> i32 {
+    let reference = reference - NTP_EPOCH;
+    let diff = ((ts >> 32) as u32).wrapping_sub(reference as u32) as i32;
+    ((reference + diff as i64) >> 32) as i32
+}
+
+/// Convert Windows FILETIME to seconds and nanoseconds
+///
+/// Given a Windows
+/// [FILETIME](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime),
+/// the number of 100 nanosecond intervals since 1601-01-01, returns seconds
+/// and nanoseconds counting from Unix epoch (January 1st, 1970). All `u64`
+/// values are within the supported range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::filetime_to_secs;
+///
+/// assert_eq!(filetime_to_secs(0), (-11644473600, 0));
+/// assert_eq!(filetime_to_secs(116444736000000000), (0, 0));
+/// assert_eq!(filetime_to_secs(133279056781234567), (1683432078, 123_456_700));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple division and addition.
+#[inline]
+pub const fn filetime_to_secs(ft: u64) -> (i64, u32) {
+    let secs = (ft / TICKS_IN_SEC) as i64 + FILETIME_EPOCH;
+    let nsecs = (ft % TICKS_IN_SEC) as u32 * NANOS_IN_TICK;
+    (secs, nsecs)
+}
+
+/// Convert seconds and nanoseconds to Windows FILETIME
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the Windows FILETIME, the number of 100 nanosecond intervals since
+/// 1601-01-01.
+///
+/// # Panics
+///
+/// The result must fit in `u64`, which limits the date to between 1601-01-01
+/// and 60056-05-28. Nanoseconds must be between `0` and `999_999_999`. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_filetime;
+///
+/// assert_eq!(secs_to_filetime((-11644473600, 0)), 0);
+/// assert_eq!(secs_to_filetime((0, 0)), 116444736000000000);
+/// assert_eq!(secs_to_filetime((1683432078, 123_456_789)), 133279056781234567);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication and addition.
+#[inline]
+pub const fn secs_to_filetime((secs, nsecs): (i64, u32)) -> u64 {
+    debug_assert!(nsecs < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    let secs = secs - FILETIME_EPOCH;
+    let ticks = (nsecs / NANOS_IN_TICK) as u64;
+    debug_assert!(
+        secs >= 0 && (secs as u128 * TICKS_IN_SEC as u128 + ticks as u128) <= u64::MAX as u128,
+        "given seconds value is out of range"
+    );
+    (secs as u64).wrapping_mul(TICKS_IN_SEC).wrapping_add(ticks)
+}
+
+/// Convert .NET `DateTime.Ticks` to seconds and nanoseconds
+///
+/// Given the number of 100 nanosecond ticks since 0001-01-01, as returned by
+/// [`DateTime.Ticks`](https://learn.microsoft.com/en-us/dotnet/api/system.datetime.ticks),
+/// returns seconds and nanoseconds counting from Unix epoch (January 1st,
+/// 1970). `DateTime` itself does not store a time zone, so the result is in
+/// whatever time zone the `DateTime` was in. All `i64` values are within the
+/// supported range, even though `DateTime` only supports years up to `9999`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::ticks_to_secs;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(ticks_to_secs(0), (datetime_to_secs((1, 1, 1, 0, 0, 0)), 0));
+/// assert_eq!(ticks_to_secs(621355968000000000), (0, 0));
+/// assert_eq!(ticks_to_secs(3155378975999999999), (datetime_to_secs((9999, 12, 31, 23, 59, 59)), 999_999_900));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division and addition.
+#[inline]
+pub const fn ticks_to_secs(ticks: i64) -> (i64, u32) {
+    let secs = ticks.div_euclid(TICKS_IN_SEC as i64) + DOTNET_EPOCH;
+    let nsecs = ticks.rem_euclid(TICKS_IN_SEC as i64) as u32 * NANOS_IN_TICK;
+    (secs, nsecs)
+}
+
+/// Convert seconds and nanoseconds to .NET `DateTime.Ticks`
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the number of 100 nanosecond ticks since 0001-01-01, as used by
+/// `DateTime.Ticks`.
+///
+/// # Panics
+///
+/// The result must fit in `i64`, which limits the year to roughly ±29,000
+/// years from the epoch. Nanoseconds must be between `0` and `999_999_999`.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_ticks;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(secs_to_ticks((datetime_to_secs((1, 1, 1, 0, 0, 0)), 0)), 0);
+/// assert_eq!(secs_to_ticks((0, 0)), 621355968000000000);
+/// assert_eq!(secs_to_ticks((datetime_to_secs((9999, 12, 31, 23, 59, 59)), 999_999_999)), 3155378975999999999);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication and addition.
+#[inline]
+pub const fn secs_to_ticks((secs, nsecs): (i64, u32)) -> i64 {
+    debug_assert!(nsecs < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    let secs = secs - DOTNET_EPOCH;
+    let ticks = (nsecs / NANOS_IN_TICK) as i64;
+    let total = secs as i128 * TICKS_IN_SEC as i128 + ticks as i128;
+    debug_assert!(
+        total >= i64::MIN as i128 && total <= i64::MAX as i128,
+        "given seconds value is out of range"
+    );
+    secs.wrapping_mul(TICKS_IN_SEC as i64).wrapping_add(ticks)
+}
+
+/// Convert Apple Cocoa absolute time to seconds and nanoseconds
+///
+/// Given a `CFAbsoluteTime` or `NSDate.timeIntervalSinceReferenceDate`, the
+/// floating point seconds since 2001-01-01, returns seconds and nanoseconds
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be finite and the result must be between [RD_SECONDS_MIN] and
+/// [RD_SECONDS_MAX] inclusive. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::cocoa_to_secs;
+///
+/// assert_eq!(cocoa_to_secs(0.0), (978307200, 0));
+/// assert_eq!(cocoa_to_secs(-978307200.0), (0, 0));
+/// assert_eq!(cocoa_to_secs(705124878.25), (1683432078, 250_000_000));
+/// assert_eq!(cocoa_to_secs(-0.5), (978307199, 500_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// The fraction is rounded to the nearest nanosecond, with ties to even.
+#[inline]
+pub const fn cocoa_to_secs(t: f64) -> (i64, u32) {
+    float_to_secs(t, NANOS_IN_SEC, COCOA_EPOCH)
+}
+
+/// Convert seconds and nanoseconds to Apple Cocoa absolute time
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the floating point seconds since 2001-01-01, as used by
+/// `CFAbsoluteTime` and `NSDate`.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_cocoa;
+///
+/// assert_eq!(secs_to_cocoa((978307200, 0)), 0.0);
+/// assert_eq!(secs_to_cocoa((1683432078, 250_000_000)), 705124878.25);
+/// assert_eq!(secs_to_cocoa((978307199, 500_000_000)), -0.5);
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch followed by conversion to floating point.
+#[inline]
+pub const fn secs_to_cocoa((secs, nsecs): (i64, u32)) -> f64 {
+    secs_to_float((secs, nsecs), NANOS_IN_SEC, COCOA_EPOCH)
+}
+
+/// Convert classic Mac OS and HFS time to seconds
+///
+/// Given the unsigned 32-bit seconds since 1904-01-01, as used by classic Mac
+/// OS, HFS and HFS+ file systems and QuickTime/MP4 headers, returns seconds
+/// counting from Unix epoch (January 1st, 1970). Classic Mac OS and HFS store
+/// local time, while HFS+ and MP4 store UTC. All `u32` values are within the
+/// supported range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::hfs_to_secs;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(hfs_to_secs(0), datetime_to_secs((1904, 1, 1, 0, 0, 0)));
+/// assert_eq!(hfs_to_secs(2082844800), 0);
+/// assert_eq!(hfs_to_secs(u32::MAX), datetime_to_secs((2040, 2, 6, 6, 28, 15)));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition.
+#[inline]
+pub const fn hfs_to_secs(t: u32) -> i64 {
+    t as i64 + HFS_EPOCH
+}
+
+/// Convert seconds to classic Mac OS and HFS time
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns the
+/// unsigned 32-bit seconds since 1904-01-01.
+///
+/// # Panics
+///
+/// The result must fit in `u32`, which limits the time to between 1904-01-01
+/// 00:00:00 and 2040-02-06 06:28:15. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_hfs;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(secs_to_hfs(datetime_to_secs((1904, 1, 1, 0, 0, 0))), 0);
+/// assert_eq!(secs_to_hfs(0), 2082844800);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction.
+#[inline]
+pub const fn secs_to_hfs(secs: i64) -> u32 {
+    let t = secs - HFS_EPOCH;
+    debug_assert!(t >= 0 && t <= u32::MAX as i64, "given seconds value is out of range");
+    t as u32
+}
+
+/// Convert PostgreSQL timestamp to seconds and nanoseconds
+///
+/// Given the microseconds since 2000-01-01, as used internally and in the
+/// binary wire format for `timestamp` and `timestamptz` by
+/// [PostgreSQL](https://www.postgresql.org/docs/current/datatype-datetime.html),
+/// returns seconds and nanoseconds counting from Unix epoch (January 1st,
+/// 1970). The special values `infinity` and `-infinity` are represented by
+/// `i64::MAX` and `i64::MIN` and must be handled by the caller if needed. All
+/// `i64` values are within the supported range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::postgres_to_secs;
+///
+/// assert_eq!(postgres_to_secs(0), (946684800, 0));
+/// assert_eq!(postgres_to_secs(-946684800000000), (0, 0));
+/// assert_eq!(postgres_to_secs(736747278123456), (1683432078, 123_456_000));
+/// assert_eq!(postgres_to_secs(-1), (946684799, 999_999_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division and addition.
+#[inline]
+pub const fn postgres_to_secs(micros: i64) -> (i64, u32) {
+    let secs = micros.div_euclid(MICROS_IN_SEC) + POSTGRES_EPOCH;
+    let nsecs = micros.rem_euclid(MICROS_IN_SEC) as u32 * NANOS_IN_MICRO;
+    (secs, nsecs)
+}
+
+/// Convert seconds and nanoseconds to PostgreSQL timestamp
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the microseconds since 2000-01-01, as used by PostgreSQL. Note that
+/// PostgreSQL itself only accepts years between 4713 BC and 294276 AD.
+///
+/// # Panics
+///
+/// The result must fit in `i64`, which limits the year to roughly ±292,000
+/// years from 2000. Nanoseconds must be between `0` and `999_999_999`. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_postgres;
+///
+/// assert_eq!(secs_to_postgres((946684800, 0)), 0);
+/// assert_eq!(secs_to_postgres((1683432078, 123_456_789)), 736747278123456);
+/// assert_eq!(secs_to_postgres((946684799, 999_999_999)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication and addition.
+#[inline]
+pub const fn secs_to_postgres((secs, nsecs): (i64, u32)) -> i64 {
+    debug_assert!(nsecs < NANOS_IN_SEC as u32, "given nanoseconds value is out of range");
+    let secs = secs - POSTGRES_EPOCH;
+    let micros = (nsecs / NANOS_IN_MICRO) as i64;
+    let total = secs as i128 * MICROS_IN_SEC as i128 + micros as i128;
+    debug_assert!(
+        total >= i64::MIN as i128 && total <= i64::MAX as i128,
+        "given seconds value is out of range"
+    );
+    secs.wrapping_mul(MICROS_IN_SEC).wrapping_add(micros)
+}
+
+/// Convert Excel serial date to seconds and nanoseconds
+///
+/// Given an Excel serial date in the 1900 date system, the floating point days
+/// since 1899-12-30 with the time of day as the fraction, returns seconds and
+/// nanoseconds counting from Unix epoch (January 1st, 1970). This matches
+/// Excel for dates from 1900-03-01 onwards, earlier serials are off by one day
+/// as Excel incorrectly considers 1900 to be a leap year. The [excel](crate::excel)
+/// module reproduces Excel exactly and also supports the 1904 date system.
+///
+/// # Panics
+///
+/// Argument must be finite and the result must be between [RD_SECONDS_MIN] and
+/// [RD_SECONDS_MAX] inclusive. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::excel_to_secs;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(excel_to_secs(25569.0), (0, 0));
+/// assert_eq!(excel_to_secs(45053.75), (datetime_to_secs((2023, 5, 7, 18, 0, 0)), 0));
+/// assert_eq!(excel_to_secs(61.0), (datetime_to_secs((1900, 3, 1, 0, 0, 0)), 0));
+/// ```
+///
+/// # Algorithm
+///
+/// The fraction is rounded to the nearest nanosecond, with ties to even.
+#[inline]
+pub const fn excel_to_secs(serial: f64) -> (i64, u32) {
+    float_to_secs(serial, SECS_IN_DAY as u64 * NANOS_IN_SEC, EXCEL_EPOCH)
+}
+
+/// Convert seconds and nanoseconds to Excel serial date
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the Excel serial date in the 1900 date system, the floating point
+/// days since 1899-12-30 with the time of day as the fraction. This matches
+/// Excel for dates from 1900-03-01 onwards.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must be between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::epoch::secs_to_excel;
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(secs_to_excel((0, 0)), 25569.0);
+/// assert_eq!(secs_to_excel((datetime_to_secs((2023, 5, 7, 18, 0, 0)), 0)), 45053.75);
+/// ```
+///
+/// # Algorithm
+///
+/// Subtraction of the epoch followed by conversion to floating point.
+#[inline]
+pub const fn secs_to_excel((secs, nsecs): (i64, u32)) -> f64 {
+    secs_to_float((secs, nsecs), SECS_IN_DAY as u64 * NANOS_IN_SEC, EXCEL_EPOCH)
+}
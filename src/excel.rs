@@ -0,0 +1,378 @@
+//! Spreadsheet serial dates
+//!
+//! Conversions between dates and the serial day numbers used by Excel, Lotus
+//! 1-2-3 and compatible spreadsheets. Two date systems are in use:
+//!
+//! - The 1900 date system, the default on all platforms, counts January 1st,
+//!   1900 as serial `1`. For compatibility with Lotus 1-2-3 it considers 1900
+//!   to be a leap year, so serial `60` is the fictitious February 29th, 1900
+//!   and all later serials are one day ahead of a plain day count. Serial `0`
+//!   is displayed as January 0th, 1900, which is returned here as `(1900, 1,
+//!   0)`. Negative serials are not supported.
+//! - The 1904 date system, originally used by Excel for Mac, counts January
+//!   1st, 1904 as serial `0`. Negative serials are supported and count days
+//!   before the epoch.
+//!
+//! The time of day is stored as the fraction of the day. Excel resolves times
+//! to the millisecond, so fractional serials are rounded to the nearest
+//! millisecond, with halves rounded up, and the nanoseconds in the results are
+//! always multiples of `1_000_000`.
+//!
+//! For converting serials to and from Unix seconds without the quirks of the
+//! 1900 date system, see [excel_to_secs](crate::epoch::excel_to_secs).
+
+use crate::{date_to_rd, rd_to_date, RD_MAX};
+
+/// Difference in days between the 1900 and 1904 date systems
+///
+/// Serial numbers in the 1904 date system are this much smaller than those in
+/// the 1900 date system for the same date.
+pub const DATE_1904_OFFSET: i32 = 1462;
+
+/// Rata Die of the day before serial `1` in the 1900 date system
+const EPOCH_1900_RD: i32 = date_to_rd((1899, 12, 31));
+
+/// Rata Die of serial `0` in the 1904 date system
+const EPOCH_1904_RD: i32 = date_to_rd((1904, 1, 1));
+
+/// Serial of the fictitious February 29th, 1900
+const SERIAL_1900_02_29: i32 = 60;
+
+/// Number of milliseconds in a day
+const MILLIS_IN_DAY: u32 = 86_400_000;
+
+/// Split a fractional serial into whole days and milliseconds, rounding to the
+/// nearest millisecond
+#[inline]
+const fn serial_to_days_millis(serial: f64) -> (i32, u32) {
+    debug_assert!(serial.is_finite(), "given serial is not finite");
+    let days = serial as i32;
+    let days = if days as f64 > serial { days - 1 } else { days };
+    let ms = ((serial - days as f64) * MILLIS_IN_DAY as f64 + 0.5) as u32;
+    // rounding may carry over to the next day
+    if ms >= MILLIS_IN_DAY {
+        (days + 1, ms - MILLIS_IN_DAY)
+    } else {
+        (days, ms)
+    }
+}
+
+/// Combine whole days with a time of day to a fractional serial
+#[inline]
+const fn days_to_serial(days: i32, (hh, mm, ss, nsec): (u8, u8, u8, u32)) -> f64 {
+    debug_assert!(hh <= 23, "given hour is out of range");
+    debug_assert!(mm <= 59, "given minute is out of range");
+    debug_assert!(ss <= 59, "given second is out of range");
+    debug_assert!(nsec <= 999_999_999, "given nanoseconds value is out of range");
+    let ms = ((hh as u32 * 60 + mm as u32) * 60 + ss as u32) * 1000 + (nsec + 500_000) / 1_000_000;
+    days as f64 + ms as f64 / MILLIS_IN_DAY as f64
+}
+
+/// Split milliseconds of a day to hours, minutes, seconds and nanoseconds
+#[inline]
+const fn millis_to_hms(ms: u32) -> (u8, u8, u8, u32) {
+    let secs = ms / 1000;
+    (
+        (secs / 3600) as u8,
+        (secs / 60 % 60) as u8,
+        (secs % 60) as u8,
+        ms % 1000 * 1_000_000,
+    )
+}
+
+/// Convert serial in the 1900 date system to year, month and day
+///
+/// Given a serial day number in the 1900 date system returns a `(year, month,
+/// day)` tuple. Serial `0` returns `(1900, 1, 0)` and serial `60` returns the
+/// fictitious `(1900, 2, 29)`, as displayed by Excel.
+///
+/// # Panics
+///
+/// Serial must not be negative and the resulting date must not be after
+/// [YEAR_MAX](crate::YEAR_MAX). Bounds are checked using `debug_assert` only,
+/// so that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::serial_1900_to_date;
+///
+/// assert_eq!(serial_1900_to_date(0), (1900, 1, 0));
+/// assert_eq!(serial_1900_to_date(1), (1900, 1, 1));
+/// assert_eq!(serial_1900_to_date(59), (1900, 2, 28));
+/// assert_eq!(serial_1900_to_date(60), (1900, 2, 29));
+/// assert_eq!(serial_1900_to_date(61), (1900, 3, 1));
+/// assert_eq!(serial_1900_to_date(45053), (2023, 5, 7));
+/// ```
+///
+/// # Algorithm
+///
+/// Offset by the epoch followed by [rd_to_date], skipping the fictitious day.
+#[inline]
+pub const fn serial_1900_to_date(serial: i32) -> (i32, u8, u8) {
+    debug_assert!(serial >= 0 && serial <= RD_MAX - EPOCH_1900_RD + 1, "given serial is out of range");
+    if serial == 0 {
+        (1900, 1, 0)
+    } else if serial == SERIAL_1900_02_29 {
+        (1900, 2, 29)
+    } else if serial < SERIAL_1900_02_29 {
+        rd_to_date(EPOCH_1900_RD + serial)
+    } else {
+        rd_to_date(EPOCH_1900_RD + serial - 1)
+    }
+}
+
+/// Convert year, month and day to serial in the 1900 date system
+///
+/// Given a `(year, month, day)` tuple returns the serial day number in the
+/// 1900 date system. The day `(1900, 1, 0)` returns serial `0` and the
+/// fictitious `(1900, 2, 29)` returns serial `60`, as accepted by Excel.
+///
+/// # Panics
+///
+/// Date must be a valid date between `(1900, 1, 0)` and
+/// [YEAR_MAX](crate::YEAR_MAX), or the fictitious `(1900, 2, 29)`. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::date_to_serial_1900;
+///
+/// assert_eq!(date_to_serial_1900((1900, 1, 0)), 0);
+/// assert_eq!(date_to_serial_1900((1900, 1, 1)), 1);
+/// assert_eq!(date_to_serial_1900((1900, 2, 29)), 60);
+/// assert_eq!(date_to_serial_1900((1900, 3, 1)), 61);
+/// assert_eq!(date_to_serial_1900((2023, 5, 7)), 45053);
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_rd] followed by offset by the epoch, adding the fictitious day.
+#[inline]
+pub const fn date_to_serial_1900((y, m, d): (i32, u8, u8)) -> i32 {
+    if y == 1900 && m == 1 && d == 0 {
+        return 0;
+    }
+    if y == 1900 && m == 2 && d == 29 {
+        return SERIAL_1900_02_29;
+    }
+    let serial = date_to_rd((y, m, d)) - EPOCH_1900_RD;
+    debug_assert!(serial > 0, "given date is out of range");
+    if serial < SERIAL_1900_02_29 {
+        serial
+    } else {
+        serial + 1
+    }
+}
+
+/// Convert serial in the 1904 date system to year, month and day
+///
+/// Given a serial day number in the 1904 date system returns a `(year, month,
+/// day)` tuple.
+///
+/// # Panics
+///
+/// The resulting date must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Bounds are checked using `debug_assert` only,
+/// so that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::serial_1904_to_date;
+///
+/// assert_eq!(serial_1904_to_date(0), (1904, 1, 1));
+/// assert_eq!(serial_1904_to_date(43591), (2023, 5, 7));
+/// assert_eq!(serial_1904_to_date(-1), (1903, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Offset by the epoch followed by [rd_to_date].
+#[inline]
+pub const fn serial_1904_to_date(serial: i32) -> (i32, u8, u8) {
+    rd_to_date(serial + EPOCH_1904_RD)
+}
+
+/// Convert year, month and day to serial in the 1904 date system
+///
+/// Given a `(year, month, day)` tuple returns the serial day number in the
+/// 1904 date system.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Month must be between `1` and `12`. Day must
+/// be between `1` and the number of days in the month in question. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::date_to_serial_1904;
+///
+/// assert_eq!(date_to_serial_1904((1904, 1, 1)), 0);
+/// assert_eq!(date_to_serial_1904((2023, 5, 7)), 43591);
+/// assert_eq!(date_to_serial_1904((1903, 12, 31)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_rd] followed by offset by the epoch.
+#[inline]
+pub const fn date_to_serial_1904((y, m, d): (i32, u8, u8)) -> i32 {
+    date_to_rd((y, m, d)) - EPOCH_1904_RD
+}
+
+/// Convert fractional serial in the 1900 date system to year, month, day,
+/// hours, minutes, seconds and nanoseconds
+///
+/// Given a fractional serial in the 1900 date system, with the time of day as
+/// the fraction, returns a `(year, month, day, hours, minutes, seconds,
+/// nanoseconds)` tuple. The time is rounded to the nearest millisecond, same
+/// as Excel, and may round up to the next day.
+///
+/// # Panics
+///
+/// Serial must be finite and not negative, and the resulting date must not be
+/// after [YEAR_MAX](crate::YEAR_MAX). Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::serial_1900_to_datetime;
+///
+/// assert_eq!(serial_1900_to_datetime(45053.75), (2023, 5, 7, 18, 0, 0, 0));
+/// assert_eq!(serial_1900_to_datetime(0.5), (1900, 1, 0, 12, 0, 0, 0));
+/// assert_eq!(serial_1900_to_datetime(60.25), (1900, 2, 29, 6, 0, 0, 0));
+/// assert_eq!(serial_1900_to_datetime(45053.000_000_005_8), (2023, 5, 7, 0, 0, 0, 1_000_000));
+/// assert_eq!(serial_1900_to_datetime(45053.999_999_999), (2023, 5, 8, 0, 0, 0, 0));
+/// ```
+///
+/// # Algorithm
+///
+/// The fraction is scaled to milliseconds and rounded, with any carry added to
+/// the days before [serial_1900_to_date].
+#[inline]
+pub const fn serial_1900_to_datetime(serial: f64) -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (days, ms) = serial_to_days_millis(serial);
+    let (y, m, d) = serial_1900_to_date(days);
+    let (hh, mm, ss, nsec) = millis_to_hms(ms);
+    (y, m, d, hh, mm, ss, nsec)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// fractional serial in the 1900 date system
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// returns the fractional serial in the 1900 date system, with the time of day
+/// as the fraction. Nanoseconds are rounded to the nearest millisecond, same as
+/// Excel.
+///
+/// # Panics
+///
+/// Date must be a valid date between `(1900, 1, 0)` and
+/// [YEAR_MAX](crate::YEAR_MAX), or the fictitious `(1900, 2, 29)`. Hours must
+/// be between `0` and `23`. Minutes must be between `0` and `59`. Seconds must
+/// be between `0` and `59`. Nanoseconds must be between `0` and `999_999_999`.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::datetime_to_serial_1900;
+///
+/// assert_eq!(datetime_to_serial_1900((2023, 5, 7, 18, 0, 0, 0)), 45053.75);
+/// assert_eq!(datetime_to_serial_1900((1900, 1, 0, 12, 0, 0, 0)), 0.5);
+/// assert_eq!(datetime_to_serial_1900((1900, 2, 29, 6, 0, 0, 0)), 60.25);
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_serial_1900] plus the milliseconds of the day divided by the
+/// milliseconds in a day.
+#[inline]
+pub const fn datetime_to_serial_1900((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> f64 {
+    days_to_serial(date_to_serial_1900((y, m, d)), (hh, mm, ss, nsec))
+}
+
+/// Convert fractional serial in the 1904 date system to year, month, day,
+/// hours, minutes, seconds and nanoseconds
+///
+/// Given a fractional serial in the 1904 date system, with the time of day as
+/// the fraction, returns a `(year, month, day, hours, minutes, seconds,
+/// nanoseconds)` tuple. The time is rounded to the nearest millisecond, same
+/// as Excel, and may round up to the next day. For negative serials the
+/// fraction counts forward from the start of the earlier day.
+///
+/// # Panics
+///
+/// Serial must be finite and the resulting date must be between
+/// [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX). Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::serial_1904_to_datetime;
+///
+/// assert_eq!(serial_1904_to_datetime(43591.75), (2023, 5, 7, 18, 0, 0, 0));
+/// assert_eq!(serial_1904_to_datetime(-0.25), (1903, 12, 31, 18, 0, 0, 0));
+/// ```
+///
+/// # Algorithm
+///
+/// The fraction is scaled to milliseconds and rounded, with any carry added to
+/// the days before [serial_1904_to_date].
+#[inline]
+pub const fn serial_1904_to_datetime(serial: f64) -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (days, ms) = serial_to_days_millis(serial);
+    let (y, m, d) = serial_1904_to_date(days);
+    let (hh, mm, ss, nsec) = millis_to_hms(ms);
+    (y, m, d, hh, mm, ss, nsec)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// fractional serial in the 1904 date system
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// returns the fractional serial in the 1904 date system, with the time of day
+/// as the fraction. Nanoseconds are rounded to the nearest millisecond, same as
+/// Excel.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and
+/// [YEAR_MAX](crate::YEAR_MAX). Month must be between `1` and `12`. Day must
+/// be between `1` and the number of days in the month in question. Hours must
+/// be between `0` and `23`. Minutes must be between `0` and `59`. Seconds must
+/// be between `0` and `59`. Nanoseconds must be between `0` and `999_999_999`.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::excel::datetime_to_serial_1904;
+///
+/// assert_eq!(datetime_to_serial_1904((2023, 5, 7, 18, 0, 0, 0)), 43591.75);
+/// assert_eq!(datetime_to_serial_1904((1903, 12, 31, 18, 0, 0, 0)), -0.25);
+/// ```
+///
+/// # Algorithm
+///
+/// [date_to_serial_1904] plus the milliseconds of the day divided by the
+/// milliseconds in a day.
+#[inline]
+pub const fn datetime_to_serial_1904((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> f64 {
+    days_to_serial(date_to_serial_1904((y, m, d)), (hh, mm, ss, nsec))
+}
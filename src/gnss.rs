@@ -0,0 +1,583 @@
+//! Satellite navigation system time scales
+//!
+//! Conversions for [GPS
+//! time](https://en.wikipedia.org/wiki/Global_Positioning_System#Timekeeping),
+//! Galileo System Time (GST) and BeiDou Time (BDT). All three are continuous
+//! time scales with a fixed offset from TAI, so conversions to and from UTC
+//! require a leap second table, such as [LEAP_SECONDS](crate::leapsec::LEAP_SECONDS).
+//!
+//! Time in each system is represented as seconds since the epoch of that
+//! system. Receivers usually report the time as a week number and time of week
+//! instead, which can be converted with [secs_to_week_tow] and
+//! [week_tow_to_secs]. Weeks start on Sunday in all three systems. Broadcast
+//! week numbers are truncated to a few bits, so they roll over periodically and
+//! must be resolved against a reference date with [resolve_week].
+
+use crate::leapsec::{datetime_to_tai_secs, tai_secs_to_datetime, tai_secs_to_utc_secs, utc_secs_to_tai_secs};
+use crate::{date_to_rd, datetime_to_secs, rd_to_weekday, RD_MAX, RD_MIN};
+
+/// Unix time of the GPS epoch, 1980-01-06 00:00:00 UTC
+pub const GPS_EPOCH: i64 = datetime_to_secs((1980, 1, 6, 0, 0, 0));
+
+/// Unix time of the Galileo System Time epoch, 1999-08-21 23:59:47 UTC
+///
+/// This is the start of GPS week 1024.
+pub const GST_EPOCH: i64 = datetime_to_secs((1999, 8, 21, 23, 59, 47));
+
+/// Unix time of the BeiDou Time epoch, 2006-01-01 00:00:00 UTC
+pub const BDT_EPOCH: i64 = datetime_to_secs((2006, 1, 1, 0, 0, 0));
+
+/// Number of seconds in a week
+pub const SECS_IN_WEEK: u32 = 604800;
+
+/// TAI seconds at the GPS epoch, when `TAI - UTC` was 19 seconds
+const GPS_EPOCH_TAI: i64 = GPS_EPOCH + 19;
+
+/// TAI seconds at the Galileo System Time epoch, when `TAI - UTC` was 32 seconds
+const GST_EPOCH_TAI: i64 = GST_EPOCH + 32;
+
+/// TAI seconds at the BeiDou Time epoch, when `TAI - UTC` was 33 seconds
+const BDT_EPOCH_TAI: i64 = BDT_EPOCH + 33;
+
+/// Rata Die of the first day of GPS week 0
+const GPS_EPOCH_RD: i32 = date_to_rd((1980, 1, 6));
+
+/// GPS week of the first Galileo System Time week
+const GST_WEEK_OFFSET: i32 = 1024;
+
+/// GPS week of the first BeiDou Time week
+const BDT_WEEK_OFFSET: i32 = 1356;
+
+/// Split seconds into week number and time of week
+///
+/// Given seconds since the epoch of a navigation system time scale returns a
+/// `(week, tow)` tuple, where `tow` is the seconds since the start of the week.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::secs_to_week_tow;
+///
+/// assert_eq!(secs_to_week_tow(0), (0, 0));
+/// assert_eq!(secs_to_week_tow(1368609896), (2262, 552296));
+/// assert_eq!(secs_to_week_tow(-1), (-1, 604799));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple Euclidean division by the length of a week.
+#[inline]
+pub const fn secs_to_week_tow(secs: i64) -> (i32, u32) {
+    let week = secs.div_euclid(SECS_IN_WEEK as i64);
+    let tow = secs.rem_euclid(SECS_IN_WEEK as i64);
+    (week as i32, tow as u32)
+}
+
+/// Combine week number and time of week into seconds
+///
+/// Given a `(week, tow)` tuple, where `tow` is the seconds since the start of
+/// the week, returns seconds since the epoch of a navigation system time scale.
+///
+/// # Panics
+///
+/// Time of week must be between `0` and `604799`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::week_tow_to_secs;
+///
+/// assert_eq!(week_tow_to_secs((0, 0)), 0);
+/// assert_eq!(week_tow_to_secs((2262, 552296)), 1368609896);
+/// assert_eq!(week_tow_to_secs((-1, 604799)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication and addition.
+#[inline]
+pub const fn week_tow_to_secs((week, tow): (i32, u32)) -> i64 {
+    debug_assert!(tow < SECS_IN_WEEK, "given time of week is out of range");
+    week as i64 * SECS_IN_WEEK as i64 + tow as i64
+}
+
+/// Resolve a truncated week number against a reference week
+///
+/// Given a week number truncated to `bits` bits, as broadcast by the
+/// satellites, returns the first full week number on or after `reference` that
+/// matches it. GPS uses 10 bits in the legacy navigation message and 13 bits in
+/// the modernized messages, Galileo uses 12 bits and BeiDou uses 13 bits. The
+/// reference is usually a date known to be in the past, such as the build date
+/// of the software, converted with [rd_to_gps_week], [rd_to_gst_week] or
+/// [rd_to_bdt_week].
+///
+/// # Panics
+///
+/// Bits must be between `1` and `16`, and the week must fit in the given number
+/// of bits. Bounds are checked using `debug_assert` only, so that the checks are
+/// not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{resolve_week, rd_to_gps_week};
+/// use datealgo::date_to_rd;
+///
+/// let reference = rd_to_gps_week(date_to_rd((2020, 1, 1)));
+/// assert_eq!(reference, 2086);
+/// assert_eq!(resolve_week(214, 10, reference), 2262);
+/// assert_eq!(resolve_week(38, 10, reference), 2086);
+/// assert_eq!(resolve_week(37, 10, reference), 3109);
+/// assert_eq!(resolve_week(2262, 13, reference), 2262);
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean remainder of the difference to the reference, modulo the rollover
+/// period.
+#[inline]
+pub const fn resolve_week(week: u16, bits: u8, reference: i32) -> i32 {
+    debug_assert!(bits >= 1 && bits <= 16, "given bits is out of range");
+    debug_assert!(bits == 16 || week < (1 << bits), "given week is out of range");
+    let period = 1i32 << bits;
+    reference + (week as i32 - reference).rem_euclid(period)
+}
+
+/// Determine the GPS week of the given day
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the GPS
+/// week containing it. GPS weeks start on Sunday. The offset of GPS time from
+/// UTC is ignored, so the week changes at midnight UTC.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::rd_to_gps_week;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 6))), 0);
+/// assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 5))), -1);
+/// assert_eq!(rd_to_gps_week(date_to_rd((2023, 5, 20))), 2262);
+/// assert_eq!(rd_to_gps_week(date_to_rd((2023, 5, 21))), 2263);
+/// ```
+///
+/// # Algorithm
+///
+/// The preceding Sunday is found with [rd_to_weekday], after which the
+/// division by seven is exact.
+#[inline]
+pub const fn rd_to_gps_week(rd: i32) -> i32 {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let sunday = rd - (rd_to_weekday(rd) % 7) as i32;
+    (sunday - GPS_EPOCH_RD) / 7
+}
+
+/// Determine the Galileo System Time week of the given day
+///
+/// Same as [rd_to_gps_week], but with week numbers starting from the Galileo
+/// System Time epoch.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::rd_to_gst_week;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(rd_to_gst_week(date_to_rd((1999, 8, 22))), 0);
+/// assert_eq!(rd_to_gst_week(date_to_rd((2023, 5, 20))), 1238);
+/// ```
+///
+/// # Algorithm
+///
+/// Offset from [rd_to_gps_week].
+#[inline]
+pub const fn rd_to_gst_week(rd: i32) -> i32 {
+    rd_to_gps_week(rd) - GST_WEEK_OFFSET
+}
+
+/// Determine the BeiDou Time week of the given day
+///
+/// Same as [rd_to_gps_week], but with week numbers starting from the BeiDou
+/// Time epoch.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::rd_to_bdt_week;
+/// use datealgo::date_to_rd;
+///
+/// assert_eq!(rd_to_bdt_week(date_to_rd((2006, 1, 1))), 0);
+/// assert_eq!(rd_to_bdt_week(date_to_rd((2023, 5, 20))), 906);
+/// ```
+///
+/// # Algorithm
+///
+/// Offset from [rd_to_gps_week].
+#[inline]
+pub const fn rd_to_bdt_week(rd: i32) -> i32 {
+    rd_to_gps_week(rd) - BDT_WEEK_OFFSET
+}
+
+/// Convert UTC seconds to GPS seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and a leap second
+/// table returns seconds since the GPS epoch in GPS time.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{utc_secs_to_gps_secs, GPS_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(utc_secs_to_gps_secs(GPS_EPOCH, LEAP_SECONDS), 0);
+/// assert_eq!(utc_secs_to_gps_secs(1684574678, LEAP_SECONDS), 1368609896);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to TAI with [utc_secs_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn utc_secs_to_gps_secs(secs: i64, table: &[(i64, u8)]) -> i64 {
+    utc_secs_to_tai_secs(secs, table) - GPS_EPOCH_TAI
+}
+
+/// Convert GPS seconds to UTC seconds
+///
+/// Given seconds since the GPS epoch in GPS time and a leap second table
+/// returns seconds counting from Unix epoch (January 1st, 1970). An inserted
+/// leap second returns the same value as the following second.
+///
+/// # Panics
+///
+/// The resulting seconds must be between
+/// [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{gps_secs_to_utc_secs, GPS_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(gps_secs_to_utc_secs(0, LEAP_SECONDS), GPS_EPOCH);
+/// assert_eq!(gps_secs_to_utc_secs(1368609896, LEAP_SECONDS), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_utc_secs].
+#[inline]
+pub const fn gps_secs_to_utc_secs(gps: i64, table: &[(i64, u8)]) -> i64 {
+    tai_secs_to_utc_secs(gps + GPS_EPOCH_TAI, table)
+}
+
+/// Convert GPS seconds to UTC year, month, day, hours, minutes and seconds
+///
+/// Given seconds since the GPS epoch in GPS time and a leap second table
+/// returns a `(year, month, day, hours, minutes, seconds)` tuple in UTC.
+/// Seconds will be `60` during an inserted leap second.
+///
+/// # Panics
+///
+/// The resulting UTC time must be between
+/// [RD_SECONDS_MIN](crate::RD_SECONDS_MIN) and
+/// [RD_SECONDS_MAX](crate::RD_SECONDS_MAX) inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{gps_secs_to_datetime, week_tow_to_secs};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(gps_secs_to_datetime(0, LEAP_SECONDS), (1980, 1, 6, 0, 0, 0));
+/// assert_eq!(gps_secs_to_datetime(week_tow_to_secs((2262, 552296)), LEAP_SECONDS), (2023, 5, 20, 9, 24, 38));
+/// assert_eq!(gps_secs_to_datetime(week_tow_to_secs((1930, 17)), LEAP_SECONDS), (2016, 12, 31, 23, 59, 60));
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_datetime].
+#[inline]
+pub const fn gps_secs_to_datetime(gps: i64, table: &[(i64, u8)]) -> (i32, u8, u8, u8, u8, u8) {
+    tai_secs_to_datetime(gps + GPS_EPOCH_TAI, table)
+}
+
+/// Convert UTC year, month, day, hours, minutes and seconds to GPS seconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple in UTC and a
+/// leap second table returns seconds since the GPS epoch in GPS time. Seconds
+/// may be `60` on a day that ends in an inserted leap second.
+///
+/// # Panics
+///
+/// Same as [datetime_to_tai_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{datetime_to_gps_secs, secs_to_week_tow};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(datetime_to_gps_secs((1980, 1, 6, 0, 0, 0), LEAP_SECONDS), 0);
+/// assert_eq!(secs_to_week_tow(datetime_to_gps_secs((2023, 5, 20, 9, 24, 38), LEAP_SECONDS)), (2262, 552296));
+/// assert_eq!(secs_to_week_tow(datetime_to_gps_secs((2016, 12, 31, 23, 59, 60), LEAP_SECONDS)), (1930, 17));
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion with [datetime_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn datetime_to_gps_secs(dt: (i32, u8, u8, u8, u8, u8), table: &[(i64, u8)]) -> i64 {
+    datetime_to_tai_secs(dt, table) - GPS_EPOCH_TAI
+}
+
+/// Convert UTC seconds to Galileo System Time seconds
+///
+/// Same as [utc_secs_to_gps_secs], but returns seconds since the Galileo
+/// System Time epoch.
+///
+/// # Panics
+///
+/// Same as [utc_secs_to_gps_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{utc_secs_to_gst_secs, GST_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(utc_secs_to_gst_secs(GST_EPOCH, LEAP_SECONDS), 0);
+/// assert_eq!(utc_secs_to_gst_secs(1684574678, LEAP_SECONDS), 749294696);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to TAI with [utc_secs_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn utc_secs_to_gst_secs(secs: i64, table: &[(i64, u8)]) -> i64 {
+    utc_secs_to_tai_secs(secs, table) - GST_EPOCH_TAI
+}
+
+/// Convert Galileo System Time seconds to UTC seconds
+///
+/// Same as [gps_secs_to_utc_secs], but takes seconds since the Galileo System
+/// Time epoch.
+///
+/// # Panics
+///
+/// Same as [gps_secs_to_utc_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{gst_secs_to_utc_secs, GST_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(gst_secs_to_utc_secs(0, LEAP_SECONDS), GST_EPOCH);
+/// assert_eq!(gst_secs_to_utc_secs(749294696, LEAP_SECONDS), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_utc_secs].
+#[inline]
+pub const fn gst_secs_to_utc_secs(gst: i64, table: &[(i64, u8)]) -> i64 {
+    tai_secs_to_utc_secs(gst + GST_EPOCH_TAI, table)
+}
+
+/// Convert Galileo System Time seconds to UTC year, month, day, hours, minutes
+/// and seconds
+///
+/// Same as [gps_secs_to_datetime], but takes seconds since the Galileo System
+/// Time epoch.
+///
+/// # Panics
+///
+/// Same as [gps_secs_to_datetime].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{gst_secs_to_datetime, week_tow_to_secs};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(gst_secs_to_datetime(0, LEAP_SECONDS), (1999, 8, 21, 23, 59, 47));
+/// assert_eq!(gst_secs_to_datetime(week_tow_to_secs((1238, 552296)), LEAP_SECONDS), (2023, 5, 20, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_datetime].
+#[inline]
+pub const fn gst_secs_to_datetime(gst: i64, table: &[(i64, u8)]) -> (i32, u8, u8, u8, u8, u8) {
+    tai_secs_to_datetime(gst + GST_EPOCH_TAI, table)
+}
+
+/// Convert UTC year, month, day, hours, minutes and seconds to Galileo System
+/// Time seconds
+///
+/// Same as [datetime_to_gps_secs], but returns seconds since the Galileo
+/// System Time epoch.
+///
+/// # Panics
+///
+/// Same as [datetime_to_tai_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{datetime_to_gst_secs, secs_to_week_tow};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(datetime_to_gst_secs((1999, 8, 21, 23, 59, 47), LEAP_SECONDS), 0);
+/// assert_eq!(secs_to_week_tow(datetime_to_gst_secs((2023, 5, 20, 9, 24, 38), LEAP_SECONDS)), (1238, 552296));
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion with [datetime_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn datetime_to_gst_secs(dt: (i32, u8, u8, u8, u8, u8), table: &[(i64, u8)]) -> i64 {
+    datetime_to_tai_secs(dt, table) - GST_EPOCH_TAI
+}
+
+/// Convert UTC seconds to BeiDou Time seconds
+///
+/// Same as [utc_secs_to_gps_secs], but returns seconds since the BeiDou Time
+/// epoch.
+///
+/// # Panics
+///
+/// Same as [utc_secs_to_gps_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{utc_secs_to_bdt_secs, BDT_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(utc_secs_to_bdt_secs(BDT_EPOCH, LEAP_SECONDS), 0);
+/// assert_eq!(utc_secs_to_bdt_secs(1684574678, LEAP_SECONDS), 548501082);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion to TAI with [utc_secs_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn utc_secs_to_bdt_secs(secs: i64, table: &[(i64, u8)]) -> i64 {
+    utc_secs_to_tai_secs(secs, table) - BDT_EPOCH_TAI
+}
+
+/// Convert BeiDou Time seconds to UTC seconds
+///
+/// Same as [gps_secs_to_utc_secs], but takes seconds since the BeiDou Time
+/// epoch.
+///
+/// # Panics
+///
+/// Same as [gps_secs_to_utc_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{bdt_secs_to_utc_secs, BDT_EPOCH};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(bdt_secs_to_utc_secs(0, LEAP_SECONDS), BDT_EPOCH);
+/// assert_eq!(bdt_secs_to_utc_secs(548501082, LEAP_SECONDS), 1684574678);
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_utc_secs].
+#[inline]
+pub const fn bdt_secs_to_utc_secs(bdt: i64, table: &[(i64, u8)]) -> i64 {
+    tai_secs_to_utc_secs(bdt + BDT_EPOCH_TAI, table)
+}
+
+/// Convert BeiDou Time seconds to UTC year, month, day, hours, minutes and
+/// seconds
+///
+/// Same as [gps_secs_to_datetime], but takes seconds since the BeiDou Time
+/// epoch.
+///
+/// # Panics
+///
+/// Same as [gps_secs_to_datetime].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{bdt_secs_to_datetime, week_tow_to_secs};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(bdt_secs_to_datetime(0, LEAP_SECONDS), (2006, 1, 1, 0, 0, 0));
+/// assert_eq!(bdt_secs_to_datetime(week_tow_to_secs((906, 552282)), LEAP_SECONDS), (2023, 5, 20, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Fixed offset to TAI, followed by conversion with [tai_secs_to_datetime].
+#[inline]
+pub const fn bdt_secs_to_datetime(bdt: i64, table: &[(i64, u8)]) -> (i32, u8, u8, u8, u8, u8) {
+    tai_secs_to_datetime(bdt + BDT_EPOCH_TAI, table)
+}
+
+/// Convert UTC year, month, day, hours, minutes and seconds to BeiDou Time
+/// seconds
+///
+/// Same as [datetime_to_gps_secs], but returns seconds since the BeiDou Time
+/// epoch.
+///
+/// # Panics
+///
+/// Same as [datetime_to_tai_secs].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::gnss::{datetime_to_bdt_secs, secs_to_week_tow};
+/// use datealgo::leapsec::LEAP_SECONDS;
+///
+/// assert_eq!(datetime_to_bdt_secs((2006, 1, 1, 0, 0, 0), LEAP_SECONDS), 0);
+/// assert_eq!(secs_to_week_tow(datetime_to_bdt_secs((2023, 5, 20, 9, 24, 38), LEAP_SECONDS)), (906, 552282));
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion with [datetime_to_tai_secs], followed by a fixed offset.
+#[inline]
+pub const fn datetime_to_bdt_secs(dt: (i32, u8, u8, u8, u8, u8), table: &[(i64, u8)]) -> i64 {
+    datetime_to_tai_secs(dt, table) - BDT_EPOCH_TAI
+}
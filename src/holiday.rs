@@ -0,0 +1,767 @@
+//! Rule-based public holidays
+//!
+//! A holiday table is a slice of [HolidayRule] entries, each giving the name
+//! of a holiday, a [DateRule] for calculating its date in a given year, how it
+//! is [Observed] when it falls on a weekend, and the inclusive range of years
+//! in which the rule applies. Changes to a holiday over time are expressed as
+//! several rules with the same name and non-overlapping years, and one-off
+//! holidays as a rule for a single year.
+//!
+//! Built-in tables are provided for [US_FEDERAL], [ENGLAND_AND_WALES],
+//! [GERMANY] and [FINLAND]. Each models the rules from the year given in its
+//! documentation onwards, and returns no holidays for earlier years. Custom
+//! tables can be built the same way, or by combining the built-in rules with
+//! regional ones:
+//!
+//! ```
+//! use datealgo::holiday::{is_holiday, DateRule, HolidayRule, Observed};
+//! use datealgo::{date_to_rd, YEAR_MAX};
+//!
+//! const BAVARIA_EXTRA: &[HolidayRule] = &[
+//!     HolidayRule { name: "Epiphany", date: DateRule::Fixed { month: 1, day: 6 }, observed: Observed::Never, years: (1990, YEAR_MAX) },
+//!     HolidayRule { name: "Corpus Christi", date: DateRule::Easter { offset: 60 }, observed: Observed::Never, years: (1990, YEAR_MAX) },
+//! ];
+//!
+//! assert!(is_holiday(date_to_rd((2024, 5, 30)), BAVARIA_EXTRA));
+//! assert!(!is_holiday(date_to_rd((2024, 5, 31)), BAVARIA_EXTRA));
+//! ```
+//!
+//! When a holiday with [Observed::FollowingWeekday] falls on a weekend, its
+//! substitute day is the next weekday that is not the date of another holiday,
+//! the substitute day of a holiday with [Observed::NearestWeekday], or the
+//! substitute day of an earlier holiday, taken by year and then in table
+//! order. This gives the British rule where Christmas Day on a Sunday is
+//! substituted on Tuesday, as Monday is Boxing Day.
+
+use crate::consts::{FRIDAY, MONDAY, SATURDAY, SUNDAY, THURSDAY, WEDNESDAY};
+use crate::easter::{gregorian_easter_rd, orthodox_easter_rd, ASCENSION, EASTER_MONDAY, GOOD_FRIDAY, PENTECOST, WHIT_MONDAY};
+use crate::{
+    date_to_rd, days_in_month, last_weekday_of_month, nth_weekday_of_month, rd_to_date, rd_to_weekday, JULIAN_YEAR_MAX, JULIAN_YEAR_MIN,
+    RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN,
+};
+
+/// Rule for calculating the date of a holiday in a given year
+///
+/// Months are between `1` and `12`, and days of week between `1` and `7`, with
+/// `1` meaning Monday and `7` meaning Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateRule {
+    /// Same month and day every year, skipped in years where the day does not
+    /// exist, such as February 29th in common years
+    Fixed {
+        /// Month of the holiday
+        month: u8,
+        /// Day of month of the holiday
+        day: u8,
+    },
+    /// `n`th given day of week of a month, such as the fourth Thursday of
+    /// November, skipped in years where the month has no such day
+    NthWeekday {
+        /// Month of the holiday
+        month: u8,
+        /// Day of week of the holiday
+        weekday: u8,
+        /// Occurrence of the day of week, between `1` and `5`
+        n: u8,
+    },
+    /// Last given day of week of a month, such as the last Monday of May
+    LastWeekday {
+        /// Month of the holiday
+        month: u8,
+        /// Day of week of the holiday
+        weekday: u8,
+    },
+    /// First given day of week on or after a month and day, such as the
+    /// Saturday between June 20th and 26th
+    WeekdayOnOrAfter {
+        /// Month of the earliest date of the holiday
+        month: u8,
+        /// Day of month of the earliest date of the holiday
+        day: u8,
+        /// Day of week of the holiday
+        weekday: u8,
+    },
+    /// Days from Western Easter Sunday, see the `easter` module for constants
+    Easter {
+        /// Days from Easter Sunday, negative for days before it
+        offset: i16,
+    },
+    /// Days from Orthodox Easter Sunday, skipped for years outside
+    /// [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]
+    OrthodoxEaster {
+        /// Days from Easter Sunday, negative for days before it
+        offset: i16,
+    },
+}
+
+/// Handling of a holiday falling on a weekend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Observed {
+    /// No substitute day
+    Never,
+    /// Saturday is substituted on the preceding Friday and Sunday on the
+    /// following Monday, even if those are in a different year
+    NearestWeekday,
+    /// Substituted on the next weekday that is not already a holiday
+    FollowingWeekday,
+}
+
+/// Rule for a holiday in a holiday table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    /// Name of the holiday
+    pub name: &'static str,
+    /// Date of the holiday in a given year
+    pub date: DateRule,
+    /// Handling of the holiday falling on a weekend
+    pub observed: Observed,
+    /// First and last year in which the rule applies, inclusive
+    pub years: (i32, i32),
+}
+
+/// Shorthand for a rule in the built-in tables
+#[inline]
+const fn rule(name: &'static str, date: DateRule, observed: Observed, first: i32, last: i32) -> HolidayRule {
+    HolidayRule {
+        name,
+        date,
+        observed,
+        years: (first, last),
+    }
+}
+
+/// Shorthand for a fixed date in the built-in tables
+#[inline]
+const fn fixed(month: u8, day: u8) -> DateRule {
+    DateRule::Fixed { month, day }
+}
+
+/// Shorthand for a date relative to Western Easter in the built-in tables
+#[inline]
+const fn easter(offset: i32) -> DateRule {
+    DateRule::Easter { offset: offset as i16 }
+}
+
+/// Federal holidays of the United States
+///
+/// Holidays designated by 5 U.S.C. 6103, modeled from 1971, when the Uniform
+/// Monday Holiday Act took effect. A holiday falling on a Saturday is observed
+/// on the preceding Friday, and one falling on a Sunday on the following
+/// Monday, so that New Year's Day on a Saturday is observed on December 31st
+/// of the previous year.
+pub const US_FEDERAL: &[HolidayRule] = &[
+    rule("New Year's Day", fixed(1, 1), Observed::NearestWeekday, 1971, YEAR_MAX),
+    rule(
+        "Birthday of Martin Luther King, Jr.",
+        DateRule::NthWeekday {
+            month: 1,
+            weekday: MONDAY,
+            n: 3,
+        },
+        Observed::Never,
+        1986,
+        YEAR_MAX,
+    ),
+    rule(
+        "Washington's Birthday",
+        DateRule::NthWeekday {
+            month: 2,
+            weekday: MONDAY,
+            n: 3,
+        },
+        Observed::Never,
+        1971,
+        YEAR_MAX,
+    ),
+    rule(
+        "Memorial Day",
+        DateRule::LastWeekday { month: 5, weekday: MONDAY },
+        Observed::Never,
+        1971,
+        YEAR_MAX,
+    ),
+    rule(
+        "Juneteenth National Independence Day",
+        fixed(6, 19),
+        Observed::NearestWeekday,
+        2021,
+        YEAR_MAX,
+    ),
+    rule("Independence Day", fixed(7, 4), Observed::NearestWeekday, 1971, YEAR_MAX),
+    rule(
+        "Labor Day",
+        DateRule::NthWeekday {
+            month: 9,
+            weekday: MONDAY,
+            n: 1,
+        },
+        Observed::Never,
+        1971,
+        YEAR_MAX,
+    ),
+    rule(
+        "Columbus Day",
+        DateRule::NthWeekday {
+            month: 10,
+            weekday: MONDAY,
+            n: 2,
+        },
+        Observed::Never,
+        1971,
+        YEAR_MAX,
+    ),
+    rule(
+        "Veterans Day",
+        DateRule::NthWeekday {
+            month: 10,
+            weekday: MONDAY,
+            n: 4,
+        },
+        Observed::Never,
+        1971,
+        1977,
+    ),
+    rule("Veterans Day", fixed(11, 11), Observed::NearestWeekday, 1978, YEAR_MAX),
+    rule(
+        "Thanksgiving Day",
+        DateRule::NthWeekday {
+            month: 11,
+            weekday: THURSDAY,
+            n: 4,
+        },
+        Observed::Never,
+        1971,
+        YEAR_MAX,
+    ),
+    rule("Christmas Day", fixed(12, 25), Observed::NearestWeekday, 1971, YEAR_MAX),
+];
+
+/// Bank holidays of England and Wales
+///
+/// Bank holidays under the Banking and Financial Dealings Act 1971 and royal
+/// proclamations, along with Good Friday and Christmas Day, modeled from 1978,
+/// when the early May bank holiday was introduced. Includes the holidays that
+/// were moved or added for a single year. New Year's Day, Christmas Day and
+/// Boxing Day falling on a weekend are substituted on the following weekdays.
+/// Scotland and Northern Ireland have different bank holidays.
+pub const ENGLAND_AND_WALES: &[HolidayRule] = &[
+    rule("New Year's Day", fixed(1, 1), Observed::FollowingWeekday, 1978, YEAR_MAX),
+    rule("Good Friday", easter(GOOD_FRIDAY), Observed::Never, 1978, YEAR_MAX),
+    rule("Easter Monday", easter(EASTER_MONDAY), Observed::Never, 1978, YEAR_MAX),
+    rule(
+        "Wedding of Prince Charles and Lady Diana Spencer",
+        fixed(7, 29),
+        Observed::Never,
+        1981,
+        1981,
+    ),
+    rule(
+        "Early May bank holiday",
+        DateRule::NthWeekday {
+            month: 5,
+            weekday: MONDAY,
+            n: 1,
+        },
+        Observed::Never,
+        1978,
+        1994,
+    ),
+    rule("Early May bank holiday", fixed(5, 8), Observed::Never, 1995, 1995),
+    rule(
+        "Early May bank holiday",
+        DateRule::NthWeekday {
+            month: 5,
+            weekday: MONDAY,
+            n: 1,
+        },
+        Observed::Never,
+        1996,
+        2019,
+    ),
+    rule("Early May bank holiday", fixed(5, 8), Observed::Never, 2020, 2020),
+    rule(
+        "Early May bank holiday",
+        DateRule::NthWeekday {
+            month: 5,
+            weekday: MONDAY,
+            n: 1,
+        },
+        Observed::Never,
+        2021,
+        YEAR_MAX,
+    ),
+    rule(
+        "Spring bank holiday",
+        DateRule::LastWeekday { month: 5, weekday: MONDAY },
+        Observed::Never,
+        1978,
+        2001,
+    ),
+    rule("Spring bank holiday", fixed(6, 4), Observed::Never, 2002, 2002),
+    rule("Golden Jubilee of Elizabeth II", fixed(6, 3), Observed::Never, 2002, 2002),
+    rule(
+        "Spring bank holiday",
+        DateRule::LastWeekday { month: 5, weekday: MONDAY },
+        Observed::Never,
+        2003,
+        2011,
+    ),
+    rule(
+        "Wedding of Prince William and Catherine Middleton",
+        fixed(4, 29),
+        Observed::Never,
+        2011,
+        2011,
+    ),
+    rule("Spring bank holiday", fixed(6, 4), Observed::Never, 2012, 2012),
+    rule("Diamond Jubilee of Elizabeth II", fixed(6, 5), Observed::Never, 2012, 2012),
+    rule(
+        "Spring bank holiday",
+        DateRule::LastWeekday { month: 5, weekday: MONDAY },
+        Observed::Never,
+        2013,
+        2021,
+    ),
+    rule("Spring bank holiday", fixed(6, 2), Observed::Never, 2022, 2022),
+    rule("Platinum Jubilee of Elizabeth II", fixed(6, 3), Observed::Never, 2022, 2022),
+    rule("State Funeral of Queen Elizabeth II", fixed(9, 19), Observed::Never, 2022, 2022),
+    rule("Coronation of King Charles III", fixed(5, 8), Observed::Never, 2023, 2023),
+    rule(
+        "Spring bank holiday",
+        DateRule::LastWeekday { month: 5, weekday: MONDAY },
+        Observed::Never,
+        2023,
+        YEAR_MAX,
+    ),
+    rule(
+        "Summer bank holiday",
+        DateRule::LastWeekday { month: 8, weekday: MONDAY },
+        Observed::Never,
+        1978,
+        YEAR_MAX,
+    ),
+    rule("Christmas Day", fixed(12, 25), Observed::FollowingWeekday, 1978, YEAR_MAX),
+    rule("Boxing Day", fixed(12, 26), Observed::FollowingWeekday, 1978, YEAR_MAX),
+    rule("Millennium Celebrations", fixed(12, 31), Observed::Never, 1999, 1999),
+];
+
+/// Nationwide public holidays of Germany
+///
+/// Holidays observed in all states, modeled from 1990, when the Day of German
+/// Unity was established. Holidays observed only in some states, such as
+/// Epiphany and Corpus Christi, are not included. Holidays are not
+/// substituted when they fall on a weekend.
+pub const GERMANY: &[HolidayRule] = &[
+    rule("New Year's Day", fixed(1, 1), Observed::Never, 1990, YEAR_MAX),
+    rule("Good Friday", easter(GOOD_FRIDAY), Observed::Never, 1990, YEAR_MAX),
+    rule("Easter Monday", easter(EASTER_MONDAY), Observed::Never, 1990, YEAR_MAX),
+    rule("Labour Day", fixed(5, 1), Observed::Never, 1990, YEAR_MAX),
+    rule("Ascension Day", easter(ASCENSION), Observed::Never, 1990, YEAR_MAX),
+    rule("Whit Monday", easter(WHIT_MONDAY), Observed::Never, 1990, YEAR_MAX),
+    rule("Day of German Unity", fixed(10, 3), Observed::Never, 1990, YEAR_MAX),
+    rule("Reformation Day", fixed(10, 31), Observed::Never, 2017, 2017),
+    rule(
+        "Day of Repentance and Prayer",
+        DateRule::WeekdayOnOrAfter {
+            month: 11,
+            day: 16,
+            weekday: WEDNESDAY,
+        },
+        Observed::Never,
+        1990,
+        1994,
+    ),
+    rule("Christmas Day", fixed(12, 25), Observed::Never, 1990, YEAR_MAX),
+    rule("Second Day of Christmas", fixed(12, 26), Observed::Never, 1990, YEAR_MAX),
+];
+
+/// Public holidays of Finland
+///
+/// Holidays of the Evangelical Lutheran Church given by law, along with May
+/// Day and Independence Day, modeled from 1955, when Midsummer Day and All
+/// Saints' Day were moved to Saturdays. Between 1973 and 1990 Epiphany and
+/// Ascension Day were also moved to Saturdays. Midsummer Eve and Christmas
+/// Eve are customary days off, but not public holidays, and are not
+/// included. Holidays are not substituted when they fall on a weekend.
+pub const FINLAND: &[HolidayRule] = &[
+    rule("New Year's Day", fixed(1, 1), Observed::Never, 1955, YEAR_MAX),
+    rule("Epiphany", fixed(1, 6), Observed::Never, 1955, 1972),
+    rule(
+        "Epiphany",
+        DateRule::WeekdayOnOrAfter {
+            month: 1,
+            day: 6,
+            weekday: SATURDAY,
+        },
+        Observed::Never,
+        1973,
+        1990,
+    ),
+    rule("Epiphany", fixed(1, 6), Observed::Never, 1991, YEAR_MAX),
+    rule("Good Friday", easter(GOOD_FRIDAY), Observed::Never, 1955, YEAR_MAX),
+    rule("Easter Day", easter(0), Observed::Never, 1955, YEAR_MAX),
+    rule("Easter Monday", easter(EASTER_MONDAY), Observed::Never, 1955, YEAR_MAX),
+    rule("May Day", fixed(5, 1), Observed::Never, 1955, YEAR_MAX),
+    rule("Ascension Day", easter(ASCENSION), Observed::Never, 1955, 1972),
+    rule("Ascension Day", easter(ASCENSION + 2), Observed::Never, 1973, 1990),
+    rule("Ascension Day", easter(ASCENSION), Observed::Never, 1991, YEAR_MAX),
+    rule("Whitsunday", easter(PENTECOST), Observed::Never, 1955, YEAR_MAX),
+    rule(
+        "Midsummer Day",
+        DateRule::WeekdayOnOrAfter {
+            month: 6,
+            day: 20,
+            weekday: SATURDAY,
+        },
+        Observed::Never,
+        1955,
+        YEAR_MAX,
+    ),
+    rule(
+        "All Saints' Day",
+        DateRule::WeekdayOnOrAfter {
+            month: 10,
+            day: 31,
+            weekday: SATURDAY,
+        },
+        Observed::Never,
+        1955,
+        YEAR_MAX,
+    ),
+    rule("Independence Day", fixed(12, 6), Observed::Never, 1955, YEAR_MAX),
+    rule("Christmas Day", fixed(12, 25), Observed::Never, 1955, YEAR_MAX),
+    rule("St. Stephen's Day", fixed(12, 26), Observed::Never, 1955, YEAR_MAX),
+];
+
+/// Calculate the date of a rule in the given year, if any
+#[inline]
+const fn rule_to_rd(rule: &HolidayRule, y: i32) -> Option<i32> {
+    if y < rule.years.0 || y > rule.years.1 || y < YEAR_MIN || y > YEAR_MAX {
+        return None;
+    }
+    let rd = match rule.date {
+        DateRule::Fixed { month, day } => {
+            if day > days_in_month(y, month) {
+                return None;
+            }
+            date_to_rd((y, month, day))
+        }
+        DateRule::NthWeekday { month, weekday, n } => match nth_weekday_of_month(y, month, weekday, n) {
+            Some(d) => date_to_rd((y, month, d)),
+            None => return None,
+        },
+        DateRule::LastWeekday { month, weekday } => date_to_rd((y, month, last_weekday_of_month(y, month, weekday))),
+        DateRule::WeekdayOnOrAfter { month, day, weekday } => {
+            // same as weekday_on_or_after, which would not allow going past RD_MAX
+            let rd = date_to_rd((y, month, day));
+            rd + ((weekday + 7 - rd_to_weekday(rd)) % 7) as i32
+        }
+        DateRule::Easter { offset } => gregorian_easter_rd(y) + offset as i32,
+        DateRule::OrthodoxEaster { offset } => {
+            if y < JULIAN_YEAR_MIN || y > JULIAN_YEAR_MAX {
+                return None;
+            }
+            orthodox_easter_rd(y) + offset as i32
+        }
+    };
+    if rd < RD_MIN || rd > RD_MAX {
+        return None;
+    }
+    Some(rd)
+}
+
+/// Calculate the substitute day of a rule with [Observed::NearestWeekday] in
+/// the given year, if any
+#[inline]
+const fn nearest_weekday_rd(rule: &HolidayRule, y: i32) -> Option<i32> {
+    if !matches!(rule.observed, Observed::NearestWeekday) {
+        return None;
+    }
+    let rd = match rule_to_rd(rule, y) {
+        Some(rd) => rd,
+        None => return None,
+    };
+    let n = match rd_to_weekday(rd) {
+        SATURDAY => rd - 1,
+        SUNDAY => rd + 1,
+        _ => return None,
+    };
+    if n < RD_MIN || n > RD_MAX {
+        return None;
+    }
+    Some(n)
+}
+
+/// Calculate the weekend date of a rule with [Observed::FollowingWeekday] in
+/// the given year, after which its substitute day is searched for
+#[inline]
+const fn following_weekday_start(rule: &HolidayRule, y: i32) -> Option<i32> {
+    if !matches!(rule.observed, Observed::FollowingWeekday) {
+        return None;
+    }
+    match rule_to_rd(rule, y) {
+        Some(rd) if rd_to_weekday(rd) >= SATURDAY => Some(rd),
+        _ => None,
+    }
+}
+
+/// Check whether the day is the date of a holiday or the substitute day of a
+/// rule with [Observed::NearestWeekday]
+#[inline]
+const fn is_fixed(rules: &[HolidayRule], n: i32) -> bool {
+    let (y, _, _) = rd_to_date(n);
+    let mut year = y - 1;
+    while year <= y + 1 {
+        let mut i = 0;
+        while i < rules.len() {
+            if matches!(rule_to_rd(&rules[i], year), Some(rd) if rd == n) {
+                return true;
+            }
+            if matches!(nearest_weekday_rd(&rules[i], year), Some(rd) if rd == n) {
+                return true;
+            }
+            i += 1;
+        }
+        year += 1;
+    }
+    false
+}
+
+/// Check whether the day is a weekday that may be a substitute day of a rule
+/// with [Observed::FollowingWeekday]
+#[inline]
+const fn is_free(rules: &[HolidayRule], n: i32) -> bool {
+    rd_to_weekday(n) <= FRIDAY && !is_fixed(rules, n)
+}
+
+/// Count the rules with [Observed::FollowingWeekday] that come before the rule
+/// at the given index of the given year and start their search on or after
+/// `from` and before `to`
+#[inline]
+const fn count_starts(rules: &[HolidayRule], from: i32, to: i32, y: i32, i: usize) -> i32 {
+    let (ny, _, _) = rd_to_date(to);
+    let mut count = 0;
+    let mut year = ny - 1;
+    while year <= y && year <= ny + 1 {
+        let mut j = 0;
+        while j < rules.len() && (year < y || j < i) {
+            if matches!(following_weekday_start(&rules[j], year), Some(rd) if rd >= from && rd < to) {
+                count += 1;
+            }
+            j += 1;
+        }
+        year += 1;
+    }
+    count
+}
+
+/// Check whether a free day is the substitute day of a rule with
+/// [Observed::FollowingWeekday] that comes before the rule at the given index
+/// of the given year, which is the case when for the date of one of them there
+/// are at least as many of them starting from that date as there are free
+/// days up to the day
+#[inline]
+const fn is_taken(rules: &[HolidayRule], n: i32, y: i32, i: usize) -> bool {
+    let (ny, _, _) = rd_to_date(n);
+    let mut year = ny - 1;
+    while year <= y && year <= ny + 1 {
+        let mut j = 0;
+        while j < rules.len() && (year < y || j < i) {
+            if let Some(rd) = following_weekday_start(&rules[j], year) {
+                if rd < n {
+                    // counted backwards, stopping once there are more free days than holidays
+                    let starts = count_starts(rules, rd, n, y, i);
+                    let mut free = 0;
+                    let mut d = n;
+                    while d > rd && free <= starts {
+                        if is_free(rules, d) {
+                            free += 1;
+                        }
+                        d -= 1;
+                    }
+                    if free <= starts {
+                        return true;
+                    }
+                }
+            }
+            j += 1;
+        }
+        year += 1;
+    }
+    false
+}
+
+/// Check whether a day is a holiday
+///
+/// Given a Rata Die and a holiday table returns `true` if the day is a holiday
+/// or the substitute day of a holiday falling on a weekend.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::holiday::{is_holiday, ENGLAND_AND_WALES, FINLAND, US_FEDERAL};
+/// use datealgo::date_to_rd;
+///
+/// assert!(is_holiday(date_to_rd((2023, 11, 23)), US_FEDERAL));
+/// assert!(is_holiday(date_to_rd((2021, 12, 31)), US_FEDERAL));
+/// assert!(is_holiday(date_to_rd((2022, 12, 27)), ENGLAND_AND_WALES));
+/// assert!(is_holiday(date_to_rd((2024, 6, 22)), FINLAND));
+/// assert!(!is_holiday(date_to_rd((2024, 6, 21)), FINLAND));
+/// ```
+///
+/// # Algorithm
+///
+/// Every rule is evaluated for the year of the day and the years around it,
+/// as substitute days and rules based on days of week may cross over to the
+/// neighboring years. Each holiday with [Observed::FollowingWeekday] takes the
+/// first free weekday after its date, so a free day is taken exactly when, for
+/// the date of one of them, at least as many of them fall between that date
+/// and the day as there are free days in between. This decides the day
+/// without finding the substitute day of every rule.
+#[inline]
+pub const fn is_holiday(rd: i32, rules: &[HolidayRule]) -> bool {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let (y, _, _) = rd_to_date(rd);
+    is_fixed(rules, rd) || (is_free(rules, rd) && is_taken(rules, rd, y + 2, 0))
+}
+
+/// Write a holiday to the output at the given position, if it fits
+#[inline]
+const fn push(out: &mut [(i32, &'static str, bool)], len: usize, holiday: (i32, &'static str, bool)) -> Option<usize> {
+    if len >= out.len() {
+        return None;
+    }
+    out[len] = holiday;
+    Some(len + 1)
+}
+
+/// Check whether a holiday on the day is among the first `len` written to the
+/// output
+#[inline]
+const fn is_written(out: &[(i32, &'static str, bool)], len: usize, n: i32) -> bool {
+    let mut i = 0;
+    while i < len {
+        if out[i].0 == n {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// List holidays in a year
+///
+/// Given a year and a holiday table writes the holidays in the year into the
+/// output as `(rata die, name, substitute)` tuples, sorted by date, and returns
+/// the number of holidays written. Both the actual dates of holidays and the
+/// substitute days of holidays falling on a weekend are included, with the
+/// latter marked as substitutes. Holidays falling on the same day are written
+/// in table order.
+///
+/// Returns `None` if the output is too small for all the holidays.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::holiday::{holidays_in_year, US_FEDERAL};
+/// use datealgo::date_to_rd;
+///
+/// let mut out = [(0, "", false); 32];
+/// let len = holidays_in_year(2021, US_FEDERAL, &mut out).unwrap();
+/// assert_eq!(len, 15);
+/// assert_eq!(out[0], (date_to_rd((2021, 1, 1)), "New Year's Day", false));
+/// assert_eq!(out[14], (date_to_rd((2021, 12, 31)), "New Year's Day", true));
+/// assert_eq!(holidays_in_year(2021, US_FEDERAL, &mut out[..10]), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Every rule is evaluated for the year and the years around it, keeping
+/// those that fall within the year. Substitute days of holidays with
+/// [Observed::FollowingWeekday] are then found by year and in table order,
+/// checking days within the year against the holidays already written. The
+/// output is sorted with an insertion sort.
+#[inline]
+pub const fn holidays_in_year(y: i32, rules: &[HolidayRule], out: &mut [(i32, &'static str, bool)]) -> Option<usize> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let first = date_to_rd((y, 1, 1));
+    let last = date_to_rd((y, 12, 31));
+    let mut len = 0;
+    let mut year = y - 1;
+    while year <= y + 1 {
+        let mut i = 0;
+        while i < rules.len() {
+            let name = rules[i].name;
+            if let Some(rd) = rule_to_rd(&rules[i], year) {
+                if rd >= first && rd <= last {
+                    len = match push(out, len, (rd, name, false)) {
+                        Some(len) => len,
+                        None => return None,
+                    };
+                }
+            }
+            if let Some(rd) = nearest_weekday_rd(&rules[i], year) {
+                if rd >= first && rd <= last {
+                    len = match push(out, len, (rd, name, true)) {
+                        Some(len) => len,
+                        None => return None,
+                    };
+                }
+            }
+            i += 1;
+        }
+        year += 1;
+    }
+    year = y - 1;
+    while year <= y + 1 {
+        let mut i = 0;
+        while i < rules.len() {
+            if let Some(rd) = following_weekday_start(&rules[i], year) {
+                let mut n = rd + 1;
+                while n <= last {
+                    let taken = if n >= first {
+                        rd_to_weekday(n) > FRIDAY || is_written(out, len, n)
+                    } else {
+                        !is_free(rules, n) || is_taken(rules, n, year, i)
+                    };
+                    if !taken {
+                        break;
+                    }
+                    n += 1;
+                }
+                if n >= first && n <= last {
+                    len = match push(out, len, (n, rules[i].name, true)) {
+                        Some(len) => len,
+                        None => return None,
+                    };
+                }
+            }
+            i += 1;
+        }
+        year += 1;
+    }
+    let mut i = 1;
+    while i < len {
+        let mut j = i;
+        while j > 0 && out[j - 1].0 > out[j].0 {
+            let tmp = out[j];
+            out[j] = out[j - 1];
+            out[j - 1] = tmp;
+            j -= 1;
+        }
+        i += 1;
+    }
+    Some(len)
+}
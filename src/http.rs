@@ -0,0 +1,217 @@
+//! HTTP dates
+//!
+//! [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7) defines
+//! the format of dates in HTTP headers such as `Date`, `Last-Modified` and
+//! `Expires`. Dates are always sent in the IMF-fixdate format, such as
+//! `Sun, 06 Nov 1994 08:49:37 GMT`, but recipients must also accept the
+//! obsolete RFC 850 format, such as `Sunday, 06-Nov-94 08:49:37 GMT`, and the
+//! ANSI C `asctime()` format, such as `Sun Nov  6 08:49:37 1994`.
+//!
+//! Dates are converted to and from seconds counting from Unix epoch (January
+//! 1st, 1970), as HTTP dates are always in UTC and have no fractional seconds.
+
+use crate::iso8601::{digits1, digits2, digits4, NOT_DIGITS};
+use crate::util::{find_abbr, matches, write_2, write_abbr};
+use crate::{
+    datetime_to_secs, rd_to_weekday, secs_to_datetime, secs_to_dhms, validate_datetime, ParseError, MONTH_ABBRS, RD_SECONDS_MAX,
+    RD_SECONDS_MIN, WEEKDAY_ABBRS, WEEKDAY_NAMES,
+};
+
+/// Length of a date written by [format_http_date]
+pub const HTTP_DATE_LEN: usize = 29;
+
+/// Format seconds as an HTTP date
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) writes the date
+/// in the IMF-fixdate format, such as `Sun, 06 Nov 1994 08:49:37 GMT`, into
+/// the output buffer.
+///
+/// # Panics
+///
+/// Seconds must be between `-62167219200` and `253402300799`, which are the
+/// first and last seconds of years `0` and `9999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::http::{format_http_date, HTTP_DATE_LEN};
+///
+/// let mut out = [0u8; HTTP_DATE_LEN];
+/// format_http_date(784111777, &mut out);
+/// assert_eq!(&out, b"Sun, 06 Nov 1994 08:49:37 GMT");
+/// format_http_date(0, &mut out);
+/// assert_eq!(&out, b"Thu, 01 Jan 1970 00:00:00 GMT");
+/// ```
+///
+/// # Algorithm
+///
+/// Uses [secs_to_dhms] and [rd_to_weekday], with digits written directly at
+/// fixed positions.
+#[inline]
+pub const fn format_http_date(secs: i64, out: &mut [u8; HTTP_DATE_LEN]) {
+    debug_assert!(secs >= -62167219200 && secs <= 253402300799, "given seconds is out of range");
+    let (rd, hh, mm, ss) = secs_to_dhms(secs);
+    let (y, m, d) = crate::rd_to_date(rd);
+    let y = y as u32;
+    write_abbr(out, 0, WEEKDAY_ABBRS[rd_to_weekday(rd) as usize - 1]);
+    out[3] = b',';
+    out[4] = b' ';
+    write_2(out, 5, d);
+    out[7] = b' ';
+    write_abbr(out, 8, MONTH_ABBRS[m as usize - 1]);
+    out[11] = b' ';
+    write_2(out, 12, (y / 100) as u8);
+    write_2(out, 14, (y % 100) as u8);
+    out[16] = b' ';
+    write_2(out, 17, hh);
+    out[19] = b':';
+    write_2(out, 20, mm);
+    out[22] = b':';
+    write_2(out, 23, ss);
+    out[25] = b' ';
+    out[26] = b'G';
+    out[27] = b'M';
+    out[28] = b'T';
+}
+
+/// Parse `hh:mm:ss` at the given position
+#[inline]
+const fn parse_hms(s: &[u8], i: usize) -> Option<(u32, u32, u32)> {
+    if i + 8 > s.len() || s[i + 2] != b':' || s[i + 5] != b':' {
+        return None;
+    }
+    let hh = digits2(s, i);
+    let mm = digits2(s, i + 3);
+    let ss = digits2(s, i + 6);
+    if (hh | mm | ss) & NOT_DIGITS != 0 {
+        return None;
+    }
+    Some((hh, mm, ss))
+}
+
+/// Resolve a two digit year to the year within 50 years of the reference year
+///
+/// The year is interpreted as being in the future unless that would be more
+/// than 50 years from the reference year, as required by RFC 9110.
+#[inline]
+const fn resolve_year(yy: i32, reference: i32) -> i32 {
+    let base = reference - 49;
+    base + (yy - base).rem_euclid(100)
+}
+
+/// Parse an HTTP date
+///
+/// Given an HTTP date in any of the three formats allowed by RFC 9110 returns
+/// seconds counting from Unix epoch (January 1st, 1970). Two digit years in
+/// the RFC 850 format are resolved relative to the given current time in
+/// seconds, so that a year more than 50 years in the future is taken to be in
+/// the past.
+///
+/// The parser is lenient. Leading and trailing whitespace is ignored, names
+/// are matched ignoring case, four digit years are accepted in the RFC 850
+/// format, and days in the `asctime()` format may be padded with a space or a
+/// zero. The day of week must be present but is not checked against the date.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not an HTTP date, and
+/// [ParseError::OutOfRange] if the date or time is not valid.
+///
+/// # Panics
+///
+/// Current time must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::http::parse_http_date;
+/// use datealgo::ParseError;
+///
+/// let now = 1683451478;
+/// assert_eq!(parse_http_date(b"Sun, 06 Nov 1994 08:49:37 GMT", now), Ok(784111777));
+/// assert_eq!(parse_http_date(b"Sunday, 06-Nov-94 08:49:37 GMT", now), Ok(784111777));
+/// assert_eq!(parse_http_date(b"Sun Nov  6 08:49:37 1994", now), Ok(784111777));
+/// assert_eq!(parse_http_date(b"Sun, 31 Nov 1994 08:49:37 GMT", now), Err(ParseError::OutOfRange));
+/// assert_eq!(parse_http_date(b"1994-11-06T08:49:37Z", now), Err(ParseError::InvalidFormat));
+/// ```
+///
+/// # Algorithm
+///
+/// Matches each format at fixed positions, with digits parsed using SIMD
+/// within a register, and the result validated by [validate_datetime] before
+/// converting with [datetime_to_secs].
+#[inline]
+pub const fn parse_http_date(s: &[u8], now: i64) -> Result<i64, ParseError> {
+    debug_assert!(now >= RD_SECONDS_MIN && now <= RD_SECONDS_MAX, "given current time is out of range");
+    let (mut start, mut end) = (0, s.len());
+    while start < end && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    let (s, _) = s.split_at(end);
+    let wd = match find_abbr(s, start, &WEEKDAY_ABBRS) {
+        Some(wd) => wd,
+        None => return Err(ParseError::InvalidFormat),
+    };
+    let i = start + 3;
+    let (y, m, d, hms, len) = if i + 26 == end && s[i] == b',' {
+        // IMF-fixdate: Sun, 06 Nov 1994 08:49:37 GMT
+        let ok = s[i + 1] == b' ' && s[i + 4] == b' ' && s[i + 8] == b' ' && s[i + 13] == b' ' && matches(s, i + 22, " GMT");
+        if !ok {
+            return Err(ParseError::InvalidFormat);
+        }
+        (
+            digits4(s, i + 9),
+            find_abbr(s, i + 5, &MONTH_ABBRS),
+            digits2(s, i + 2),
+            parse_hms(s, i + 14),
+            4,
+        )
+    } else if i + 21 == end && s[i] == b' ' {
+        // asctime: Sun Nov  6 08:49:37 1994
+        if s[i + 4] != b' ' || s[i + 7] != b' ' || s[i + 16] != b' ' {
+            return Err(ParseError::InvalidFormat);
+        }
+        let d = if s[i + 5] == b' ' { digits1(s, i + 6) } else { digits2(s, i + 5) };
+        (digits4(s, i + 17), find_abbr(s, i + 1, &MONTH_ABBRS), d, parse_hms(s, i + 8), 4)
+    } else if matches(s, start, WEEKDAY_NAMES[wd]) {
+        // RFC 850: Sunday, 06-Nov-94 08:49:37 GMT
+        let i = start + WEEKDAY_NAMES[wd].len();
+        let len = end.saturating_sub(i + 22);
+        if len != 2 && len != 4 {
+            return Err(ParseError::InvalidFormat);
+        }
+        let j = i + 9 + len;
+        let ok = s[i] == b',' && s[i + 1] == b' ' && s[i + 4] == b'-' && s[i + 8] == b'-' && s[j] == b' ' && matches(s, j + 9, " GMT");
+        if !ok {
+            return Err(ParseError::InvalidFormat);
+        }
+        let y = if len == 2 { digits2(s, i + 9) } else { digits4(s, i + 9) };
+        (y, find_abbr(s, i + 5, &MONTH_ABBRS), digits2(s, i + 2), parse_hms(s, j + 1), len)
+    } else {
+        return Err(ParseError::InvalidFormat);
+    };
+    let (m, (hh, mm, ss)) = match (m, hms) {
+        (Some(m), Some(hms)) => (m, hms),
+        _ => return Err(ParseError::InvalidFormat),
+    };
+    if (y | d) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    let y = if len == 2 {
+        resolve_year(y as i32, secs_to_datetime(now).0)
+    } else {
+        y as i32
+    };
+    let dt = (y, m as u8 + 1, d as u8, hh as u8, mm as u8, ss as u8);
+    match validate_datetime(dt) {
+        Ok(()) => Ok(datetime_to_secs(dt)),
+        Err(_) => Err(ParseError::OutOfRange),
+    }
+}
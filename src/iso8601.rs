@@ -0,0 +1,709 @@
+//! ISO 8601 and RFC 3339 date and time strings
+//!
+//! [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) is the profile of ISO
+//! 8601 used by most internet protocols, such as `2023-05-07T09:24:38.5Z`.
+//! Only years from `0000` to `9999` can be represented in RFC 3339, so years
+//! outside that range are written in the ISO 8601 expanded representation,
+//! with an explicit sign and at least four digits, such as `+10000` and
+//! `-0001`. This matches what most date libraries produce.
+//!
+//! Formatting functions write into a caller provided fixed size buffer and
+//! return the number of bytes written, without allocation or [core::fmt]. The
+//! values given are written as is, so the date and time should already be in
+//! the local time of the offset given.
+//!
+//! Parsing functions return the date and time as written, along with the
+//! offset from UTC in seconds, so that [datetime_to_secs](crate::datetime_to_secs)
+//! minus the offset gives the time in UTC. Digits are parsed several at a time
+//! using SIMD within a register techniques, so that the common forms parse
+//! with few branches.
+
+use crate::util::write_2;
+use crate::{
+    consts, days_in_month, days_in_year, isoweekdate_to_date, ordinal_to_date, validate_date, validate_datetime, validate_isoweekdate,
+    ParseError,
+};
+
+/// Maximum length of a date written by [format_date]
+///
+/// This is the length of `+1471744-12-31`, the largest date in range.
+pub const DATE_MAX_LEN: usize = 14;
+
+/// Maximum length of a date and time written by [format_rfc3339]
+///
+/// This is the length of `+1471744-12-31T23:59:59.999999999+23:59`, the
+/// longest date and time in range with nine fractional digits and an offset.
+pub const RFC3339_MAX_LEN: usize = 39;
+
+/// Powers of ten used to truncate nanoseconds to fractional digits
+const POW10: [u32; 10] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+];
+
+/// Length of the year as written, including the sign if any
+#[inline]
+const fn year_len(y: i32) -> usize {
+    let n = y.unsigned_abs();
+    let digits = if n < 10_000 {
+        4
+    } else if n < 100_000 {
+        5
+    } else if n < 1_000_000 {
+        6
+    } else if n < 10_000_000 {
+        7
+    } else if n < 100_000_000 {
+        8
+    } else if n < 1_000_000_000 {
+        9
+    } else {
+        10
+    };
+    digits + (y < 0 || y > 9999) as usize
+}
+
+/// Write year, month and day as `YYYY-MM-DD`, returning the length written
+#[inline]
+const fn write_date(out: &mut [u8], (y, m, d): (i32, u8, u8)) -> usize {
+    let len = year_len(y);
+    let start = (y < 0 || y > 9999) as usize;
+    if y < 0 {
+        out[0] = b'-';
+    } else if y > 9999 {
+        out[0] = b'+';
+    }
+    let mut n = y.unsigned_abs();
+    let mut i = len;
+    while i > start {
+        i -= 1;
+        out[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+    out[len] = b'-';
+    write_2(out, len + 1, m);
+    out[len + 3] = b'-';
+    write_2(out, len + 4, d);
+    len + 6
+}
+
+/// Format year, month and day as an ISO 8601 date
+///
+/// Given a `(year, month, day)` tuple writes it as `YYYY-MM-DD` into the
+/// output buffer and returns the number of bytes written. Years outside `0` to
+/// `9999` are written with a sign and at least four digits. A buffer of
+/// [DATE_MAX_LEN] bytes is always enough.
+///
+/// Returns `None` if the output buffer is too small.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX).
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso8601::{format_date, DATE_MAX_LEN};
+///
+/// let mut out = [0u8; DATE_MAX_LEN];
+/// let len = format_date((2023, 5, 7), &mut out).unwrap();
+/// assert_eq!(&out[..len], b"2023-05-07");
+/// let len = format_date((-1, 12, 31), &mut out).unwrap();
+/// assert_eq!(&out[..len], b"-0001-12-31");
+/// let len = format_date((10000, 1, 1), &mut out).unwrap();
+/// assert_eq!(&out[..len], b"+10000-01-01");
+/// assert_eq!(format_date((10000, 1, 1), &mut [0u8; 11]), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are written directly at fixed positions after the year.
+#[inline]
+pub const fn format_date<const N: usize>((y, m, d): (i32, u8, u8), out: &mut [u8; N]) -> Option<usize> {
+    debug_assert!(y >= crate::YEAR_MIN && y <= crate::YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    if N < year_len(y) + 6 {
+        return None;
+    }
+    Some(write_date(out, (y, m, d)))
+}
+
+/// Format year, month, day, hours, minutes, seconds and nanoseconds as RFC 3339
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple,
+/// the number of fractional second digits and the offset from UTC in seconds,
+/// writes it as `YYYY-MM-DDThh:mm:ss.sssZ` into the output buffer and returns
+/// the number of bytes written. Nanoseconds are truncated to the given number
+/// of digits, and no fraction is written if the number of digits is zero. An
+/// offset of `None` is written as `Z`, and `Some(0)` as `+00:00`. Years outside
+/// `0` to `9999` are written with a sign and at least four digits. A buffer of
+/// [RFC3339_MAX_LEN] bytes is always enough.
+///
+/// Returns `None` if the output buffer is too small.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX).
+/// Month must be between `1` and `12`. Day must be between `1` and the number
+/// of days in the month in question. Hours must be between `0` and `23`.
+/// Minutes must be between `0` and `59`. Seconds must be between `0` and `59`.
+/// Nanoseconds must be between `0` and `999_999_999`. Fractional digits must
+/// be between `0` and `9`. Offset must be a whole number of minutes between
+/// `-86399` and `86399` seconds, the same range [parse_rfc3339] returns. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present
+/// in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso8601::{format_rfc3339, RFC3339_MAX_LEN};
+///
+/// let mut out = [0u8; RFC3339_MAX_LEN];
+/// let len = format_rfc3339((2023, 5, 7, 9, 24, 38, 500_000_000), 0, None, &mut out).unwrap();
+/// assert_eq!(&out[..len], b"2023-05-07T09:24:38Z");
+/// let len = format_rfc3339((2023, 5, 7, 9, 24, 38, 500_000_000), 3, None, &mut out).unwrap();
+/// assert_eq!(&out[..len], b"2023-05-07T09:24:38.500Z");
+/// let len = format_rfc3339((2023, 5, 7, 12, 24, 38, 123_456_789), 9, Some(10800), &mut out).unwrap();
+/// assert_eq!(&out[..len], b"2023-05-07T12:24:38.123456789+03:00");
+/// let len = format_rfc3339((-1, 12, 31, 23, 59, 59, 0), 0, Some(-1800), &mut out).unwrap();
+/// assert_eq!(&out[..len], b"-0001-12-31T23:59:59-00:30");
+/// assert_eq!(format_rfc3339((2023, 5, 7, 9, 24, 38, 0), 0, None, &mut [0u8; 19]), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are written directly at fixed positions after the year, with the
+/// fractional digits written from the end.
+#[inline]
+pub const fn format_rfc3339<const N: usize>(
+    (y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32),
+    digits: u8,
+    offset: Option<i32>,
+    out: &mut [u8; N],
+) -> Option<usize> {
+    debug_assert!(y >= crate::YEAR_MIN && y <= crate::YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+    debug_assert!(
+        nsec >= consts::NANOSECOND_MIN && nsec <= consts::NANOSECOND_MAX,
+        "given nanosecond is out of range"
+    );
+    debug_assert!(digits <= 9, "given fractional digits is out of range");
+    let digits = digits as usize;
+    let frac_len = if digits > 0 { digits + 1 } else { 0 };
+    let offset_len = if offset.is_some() { 6 } else { 1 };
+    let len = year_len(y) + 15 + frac_len + offset_len;
+    if N < len {
+        return None;
+    }
+    let mut i = write_date(out, (y, m, d));
+    out[i] = b'T';
+    write_2(out, i + 1, hh);
+    out[i + 3] = b':';
+    write_2(out, i + 4, mm);
+    out[i + 6] = b':';
+    write_2(out, i + 7, ss);
+    i += 9;
+    if digits > 0 {
+        out[i] = b'.';
+        let mut f = nsec / POW10[9 - digits];
+        let mut j = i + digits;
+        while j > i {
+            out[j] = b'0' + (f % 10) as u8;
+            f /= 10;
+            j -= 1;
+        }
+        i += frac_len;
+    }
+    match offset {
+        None => out[i] = b'Z',
+        Some(o) => {
+            debug_assert!(o > -86400 && o < 86400 && o % 60 == 0, "given offset is out of range");
+            out[i] = if o < 0 { b'-' } else { b'+' };
+            let o = o.unsigned_abs() / 60;
+            write_2(out, i + 1, (o / 60) as u8);
+            out[i + 3] = b':';
+            write_2(out, i + 4, (o % 60) as u8);
+        }
+    }
+    Some(len)
+}
+
+/// Flag set in the values returned by the digit parsing functions when the
+/// input contains something other than ASCII digits
+pub(crate) const NOT_DIGITS: u32 = 1 << 31;
+
+/// Parse one ASCII digit at the given position
+#[inline]
+pub(crate) const fn digits1(s: &[u8], i: usize) -> u32 {
+    let v = s[i].wrapping_sub(b'0') as u32;
+    v | ((v > 9) as u32) << 31
+}
+
+/// Parse two ASCII digits at the given position
+#[inline]
+pub(crate) const fn digits2(s: &[u8], i: usize) -> u32 {
+    let v = u16::from_le_bytes([s[i], s[i + 1]]).wrapping_sub(0x3030);
+    let bad = (v & 0xf0f0) | (v.wrapping_add(0x0606) & 0xf0f0);
+    ((v & 0xff) * 10 + (v >> 8)) as u32 | ((bad != 0) as u32) << 31
+}
+
+/// Parse three ASCII digits at the given position
+#[inline]
+const fn digits3(s: &[u8], i: usize) -> u32 {
+    let hi = digits2(s, i);
+    ((hi & !NOT_DIGITS) * 10 + digits1(s, i + 2)) | (hi & NOT_DIGITS)
+}
+
+/// Parse four ASCII digits at the given position
+#[inline]
+pub(crate) const fn digits4(s: &[u8], i: usize) -> u32 {
+    let v = u32::from_le_bytes([s[i], s[i + 1], s[i + 2], s[i + 3]]).wrapping_sub(0x3030_3030);
+    let bad = (v & 0xf0f0_f0f0) | (v.wrapping_add(0x0606_0606) & 0xf0f0_f0f0);
+    let v = v.wrapping_mul(10).wrapping_add(v >> 8) & 0x00ff_00ff;
+    let v = (v & 0xffff) * 100 + (v >> 16);
+    v | ((bad != 0) as u32) << 31
+}
+
+/// Parse eight ASCII digits at the given position
+#[inline]
+const fn digits8(s: &[u8], i: usize) -> u32 {
+    let b = [s[i], s[i + 1], s[i + 2], s[i + 3], s[i + 4], s[i + 5], s[i + 6], s[i + 7]];
+    let v = u64::from_le_bytes(b).wrapping_sub(0x3030_3030_3030_3030);
+    let bad = (v & 0xf0f0_f0f0_f0f0_f0f0) | (v.wrapping_add(0x0606_0606_0606_0606) & 0xf0f0_f0f0_f0f0_f0f0);
+    let v = v.wrapping_mul(10).wrapping_add(v >> 8) & 0x00ff_00ff_00ff_00ff;
+    let v = v.wrapping_mul(100).wrapping_add(v >> 16) & 0x0000_ffff_0000_ffff;
+    let v = v.wrapping_mul(10000).wrapping_add(v >> 32) & 0xffff_ffff;
+    v as u32 | ((bad != 0) as u32) << 31
+}
+
+/// Parse fractional second digits starting at the given position, returning
+/// nanoseconds and the position after the digits
+///
+/// Digits after the first nine are ignored.
+#[inline]
+const fn parse_fraction(s: &[u8], start: usize) -> (u32, usize) {
+    if start + 9 <= s.len() {
+        let hi = digits8(s, start);
+        let lo = digits1(s, start + 8);
+        if (hi | lo) & NOT_DIGITS == 0 {
+            let mut i = start + 9;
+            while i < s.len() && s[i].is_ascii_digit() {
+                i += 1;
+            }
+            return (hi * 10 + lo, i);
+        }
+    }
+    let mut i = start;
+    while i < s.len() && s[i].is_ascii_digit() {
+        i += 1;
+    }
+    let n = i - start;
+    let mut v = 0;
+    let mut j = start;
+    while j < i {
+        v = v * 10 + digits1(s, j);
+        j += 1;
+    }
+    (v * POW10[9 - n], i)
+}
+
+/// Kinds of dates recognized by [parse_date_fields]
+const CALENDAR: u8 = 0;
+const ORDINAL: u8 = 1;
+const WEEK: u8 = 2;
+
+/// Parse the date part of an ISO 8601 string without validating it,
+/// returning the year, the two remaining fields, the kind of date and the
+/// position after the date
+#[inline]
+const fn parse_date_fields(s: &[u8]) -> Result<(i32, u32, u32, u8, usize), ParseError> {
+    let len = s.len();
+    let (y, mut i) = if len > 0 && (s[0] == b'+' || s[0] == b'-') {
+        let mut i = 1;
+        let mut y = 0;
+        while i < len && s[i].is_ascii_digit() {
+            if i > 9 {
+                return Err(ParseError::OutOfRange);
+            }
+            y = y * 10 + digits1(s, i) as i32;
+            i += 1;
+        }
+        // expanded years are only allowed in the extended format
+        if i < 5 || i >= len || s[i] != b'-' {
+            return Err(ParseError::InvalidFormat);
+        }
+        (if s[0] == b'-' { -y } else { y }, i)
+    } else {
+        if len < 4 {
+            return Err(ParseError::InvalidFormat);
+        }
+        let y = digits4(s, 0);
+        if y & NOT_DIGITS != 0 {
+            return Err(ParseError::InvalidFormat);
+        }
+        (y as i32, 4)
+    };
+    let extended = i < len && s[i] == b'-';
+    if extended {
+        i += 1;
+    }
+    let (a, b, kind) = if i < len && s[i] == b'W' {
+        if extended && i + 5 <= len && s[i + 3] == b'-' {
+            i += 5;
+            (digits2(s, i - 4), digits1(s, i - 1), WEEK)
+        } else if !extended && i + 4 <= len {
+            i += 4;
+            (digits2(s, i - 3), digits1(s, i - 1), WEEK)
+        } else {
+            return Err(ParseError::InvalidFormat);
+        }
+    } else if extended && i + 5 <= len && s[i + 2] == b'-' {
+        i += 5;
+        (digits2(s, i - 5), digits2(s, i - 2), CALENDAR)
+    } else if extended && i + 3 <= len {
+        i += 3;
+        (digits3(s, i - 3), 0, ORDINAL)
+    } else if !extended && (i + 4 == len || (i + 4 < len && !s[i + 4].is_ascii_digit())) {
+        i += 4;
+        (digits2(s, i - 4), digits2(s, i - 2), CALENDAR)
+    } else if !extended && i + 3 <= len {
+        i += 3;
+        (digits3(s, i - 3), 0, ORDINAL)
+    } else {
+        return Err(ParseError::InvalidFormat);
+    };
+    if (a | b) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    Ok((y, a, b, kind, i))
+}
+
+/// Convert date fields parsed by [parse_date_fields] to a validated date
+#[inline]
+const fn resolve_date(y: i32, a: u32, b: u32, kind: u8) -> Result<(i32, u8, u8), ParseError> {
+    match kind {
+        CALENDAR => match validate_date((y, a as u8, b as u8)) {
+            Ok(()) => Ok((y, a as u8, b as u8)),
+            Err(_) => Err(ParseError::OutOfRange),
+        },
+        ORDINAL => {
+            if validate_date((y, 1, 1)).is_err() || a < 1 || a > days_in_year(y) as u32 {
+                return Err(ParseError::OutOfRange);
+            }
+            Ok(ordinal_to_date((y, a as u16)))
+        }
+        _ => match validate_isoweekdate((y, a as u8, b as u8)) {
+            Ok(()) => Ok(isoweekdate_to_date((y, a as u8, b as u8))),
+            Err(_) => Err(ParseError::OutOfRange),
+        },
+    }
+}
+
+/// Parse an ISO 8601 date
+///
+/// Given an ISO 8601 date returns a `(year, month, day)` tuple. Calendar dates
+/// (`2023-05-07` or `20230507`), ordinal dates (`2023-127` or `2023127`) and
+/// week dates (`2023-W18-7` or `2023W187`) are accepted in both the extended
+/// and basic formats. Years outside `0000` to `9999` are accepted with a sign
+/// and at least four digits in the extended format, such as `+10000-01-01`.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not an ISO 8601 date,
+/// and [ParseError::OutOfRange] if the date is not valid or not within
+/// [YEAR_MIN](crate::YEAR_MIN) and [YEAR_MAX](crate::YEAR_MAX).
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso8601::parse_date;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_date(b"2023-05-07"), Ok((2023, 5, 7)));
+/// assert_eq!(parse_date(b"20230507"), Ok((2023, 5, 7)));
+/// assert_eq!(parse_date(b"2023-127"), Ok((2023, 5, 7)));
+/// assert_eq!(parse_date(b"2023-W18-7"), Ok((2023, 5, 7)));
+/// assert_eq!(parse_date(b"-0001-12-31"), Ok((-1, 12, 31)));
+/// assert_eq!(parse_date(b"2023-02-29"), Err(ParseError::OutOfRange));
+/// assert_eq!(parse_date(b"2023-5-7"), Err(ParseError::InvalidFormat));
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are parsed two, four or eight at a time using SIMD within a
+/// register, with the date validated by [validate_date] or
+/// [validate_isoweekdate].
+#[inline]
+pub const fn parse_date(s: &[u8]) -> Result<(i32, u8, u8), ParseError> {
+    match parse_date_fields(s) {
+        Ok((y, a, b, kind, i)) => {
+            if i != s.len() {
+                return Err(ParseError::InvalidFormat);
+            }
+            resolve_date(y, a, b, kind)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Parse an offset from UTC at the given position, returning the offset in
+/// seconds if there is one and the position after it
+#[inline]
+const fn parse_offset(s: &[u8], mut i: usize) -> Result<(Option<i32>, usize), ParseError> {
+    let len = s.len();
+    if i >= len {
+        return Ok((None, i));
+    }
+    let neg = match s[i] {
+        b'Z' | b'z' => return Ok((Some(0), i + 1)),
+        b'+' => false,
+        b'-' => true,
+        _ => return Ok((None, i)),
+    };
+    if i + 3 > len {
+        return Err(ParseError::InvalidFormat);
+    }
+    let hh = digits2(s, i + 1);
+    i += 3;
+    let mm = if i + 3 <= len && s[i] == b':' {
+        i += 3;
+        digits2(s, i - 2)
+    } else if i + 2 <= len && s[i] != b':' {
+        i += 2;
+        digits2(s, i - 2)
+    } else if i == len {
+        0
+    } else {
+        return Err(ParseError::InvalidFormat);
+    };
+    if (hh | mm) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    if hh > 23 || mm > 59 {
+        return Err(ParseError::OutOfRange);
+    }
+    let offset = (hh * 3600 + mm * 60) as i32;
+    Ok((Some(if neg { -offset } else { offset }), i))
+}
+
+/// Parse a time of day at the given position, returning hours, minutes,
+/// seconds and nanoseconds and the position after them
+#[allow(clippy::type_complexity)]
+#[inline]
+const fn parse_time(s: &[u8], mut i: usize) -> Result<((u32, u32, u32, u32), usize), ParseError> {
+    let len = s.len();
+    if i + 2 > len {
+        return Err(ParseError::InvalidFormat);
+    }
+    let hh = digits2(s, i);
+    i += 2;
+    let (mut mm, mut ss, mut nsec) = (0, 0, 0);
+    let mut seconds = false;
+    if i + 3 <= len && s[i] == b':' {
+        mm = digits2(s, i + 1);
+        i += 3;
+        if i + 3 <= len && s[i] == b':' {
+            ss = digits2(s, i + 1);
+            i += 3;
+            seconds = true;
+        }
+    } else if i + 2 <= len && s[i].is_ascii_digit() {
+        mm = digits2(s, i);
+        i += 2;
+        if i + 2 <= len && s[i].is_ascii_digit() {
+            ss = digits2(s, i);
+            i += 2;
+            seconds = true;
+        }
+    }
+    if seconds && i < len && (s[i] == b'.' || s[i] == b',') {
+        let (n, j) = parse_fraction(s, i + 1);
+        if j == i + 1 {
+            return Err(ParseError::InvalidFormat);
+        }
+        nsec = n;
+        i = j;
+    }
+    if (hh | mm | ss) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    Ok(((hh, mm, ss, nsec), i))
+}
+
+/// Parse an ISO 8601 date and time
+///
+/// Given an ISO 8601 date, optionally followed by a time of day and an offset
+/// from UTC, returns a `(year, month, day, hours, minutes, seconds,
+/// nanoseconds)` tuple and the offset in seconds, or `None` if there is no
+/// offset. The date is accepted in all the forms [parse_date] accepts. The
+/// time is separated from the date by `T`, or a space as allowed by RFC 3339,
+/// and may be given as `hh:mm:ss`, `hh:mm`, `hhmmss`, `hhmm` or `hh`, with
+/// fractional seconds after a `.` or `,`. Fractional hours and minutes are not
+/// supported. The offset may be given as `Z`,
+/// `+hh:mm`, `+hhmm` or `+hh`. Fractional digits after the first nine are
+/// ignored.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not an ISO 8601 date
+/// and time, and [ParseError::OutOfRange] if the date, time or offset is not
+/// valid. Leap seconds and the end of day time `24:00:00` are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso8601::parse_datetime;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_datetime(b"2023-05-07T09:24:38Z"), Ok(((2023, 5, 7, 9, 24, 38, 0), Some(0))));
+/// assert_eq!(parse_datetime(b"20230507T092438,5+0300"), Ok(((2023, 5, 7, 9, 24, 38, 500_000_000), Some(10800))));
+/// assert_eq!(parse_datetime(b"2023-W18-7T09:24-05"), Ok(((2023, 5, 7, 9, 24, 0, 0), Some(-18000))));
+/// assert_eq!(parse_datetime(b"2023-05-07"), Ok(((2023, 5, 7, 0, 0, 0, 0), None)));
+/// assert_eq!(parse_datetime(b"2023-05-07T24:00:00"), Err(ParseError::OutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are parsed two, four or eight at a time using SIMD within a
+/// register, with the result validated by [validate_datetime].
+#[allow(clippy::type_complexity)]
+#[inline]
+pub const fn parse_datetime(s: &[u8]) -> Result<((i32, u8, u8, u8, u8, u8, u32), Option<i32>), ParseError> {
+    let (y, a, b, kind, i) = match parse_date_fields(s) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    let ((hh, mm, ss, nsec), offset) = if i == s.len() {
+        ((0, 0, 0, 0), None)
+    } else if s[i] == b'T' || s[i] == b't' || s[i] == b' ' {
+        let (time, i) = match parse_time(s, i + 1) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        let (offset, i) = match parse_offset(s, i) {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
+        if i != s.len() {
+            return Err(ParseError::InvalidFormat);
+        }
+        (time, offset)
+    } else {
+        return Err(ParseError::InvalidFormat);
+    };
+    let (y, m, d) = match resolve_date(y, a, b, kind) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    match validate_datetime((y, m, d, hh as u8, mm as u8, ss as u8)) {
+        Ok(()) => Ok(((y, m, d, hh as u8, mm as u8, ss as u8, nsec), offset)),
+        Err(_) => Err(ParseError::OutOfRange),
+    }
+}
+
+/// Parse an RFC 3339 date and time
+///
+/// Given an RFC 3339 date and time, such as `2023-05-07T09:24:38.5+03:00`,
+/// returns a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// and the offset from UTC in seconds. The date and time may be separated by
+/// `T`, `t` or a space, and the offset may be `Z`, `z` or `+hh:mm`. The
+/// unknown local offset `-00:00` is returned as zero. Fractional digits after
+/// the first nine are ignored.
+///
+/// This is much stricter than [parse_datetime], and should be preferred when
+/// only RFC 3339 input is expected.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not an RFC 3339 date
+/// and time, and [ParseError::OutOfRange] if the date, time or offset is not
+/// valid. Leap seconds are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::iso8601::parse_rfc3339;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38Z"), Ok(((2023, 5, 7, 9, 24, 38, 0), 0)));
+/// assert_eq!(parse_rfc3339(b"2023-05-07 12:24:38.123+03:00"), Ok(((2023, 5, 7, 12, 24, 38, 123_000_000), 10800)));
+/// assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38"), Err(ParseError::InvalidFormat));
+/// assert_eq!(parse_rfc3339(b"2023-05-07T09:24:60Z"), Err(ParseError::OutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Digits are parsed two or four at a time using SIMD within a register, with
+/// separators at fixed positions checked without branching, and the result
+/// validated by [validate_datetime].
+#[allow(clippy::type_complexity)]
+#[inline]
+pub const fn parse_rfc3339(s: &[u8]) -> Result<((i32, u8, u8, u8, u8, u8, u32), i32), ParseError> {
+    let len = s.len();
+    if len < 20 {
+        return Err(ParseError::InvalidFormat);
+    }
+    let y = digits4(s, 0);
+    let m = digits2(s, 5);
+    let d = digits2(s, 8);
+    let hh = digits2(s, 11);
+    let mm = digits2(s, 14);
+    let ss = digits2(s, 17);
+    let t = s[10] | 0x20;
+    let seps = (s[4] == b'-') & (s[7] == b'-') & ((t == b't') | (s[10] == b' ')) & (s[13] == b':') & (s[16] == b':');
+    if !seps || (y | m | d | hh | mm | ss) & NOT_DIGITS != 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    let mut i = 19;
+    let mut nsec = 0;
+    if s[i] == b'.' {
+        let (n, j) = parse_fraction(s, i + 1);
+        if j == i + 1 {
+            return Err(ParseError::InvalidFormat);
+        }
+        nsec = n;
+        i = j;
+    }
+    let offset = if i + 1 == len && (s[i] | 0x20) == b'z' {
+        0
+    } else if i + 6 == len && (s[i] == b'+' || s[i] == b'-') && s[i + 3] == b':' {
+        let oh = digits2(s, i + 1);
+        let om = digits2(s, i + 4);
+        if (oh | om) & NOT_DIGITS != 0 {
+            return Err(ParseError::InvalidFormat);
+        }
+        if oh > 23 || om > 59 {
+            return Err(ParseError::OutOfRange);
+        }
+        let offset = (oh * 3600 + om * 60) as i32;
+        if s[i] == b'-' {
+            -offset
+        } else {
+            offset
+        }
+    } else {
+        return Err(ParseError::InvalidFormat);
+    };
+    let (y, m, d, hh, mm, ss) = (y as i32, m as u8, d as u8, hh as u8, mm as u8, ss as u8);
+    match validate_datetime((y, m, d, hh, mm, ss)) {
+        Ok(()) => Ok(((y, m, d, hh, mm, ss, nsec), offset)),
+        Err(_) => Err(ParseError::OutOfRange),
+    }
+}
@@ -29,6 +29,30 @@
 //! assert_eq!(secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
 //! ```
 //!
+//! Millisecond, microsecond and nanosecond timestamps are supported as well, with
+//! the sub-second part returned as nanoseconds:
+//!
+//! ```
+//! use datealgo::{millis_to_datetime, datetime_to_nanos};
+//!
+//! assert_eq!(millis_to_datetime(1684574678123), (2023, 5, 20, 9, 24, 38, 123_000_000));
+//! assert_eq!(datetime_to_nanos((2023, 5, 20, 9, 24, 38, 123_456_789)), 1684574678123456789);
+//! ```
+//!
+//! Other epochs, binary and text formats and holidays have modules of their own:
+//!
+//! - `epoch`: NTP, Windows FILETIME, .NET, Cocoa, HFS and PostgreSQL timestamps
+//! - `excel`: Spreadsheet serial dates in the 1900 and 1904 date systems
+//! - `dos`: MS-DOS date and time words, as used by FAT and ZIP
+//! - `rtc`: BCD registers of real-time clocks, such as the DS3231 and PCF8563
+//! - `iso8601`: ISO 8601 and RFC 3339 dates and times, without allocation
+//! - `http`: HTTP dates in the IMF-fixdate, RFC 850 and `asctime()` formats
+//! - `rfc2822`: Email `Date` headers, including the obsolete syntax
+//! - `asn1`: ASN.1 `UTCTime` and `GeneralizedTime` in DER, as used by X.509
+//! - `strftime`: `strftime()` and `strptime()` style format strings
+//! - `easter`: Western and Orthodox Easter and the movable feasts depending on it
+//! - `holiday`: Public holidays from rule tables, with tables for a few countries
+//!
 //! If the `std` feature is enabled, there are also converters to and from
 //! `SystemTime`:
 //!
@@ -65,8 +89,11 @@
 //! The library does not expose any kind of `Date` or `DateTime` structures, but
 //! simply tuples for the necessary values. Bounds checking is done via
 //! `debug_assert` only, which means the methods are guaranteed to not panic in
-//! release builds. Callers are required to do their own bounds checking.
-//! Datatypes are selected as the smallest that will fit the value.
+//! release builds. Callers are required to do their own bounds checking, or
+//! use the `checked_` variants of the functions which return `None` for
+//! out of range input. The `validate_` functions can be used to find out
+//! which field of a tuple is invalid. Datatypes are selected as the smallest
+//! that will fit the value.
 //!
 //! Currently the library implements algorithms for the [Proleptic Gregorian
 //! Calendar](https://en.wikipedia.org/wiki/Proleptic_Gregorian_calendar) which
@@ -75,10 +102,20 @@
 //! fourth year to be a leap year, unless the year is divisible by 100 and not
 //! by 400.
 //!
+//! For historical dates, conversions for the [Proleptic Julian
+//! Calendar](https://en.wikipedia.org/wiki/Proleptic_Julian_calendar) are also
+//! provided. The Julian calendar defines every fourth year to be a leap year,
+//! without exception. The same Rata Die epoch is used for both calendars. The
+//! `historical_` functions combine the two, switching from the Julian to the
+//! Gregorian calendar at a configurable reform date.
+//!
 //! The algorithms do not account for leap seconds, as is customary for [Unix
 //! time](https://en.wikipedia.org/wiki/Unix_time). Every day is exactly 86400
 //! seconds in length, and the calculated times do not adjust for leap seconds
-//! between timestamps.
+//! between timestamps. Leap second aware conversions between UTC and TAI are
+//! available separately in the `leapsec` module, which can also parse and write
+//! the IERS `leap-seconds.list` and tzdata `leapseconds` files, and GPS, Galileo
+//! and BeiDou time scales in the `gnss` module.
 //!
 //! We define [Rata Die](https://en.wikipedia.org/wiki/Rata_Die) to be integral
 //! day numbers counted from 1st of January, 1970, which is the Unix epoch. We
@@ -142,6 +179,22 @@
 #[cfg(feature = "std")]
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+pub mod asn1;
+pub mod dos;
+pub mod easter;
+pub mod epoch;
+pub mod excel;
+pub mod gnss;
+pub mod holiday;
+pub mod http;
+pub mod iso8601;
+pub mod leapsec;
+pub mod rfc2822;
+pub mod rtc;
+mod sha1;
+pub mod strftime;
+mod util;
+
 /// Adjustment from Unix epoch to make calculations use positive integers
 ///
 /// Unit is eras, which is defined to be 400 years, as that is the period of the
@@ -162,6 +215,29 @@ const YEAR_OFFSET: i32 = ERA_OFFSET * YEARS_IN_ERA;
 const SECS_IN_DAY: i64 = 86400;
 /// Offset to be added to given second values
 const SECS_OFFSET: i64 = DAY_OFFSET as i64 * SECS_IN_DAY;
+/// Number of milliseconds in a second
+const MILLIS_IN_SEC: i64 = 1000;
+/// Number of microseconds in a second
+const MICROS_IN_SEC: i64 = 1_000_000;
+/// Number of nanoseconds in a second
+const NANOS_IN_SEC: u64 = 1_000_000_000;
+/// Number of nanoseconds in a day
+const NANOS_IN_DAY: u64 = SECS_IN_DAY as u64 * NANOS_IN_SEC;
+/// Number of days from Julian calendar 0000-03-01 to Unix epoch 1970-01-01
+const JULIAN_DAYS_TO_UNIX_EPOCH: i32 = 719470;
+/// Every Julian calendar four year cycle has 1461 days
+const JULIAN_DAYS_IN_CYCLE: i32 = 1461;
+/// Offset to be added to given Julian calendar day values, using the same year
+/// offset as the Gregorian calculations
+const JULIAN_DAY_OFFSET: i32 = YEAR_OFFSET / 4 * JULIAN_DAYS_IN_CYCLE + JULIAN_DAYS_TO_UNIX_EPOCH;
+/// Julian Day Number of Unix epoch 1970-01-01
+const JDN_UNIX_EPOCH: i32 = 2440588;
+/// Modified Julian Date of Unix epoch 1970-01-01
+const MJD_UNIX_EPOCH: i32 = 40587;
+/// Reduced Julian Date of Unix epoch 1970-01-01 at noon
+const RJD_UNIX_EPOCH: i32 = 40588;
+/// Truncated Julian Date of Unix epoch 1970-01-01
+const TJD_UNIX_EPOCH: i32 = 587;
 
 /// Minimum supported year for conversion
 ///
@@ -199,6 +275,30 @@ pub const RD_SECONDS_MIN: i64 = RD_MIN as i64 * SECS_IN_DAY;
 /// results.
 pub const RD_SECONDS_MAX: i64 = RD_MAX as i64 * SECS_IN_DAY + SECS_IN_DAY - 1;
 
+/// Minimum supported Julian calendar year for conversion
+///
+/// Years earlier than this are not supported and will likely produce incorrect
+/// results.
+pub const JULIAN_YEAR_MIN: i32 = -1467968;
+
+/// Maximum supported Julian calendar year for conversion
+///
+/// Years later than this are not supported and will likely produce incorrect
+/// results.
+pub const JULIAN_YEAR_MAX: i32 = 1471713;
+
+/// Minimum Rata Die for Julian calendar conversion
+///
+/// Rata die days earlier than this are not supported and will likely produce incorrect
+/// results.
+pub const JULIAN_RD_MIN: i32 = julian_date_to_rd((JULIAN_YEAR_MIN, 1, 1));
+
+/// Maximum Rata Die for Julian calendar conversion
+///
+/// Rata die days later than this are not supported and will likely produce incorrect
+/// results.
+pub const JULIAN_RD_MAX: i32 = julian_date_to_rd((JULIAN_YEAR_MAX, 12, 31));
+
 /// Convenience constants, mostly for input validation
 ///
 /// The use of these constants is strictly optional, as this is a low level
@@ -278,6 +378,172 @@ pub mod consts {
     pub const SUNDAY: u8 = 7;
 }
 
+/// Gregorian calendar reform dates
+///
+/// Each constant is the Rata Die of the first day observed in the Gregorian
+/// calendar in the given region, to be used as the `reform` argument of the
+/// `historical_` functions. The day before each of these was observed in the
+/// Julian calendar.
+pub mod reform {
+    use super::date_to_rd;
+
+    /// Original reform by the papal bull Inter gravissimas, Thursday 1582-10-04
+    /// was followed by Friday 1582-10-15 (Italy, Spain, Portugal, Poland)
+    pub const GREGORIAN: i32 = date_to_rd((1582, 10, 15));
+    /// France, 1582-12-09 was followed by 1582-12-20
+    pub const FRANCE: i32 = date_to_rd((1582, 12, 20));
+    /// Prussia, 1610-08-22 was followed by 1610-09-02
+    pub const PRUSSIA: i32 = date_to_rd((1610, 9, 2));
+    /// Denmark-Norway and Protestant Germany, 1700-02-18 was followed by 1700-03-01
+    pub const DENMARK: i32 = date_to_rd((1700, 3, 1));
+    /// Great Britain and its colonies, 1752-09-02 was followed by 1752-09-14
+    pub const GREAT_BRITAIN: i32 = date_to_rd((1752, 9, 14));
+    /// Sweden and Finland, 1753-02-17 was followed by 1753-03-01
+    ///
+    /// The Swedish calendar used between 1700 and 1712 is not modeled.
+    pub const SWEDEN: i32 = date_to_rd((1753, 3, 1));
+    /// Russia, 1918-01-31 was followed by 1918-02-14
+    pub const RUSSIA: i32 = date_to_rd((1918, 2, 14));
+    /// Greece, 1923-02-15 was followed by 1923-03-01
+    pub const GREECE: i32 = date_to_rd((1923, 3, 1));
+}
+
+/// Reason for a date or time value being invalid
+///
+/// Returned by the `validate_` functions to describe which field of the given
+/// tuple is out of range. For the fields that have a variable upper bound, the
+/// bound is included in the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DateError {
+    /// Year is not between [YEAR_MIN] and [YEAR_MAX]
+    YearOutOfRange,
+    /// Month is not between `1` and `12`
+    MonthOutOfRange,
+    /// Day is not between `1` and the number of days in the month
+    DayOutOfRange {
+        /// Number of days in the given month
+        max: u8,
+    },
+    /// Week is not between `1` and the number of ISO weeks in the year
+    WeekOutOfRange {
+        /// Number of ISO weeks in the given year
+        weeks_in_year: u8,
+    },
+    /// Day of week is not between `1` and `7`, or the date is after [YEAR_MAX]
+    WeekdayOutOfRange,
+    /// Hours is not between `0` and `23`
+    HourOutOfRange,
+    /// Minutes is not between `0` and `59`
+    MinuteOutOfRange,
+    /// Seconds is not between `0` and `59`
+    SecondOutOfRange,
+}
+
+impl core::fmt::Display for DateError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            DateError::YearOutOfRange => f.write_str("year is out of range"),
+            DateError::MonthOutOfRange => f.write_str("month is out of range"),
+            DateError::DayOutOfRange { max } => write!(f, "day is out of range (month has {} days)", max),
+            DateError::WeekOutOfRange { weeks_in_year } => {
+                write!(f, "week is out of range (year has {} weeks)", weeks_in_year)
+            }
+            DateError::WeekdayOutOfRange => f.write_str("weekday is out of range"),
+            DateError::HourOutOfRange => f.write_str("hour is out of range"),
+            DateError::MinuteOutOfRange => f.write_str("minute is out of range"),
+            DateError::SecondOutOfRange => f.write_str("second is out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateError {}
+
+/// Reason for parsing an input to fail
+///
+/// Returned by the parsing functions to describe why the given input was not
+/// accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Input does not follow the expected format
+    InvalidFormat,
+    /// A value in the input is out of its allowed range
+    OutOfRange,
+    /// Output table is too small for all the entries in the input
+    TableFull,
+    /// Input does not contain a hash to validate it against
+    MissingHash,
+    /// Hash in the input does not match the contents
+    HashMismatch,
+    /// Input does not contain an expiry time
+    MissingExpiry,
+    /// Day of week in the input does not match the date
+    WeekdayMismatch,
+}
+
+impl core::fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseError::InvalidFormat => f.write_str("invalid format"),
+            ParseError::OutOfRange => f.write_str("value is out of range"),
+            ParseError::TableFull => f.write_str("table is too small"),
+            ParseError::MissingHash => f.write_str("hash is missing"),
+            ParseError::HashMismatch => f.write_str("hash does not match"),
+            ParseError::MissingExpiry => f.write_str("expiry time is missing"),
+            ParseError::WeekdayMismatch => f.write_str("day of week does not match date"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Handling of a day past the end of the month in month arithmetic
+///
+/// Used by [add_months] and [add_years] to decide what to do when the day of
+/// the month does not exist in the resulting month, such as when adding one
+/// month to January 31st or one year to February 29th.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOverflow {
+    /// Use the last day of the resulting month, so that January 31st plus one
+    /// month is February 28th or 29th
+    Clamp,
+    /// Carry the excess days over into the following month, so that January
+    /// 31st plus one month is March 3rd or 2nd
+    RollOver,
+    /// Return `None`
+    Reject,
+}
+
+/// Three letter English month abbreviations
+const MONTH_ABBRS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// English month names
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Three letter English weekday abbreviations, starting from Monday
+const WEEKDAY_ABBRS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// English weekday names, starting from Monday
+const WEEKDAY_NAMES: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
 // OPTIMIZATION NOTES:
 // - addition and substraction is the same speed regardless of signed or unsigned
 // - addition and substraction is the same speed for u32 and u64
@@ -530,455 +796,458 @@ pub const fn date_to_weekday((y, m, d): (i32, u8, u8)) -> u8 {
     ((n.wrapping_mul(P32_OVER_SEVEN)) >> 29) as u8
 }
 
-/// Calculate next Gregorian date given a Gregorian date
+/// Calculate Rata Die of given day of week on or after a Rata Die
 ///
-/// Given a `(year, month, day)` tuple returns the `(year, month, day)` tuple
-/// for the following Gregorian date.
+/// Given a Rata Die and a day of week returns the Rata Die of the first day on
+/// or after it that falls on the given day of week. Day of week is given as a
+/// number between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
-/// and `12`. Day must be between `1` and the number of days in the month in
-/// question and the next date must not be after [YEAR_MAX]. Bounds are checked
-/// using `debug_assert` only, so that the checks are not present in release
-/// builds, similar to integer overflow checks.
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and so must the
+/// result. Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{next_date};
+/// use datealgo::{date_to_rd, weekday_on_or_after, consts::{FRIDAY, THURSDAY}};
 ///
-/// assert_eq!(next_date((2023, 5, 12)), (2023, 5, 13));
-/// assert_eq!(next_date((1970, 1, 1)), (1970, 1, 2));
-/// assert_eq!(next_date((2023, 1, 31)), (2023, 2, 1));
-/// assert_eq!(next_date((2023, 12, 31)), (2024, 1, 1));
+/// assert_eq!(weekday_on_or_after(date_to_rd((2023, 5, 12)), FRIDAY), date_to_rd((2023, 5, 12)));
+/// assert_eq!(weekday_on_or_after(date_to_rd((2023, 5, 12)), THURSDAY), date_to_rd((2023, 5, 18)));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Simple incrementation with manual overflow checking and carry. Relatively
-/// speedy, but not fully optimized.
+/// The difference of the days of week, given by [rd_to_weekday], is taken
+/// modulo 7 by adding 7 before the remainder so that it stays unsigned.
 #[inline]
-pub const fn next_date((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
-    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
-    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
-    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+pub const fn weekday_on_or_after(n: i32, wd: u8) -> i32 {
     debug_assert!(
-        y != YEAR_MAX || m != consts::MONTH_MAX || d != consts::DAY_MAX,
-        "next date is out of range"
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
     );
-    if d < 28 || d < days_in_month(y, m) {
-        (y, m, d + 1)
-    } else if m < 12 {
-        (y, m + 1, 1)
-    } else {
-        (y + 1, 1, 1)
-    }
+    let n = n + ((wd + 7 - rd_to_weekday(n)) % 7) as i32;
+    debug_assert!(n <= RD_MAX, "resulting rata die is out of range");
+    n
 }
 
-/// Calculate previous Gregorian date given a Gregorian date
+/// Calculate Rata Die of given day of week on or before a Rata Die
 ///
-/// Given a `(year, month, day)` tuple returns the `(year, month, day)` tuple
-/// for the preceding Gregorian date.
+/// Given a Rata Die and a day of week returns the Rata Die of the last day on
+/// or before it that falls on the given day of week. Day of week is given as a
+/// number between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
-/// and `12`. Day must be between `1`, the number of days in the month in
-/// question and the previous date must not be before [YEAR_MIN]. Bounds are
-/// checked using `debug_assert` only, so that the checks are not present in
-/// release builds, similar to integer overflow checks.
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and so must the
+/// result. Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{prev_date};
+/// use datealgo::{date_to_rd, weekday_on_or_before, consts::{FRIDAY, SATURDAY}};
 ///
-/// assert_eq!(prev_date((2023, 5, 12)), (2023, 5, 11));
-/// assert_eq!(prev_date((1970, 1, 1)), (1969, 12, 31));
-/// assert_eq!(prev_date((2023, 2, 1)), (2023, 1, 31));
-/// assert_eq!(prev_date((2024, 1, 1)), (2023, 12, 31));
+/// assert_eq!(weekday_on_or_before(date_to_rd((2023, 5, 12)), FRIDAY), date_to_rd((2023, 5, 12)));
+/// assert_eq!(weekday_on_or_before(date_to_rd((2023, 5, 12)), SATURDAY), date_to_rd((2023, 5, 6)));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Simple decrementation with manual underflow checking and carry. Relatively
-/// speedy, but not fully optimized.
+/// Same as [weekday_on_or_after], with the difference taken the other way.
 #[inline]
-pub const fn prev_date((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
-    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
-    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
-    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+pub const fn weekday_on_or_before(n: i32, wd: u8) -> i32 {
     debug_assert!(
-        y != YEAR_MIN || m != consts::MONTH_MIN || d != consts::DAY_MIN,
-        "previous date is out of range"
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
     );
-    if d > 1 {
-        (y, m, d - 1)
-    } else if m > 1 {
-        (y, m - 1, days_in_month(y, m - 1))
-    } else {
-        (y - 1, 12, 31)
-    }
+    let n = n - ((rd_to_weekday(n) + 7 - wd) % 7) as i32;
+    debug_assert!(n >= RD_MIN, "resulting rata die is out of range");
+    n
 }
 
-/// Split total seconds to days, hours, minutes and seconds
+/// Calculate Rata Die of next given day of week after a Rata Die
 ///
-/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(days,
-/// hours, minutes, seconds)` tuple.
+/// Given a Rata Die and a day of week returns the Rata Die of the first day
+/// strictly after it that falls on the given day of week, so that the next
+/// Friday from a Friday is a week later. Day of week is given as a number
+/// between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
 ///
 /// # Panics
 ///
-/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
-/// Bounds are checked using `debug_assert` only, so that the checks are not
-/// present in release builds, similar to integer overflow checks.
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and so must the
+/// result. Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{secs_to_dhms, date_to_rd};
+/// use datealgo::{date_to_rd, next_weekday, consts::{FRIDAY, MONDAY}};
 ///
-/// assert_eq!(secs_to_dhms(0), (0, 0, 0, 0));
-/// assert_eq!(secs_to_dhms(86400), (1, 0, 0, 0));
-/// assert_eq!(secs_to_dhms(86399), (0, 23, 59, 59));
-/// assert_eq!(secs_to_dhms(-1), (-1, 23, 59, 59));
-/// assert_eq!(secs_to_dhms(1684574678), (date_to_rd((2023, 5, 20)), 9, 24, 38));
+/// assert_eq!(next_weekday(date_to_rd((2023, 5, 12)), FRIDAY), date_to_rd((2023, 5, 19)));
+/// assert_eq!(next_weekday(date_to_rd((2023, 5, 12)), MONDAY), date_to_rd((2023, 5, 15)));
 /// ```
 ///
 /// # Algorithm
 ///
-/// See examples 14 and 15 of:
-///
-/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
-/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
-/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+/// Same as [weekday_on_or_after], with the difference taken from the next day
+/// of week and offset by one.
 #[inline]
-pub const fn secs_to_dhms(secs: i64) -> (i32, u8, u8, u8) {
+pub const fn next_weekday(n: i32, wd: u8) -> i32 {
     debug_assert!(
-        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
-        "given seconds value is out of range"
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
     );
-    // Algorithm is based on the following identities valid for all n in [0, 97612919[.
-    //
-    // n / 60 = 71582789 * n / 2^32,
-    // n % 60 = 71582789 * n % 2^32 / 71582789.
-    //
-    // `SECS_IN_DAY` obviously fits within these bounds
-    let secs = if secs > RD_SECONDS_MAX { 0 } else { secs }; // allows compiler to optimize more
-    let secs = (secs + SECS_OFFSET) as u64;
-    let days = (secs / SECS_IN_DAY as u64) as u32;
-    let secs = secs % SECS_IN_DAY as u64; // secs in [0, SECS_IN_DAY[ => secs in [0, 97612919[
-
-    let prd = 71582789 * secs;
-    let mins = prd >> 32; // secs / 60
-    let ss = (prd as u32) / 71582789; // secs % 60
-
-    let prd = 71582789 * mins;
-    let hh = prd >> 32; // mins / 60
-    let mm = (prd as u32) / 71582789; // mins % 60
-
-    let days = (days as i32) - DAY_OFFSET;
-    (days, hh as u8, mm as u8, ss as u8)
+    let n = n + 1 + ((wd + 6 - rd_to_weekday(n)) % 7) as i32;
+    debug_assert!(n <= RD_MAX, "resulting rata die is out of range");
+    n
 }
 
-/// Combine days, hours, minutes and seconds to total seconds
+/// Calculate Rata Die of previous given day of week before a Rata Die
 ///
-/// Given a `(days, hours, minutes, seconds)` tuple from Unix epoch (January
-/// 1st, 1970) returns the total seconds.
+/// Given a Rata Die and a day of week returns the Rata Die of the last day
+/// strictly before it that falls on the given day of week, so that the
+/// previous Friday from a Friday is a week earlier. Day of week is given as a
+/// number between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
 ///
 /// # Panics
 ///
-/// Days must be between [RD_MIN] and [RD_MAX] inclusive. Hours must be between
-/// `0` and `23`. Minutes must be between `0` and `59`. Seconds must be between
-/// `0` and `59`. Bounds are checked using `debug_assert` only, so that the
-/// checks are not present in release builds, similar to integer overflow
-/// checks.
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive, and so must the
+/// result. Day of week must be between `1` and `7`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{dhms_to_secs, date_to_rd};
+/// use datealgo::{date_to_rd, prev_weekday, consts::{FRIDAY, SUNDAY}};
 ///
-/// assert_eq!(dhms_to_secs((0, 0, 0, 0)), 0);
-/// assert_eq!(dhms_to_secs((1, 0, 0, 0)), 86400);
-/// assert_eq!(dhms_to_secs((0, 23, 59, 59)), 86399);
-/// assert_eq!(dhms_to_secs((-1, 0, 0, 0)), -86400);
-/// assert_eq!(dhms_to_secs((-1, 0, 0, 1)), -86399);
-/// assert_eq!(dhms_to_secs((date_to_rd((2023, 5, 20)), 9, 24, 38)), 1684574678)
+/// assert_eq!(prev_weekday(date_to_rd((2023, 5, 12)), FRIDAY), date_to_rd((2023, 5, 5)));
+/// assert_eq!(prev_weekday(date_to_rd((2023, 5, 12)), SUNDAY), date_to_rd((2023, 5, 7)));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is simple multiplication, method provided only as convenience.
+/// Same as [weekday_on_or_before], with the difference taken from the
+/// previous day of week and offset by one.
 #[inline]
-pub const fn dhms_to_secs((d, h, m, s): (i32, u8, u8, u8)) -> i64 {
-    debug_assert!(d >= RD_MIN && d <= RD_MAX, "given rata die is out of range");
-    debug_assert!(h >= consts::HOUR_MIN && h <= consts::HOUR_MAX, "given hour is out of range");
-    debug_assert!(m >= consts::MINUTE_MIN && m <= consts::MINUTE_MAX, "given minute is out of range");
-    debug_assert!(s >= consts::SECOND_MIN && s <= consts::SECOND_MAX, "given second is out of range");
-    if d >= RD_MIN && d <= RD_MAX {
-        d as i64 * SECS_IN_DAY + h as i64 * 3600 + m as i64 * 60 + s as i64
-    } else {
-        0
-    }
+pub const fn prev_weekday(n: i32, wd: u8) -> i32 {
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    let n = n - 1 - ((rd_to_weekday(n) + 6 - wd) % 7) as i32;
+    debug_assert!(n >= RD_MIN, "resulting rata die is out of range");
+    n
 }
 
-/// Convert total seconds to year, month, day, hours, minutes and seconds
+/// Calculate day of month of the nth given day of week in a month
 ///
-/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(year,
-/// month, day, hours, minutes, seconds)` tuple.
+/// Given a year, month, day of week and `n` returns the day of month of the
+/// `n`th occurrence of the day of week in the month, such as the third Friday,
+/// or `None` if there is no such day, as only some months have a fifth
+/// occurrence. Day of week is given as a number between 1 and 7, with `1`
+/// meaning Monday and `7` meaning Sunday.
 ///
 /// # Panics
 ///
-/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
-/// Bounds are checked using `debug_assert` only, so that the checks are not
-/// present in release builds, similar to integer overflow checks.
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day of week must be between `1` and `7`. `n` must be between `1`
+/// and `5`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::secs_to_datetime;
+/// use datealgo::{nth_weekday_of_month, consts::{FRIDAY, MONDAY}};
 ///
-/// assert_eq!(secs_to_datetime(0), (1970, 1, 1, 0, 0, 0));
-/// assert_eq!(secs_to_datetime(86400), (1970, 1, 2, 0, 0, 0));
-/// assert_eq!(secs_to_datetime(86399), (1970, 1, 1, 23, 59, 59));
-/// assert_eq!(secs_to_datetime(-1), (1969, 12, 31, 23, 59, 59));
-/// assert_eq!(secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
+/// assert_eq!(nth_weekday_of_month(2023, 5, FRIDAY, 3), Some(19));
+/// assert_eq!(nth_weekday_of_month(2023, 5, MONDAY, 1), Some(1));
+/// assert_eq!(nth_weekday_of_month(2023, 5, MONDAY, 5), Some(29));
+/// assert_eq!(nth_weekday_of_month(2023, 5, FRIDAY, 5), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Combination of existing functions for convenience only.
+/// The first occurrence is found from the day of week of the first day of the
+/// month, given by [date_to_weekday], after which whole weeks are added and
+/// the result compared against [days_in_month].
 #[inline]
-pub const fn secs_to_datetime(secs: i64) -> (i32, u8, u8, u8, u8, u8) {
-    let (days, hh, mm, ss) = secs_to_dhms(secs);
-    let (y, m, s) = rd_to_date(days);
-    (y, m, s, hh, mm, ss)
+pub const fn nth_weekday_of_month(y: i32, m: u8, wd: u8, n: u8) -> Option<u8> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    debug_assert!(n >= 1 && n <= 5, "given n is out of range");
+    let d = 1 + (wd + 7 - date_to_weekday((y, m, 1))) % 7 + 7 * (n - 1);
+    if d <= days_in_month(y, m) {
+        Some(d)
+    } else {
+        None
+    }
 }
 
-/// Convert year, month, day, hours, minutes and seconds to total seconds
+/// Calculate day of month of the last given day of week in a month
 ///
-/// Given a `(year, month, day, hours, minutes, seconds)` tuple from Unix epoch
-/// (January 1st, 1970) returns the total seconds.
+/// Given a year, month and day of week returns the day of month of the last
+/// occurrence of the day of week in the month, such as the last Monday.
+/// Day of week is given as a number between 1 and 7, with `1` meaning Monday
+/// and `7` meaning Sunday.
 ///
 /// # Panics
 ///
 /// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
-/// and `12`. Day must be between `1` and the number of days in the month in
-/// question. Hours must be between `0` and `23`. Minutes must be between `0`
-/// and `59`. Seconds must be between `0` and `59`. Bounds are checked using
+/// and `12`. Day of week must be between `1` and `7`. Bounds are checked using
 /// `debug_assert` only, so that the checks are not present in release builds,
 /// similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::datetime_to_secs;
+/// use datealgo::{last_weekday_of_month, consts::{MONDAY, WEDNESDAY}};
 ///
-/// assert_eq!(datetime_to_secs((1970, 1, 1, 0, 0, 0)), 0);
-/// assert_eq!(datetime_to_secs((1970, 1, 2, 0, 0, 0)), 86400);
-/// assert_eq!(datetime_to_secs((1970, 1, 1, 23, 59, 59)), 86399);
-/// assert_eq!(datetime_to_secs((1969, 12, 31, 0, 0, 0)), -86400);
-/// assert_eq!(datetime_to_secs((1969, 12, 31, 0, 0, 1)), -86399);
-/// assert_eq!(datetime_to_secs((2023, 5, 20, 9, 24, 38)), 1684574678)
+/// assert_eq!(last_weekday_of_month(2023, 5, MONDAY), 29);
+/// assert_eq!(last_weekday_of_month(2023, 5, WEDNESDAY), 31);
+/// assert_eq!(last_weekday_of_month(2023, 2, MONDAY), 27);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is simple multiplication, method provided only as convenience.
+/// Same as [nth_weekday_of_month], but going backwards from the last day of
+/// the month, given by [days_in_month].
 #[inline]
-pub const fn datetime_to_secs((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> i64 {
-    let days = date_to_rd((y, m, d));
-    dhms_to_secs((days, hh, mm, ss))
+pub const fn last_weekday_of_month(y: i32, m: u8, wd: u8) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(
+        wd >= consts::WEEKDAY_MIN && wd <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    let last = days_in_month(y, m);
+    last - (date_to_weekday((y, m, last)) + 7 - wd) % 7
 }
 
-/// Determine if the given year is a leap year
+/// Calculate next Gregorian date given a Gregorian date
+///
+/// Given a `(year, month, day)` tuple returns the `(year, month, day)` tuple
+/// for the following Gregorian date.
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are checked
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question and the next date must not be after [YEAR_MAX]. Bounds are checked
 /// using `debug_assert` only, so that the checks are not present in release
 /// builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::is_leap_year;
+/// use datealgo::{next_date};
 ///
-/// assert_eq!(is_leap_year(2023), false);
-/// assert_eq!(is_leap_year(2024), true);
-/// assert_eq!(is_leap_year(2100), false);
-/// assert_eq!(is_leap_year(2400), true);
+/// assert_eq!(next_date((2023, 5, 12)), (2023, 5, 13));
+/// assert_eq!(next_date((1970, 1, 1)), (1970, 1, 2));
+/// assert_eq!(next_date((2023, 1, 31)), (2023, 2, 1));
+/// assert_eq!(next_date((2023, 12, 31)), (2024, 1, 1));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is Neri-Schneider from C++now 2023 conference:
-/// > <https://github.com/boostcon/cppnow_presentations_2023/blob/main/cppnow_slides/Speeding_Date_Implementing_Fast_Calendar_Algorithms.pdf>
+/// Simple incrementation with manual overflow checking and carry. Relatively
+/// speedy, but not fully optimized.
 #[inline]
-pub const fn is_leap_year(y: i32) -> bool {
+pub const fn next_date((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
     debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
-    // Using `%` instead of `&` causes compiler to emit branches instead. This
-    // is faster in a tight loop due to good branch prediction, but probably
-    // slower in a real program so we use `&`. Also `% 25` is functionally
-    // equivalent to `% 100` here, but a little cheaper to compute. If branches
-    // were to be emitted, using `% 100` would be most likely faster due to
-    // better branch prediction.
-    if (y % 25) != 0 {
-        y & 3 == 0
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(
+        y != YEAR_MAX || m != consts::MONTH_MAX || d != consts::DAY_MAX,
+        "next date is out of range"
+    );
+    if d < 28 || d < days_in_month(y, m) {
+        (y, m, d + 1)
+    } else if m < 12 {
+        (y, m + 1, 1)
     } else {
-        y & 15 == 0
+        (y + 1, 1, 1)
     }
 }
 
-/// Determine the number of days in the given month in the given year
+/// Calculate previous Gregorian date given a Gregorian date
+///
+/// Given a `(year, month, day)` tuple returns the `(year, month, day)` tuple
+/// for the preceding Gregorian date.
 ///
 /// # Panics
 ///
 /// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
-/// and `12`. Bounds are checked using `debug_assert` only, so that the checks
-/// are not present in release builds, similar to integer overflow checks.
+/// and `12`. Day must be between `1`, the number of days in the month in
+/// question and the previous date must not be before [YEAR_MIN]. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
 ///
-/// # Example
+/// # Examples
 ///
 /// ```
-/// use datealgo::days_in_month;
+/// use datealgo::{prev_date};
 ///
-/// assert_eq!(days_in_month(2023, 1), 31);
-/// assert_eq!(days_in_month(2023, 2), 28);
-/// assert_eq!(days_in_month(2023, 4), 30);
-/// assert_eq!(days_in_month(2024, 1), 31);
-/// assert_eq!(days_in_month(2024, 2), 29);
-/// assert_eq!(days_in_month(2024, 4), 30);
+/// assert_eq!(prev_date((2023, 5, 12)), (2023, 5, 11));
+/// assert_eq!(prev_date((1970, 1, 1)), (1969, 12, 31));
+/// assert_eq!(prev_date((2023, 2, 1)), (2023, 1, 31));
+/// assert_eq!(prev_date((2024, 1, 1)), (2023, 12, 31));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is Neri-Schneider from C++now 2023 conference:
-/// > <https://github.com/boostcon/cppnow_presentations_2023/blob/main/cppnow_slides/Speeding_Date_Implementing_Fast_Calendar_Algorithms.pdf>
+/// Simple decrementation with manual underflow checking and carry. Relatively
+/// speedy, but not fully optimized.
 #[inline]
-pub const fn days_in_month(y: i32, m: u8) -> u8 {
+pub const fn prev_date((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
     debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
-    if m != 2 {
-        30 | (m ^ (m >> 3))
-    } else if is_leap_year(y) {
-        29
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(
+        y != YEAR_MIN || m != consts::MONTH_MIN || d != consts::DAY_MIN,
+        "previous date is out of range"
+    );
+    if d > 1 {
+        (y, m, d - 1)
+    } else if m > 1 {
+        (y, m - 1, days_in_month(y, m - 1))
     } else {
-        28
+        (y - 1, 12, 31)
     }
 }
 
-/// Convert Rata Die to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+/// Apply a day overflow policy to a possibly nonexistent date
+#[inline]
+const fn resolve_day_overflow(y: i32, m: u8, d: u8, overflow: DayOverflow) -> Option<(i32, u8, u8)> {
+    let max = days_in_month(y, m);
+    if d <= max {
+        return Some((y, m, d));
+    }
+    match overflow {
+        DayOverflow::Clamp => Some((y, m, max)),
+        // December has 31 days, so the overflow never crosses a year and the
+        // at most 3 excess days always fit in the following month
+        DayOverflow::RollOver => Some((y, m + 1, d - max)),
+        DayOverflow::Reject => None,
+    }
+}
+
+/// Add months to a Gregorian date
 ///
-/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
-/// week, day of week)` tuple. Week is the ISO week number, with the first week
-/// of the year being the week containing the first Thursday of the year. Day of
-/// week is between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
+/// Given a `(year, month, day)` tuple and a number of months, which may be
+/// negative, returns the `(year, month, day)` tuple for the same day of the
+/// month that many months later. If the day does not exist in the resulting
+/// month, the result depends on the given [DayOverflow] policy.
 ///
-/// Compared to Gregorian date, the first one to three days of the year might
-/// belong to a week in the previous year, and the last one to three days of the
-/// year might belong to a week in the next year. Also some years have 53 weeks
-/// instead of 52.
+/// Returns `None` if the resulting year is not between [YEAR_MIN] and
+/// [YEAR_MAX], or if the day does not exist in the resulting month and the
+/// policy is [DayOverflow::Reject].
 ///
 /// # Panics
 ///
-/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
-/// using `debug_assert` only, so that the checks are not present in release
-/// builds, similar to integer overflow checks.
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{rd_to_isoweekdate, date_to_rd};
-///
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((2023, 5, 12))), (2023, 19, 5));
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((1970, 1, 1))), (1970, 1, 4));
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((2023, 1, 1))), (2022, 52, 7));
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((1979, 12, 31))), (1980, 1, 1));
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((1981, 12, 31))), (1981, 53, 4));
-/// assert_eq!(rd_to_isoweekdate(date_to_rd((1982, 1, 1))), (1981, 53, 5));
+/// use datealgo::{add_months, DayOverflow, YEAR_MAX};
+///
+/// assert_eq!(add_months((2023, 5, 12), 1, DayOverflow::Reject), Some((2023, 6, 12)));
+/// assert_eq!(add_months((2023, 5, 12), -17, DayOverflow::Reject), Some((2021, 12, 12)));
+/// assert_eq!(add_months((2023, 1, 31), 1, DayOverflow::Clamp), Some((2023, 2, 28)));
+/// assert_eq!(add_months((2024, 1, 31), 1, DayOverflow::Clamp), Some((2024, 2, 29)));
+/// assert_eq!(add_months((2023, 1, 31), 1, DayOverflow::RollOver), Some((2023, 3, 3)));
+/// assert_eq!(add_months((2023, 1, 31), 1, DayOverflow::Reject), None);
+/// assert_eq!(add_months((YEAR_MAX, 12, 1), 1, DayOverflow::Clamp), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is hand crafted and not significantly optimized.
+/// The date is converted to a count of months, to which the delta is added
+/// using 64-bit arithmetic so that no delta can overflow. The result is split
+/// back to year and month with Euclidean division, after which the policy is
+/// applied if the day is past the end of the month. Rolling over never
+/// crosses a year boundary, as December has 31 days.
 #[inline]
-pub const fn rd_to_isoweekdate(rd: i32) -> (i32, u8, u8) {
-    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
-    let wd = rd_to_weekday(rd);
-    let rdt = rd + (4 - wd as i32) % 7;
-    let (y, _, _) = rd_to_date(rdt);
-    let ys = date_to_rd((y, 1, 1));
-    let w = (rdt - ys) / 7 + 1;
-    (y, w as u8, wd)
+pub const fn add_months((y, m, d): (i32, u8, u8), n: i32, overflow: DayOverflow) -> Option<(i32, u8, u8)> {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let months = y as i64 * 12 + (m - 1) as i64 + n as i64;
+    let y = months.div_euclid(12);
+    if y < YEAR_MIN as i64 || y > YEAR_MAX as i64 {
+        return None;
+    }
+    resolve_day_overflow(y as i32, (months.rem_euclid(12) + 1) as u8, d, overflow)
 }
 
-/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to Rata Die
+/// Add years to a Gregorian date
 ///
-/// Given a `(year, week, day of week)` tuple returns the days since Unix epoch
-/// (January 1st, 1970). Week is the ISO week number, with the first week of the
-/// year being the week containing the first Thursday of the year. Day of week
-/// is between 1 and 7, with `1` meaning Monday and `7` meaning Sunday. Dates
-/// before the epoch produce negative values.
+/// Given a `(year, month, day)` tuple and a number of years, which may be
+/// negative, returns the `(year, month, day)` tuple for the same month and day
+/// that many years later. If the date is February 29th and the resulting year
+/// is not a leap year, the result depends on the given [DayOverflow] policy.
 ///
-/// Compared to Gregorian date, the first one to three days of the year might
-/// belong to a week in the previous year, and the last one to three days of the
-/// year might belong to a week in the next year. Also some years have 53 weeks
-/// instead of 52.
+/// Returns `None` if the resulting year is not between [YEAR_MIN] and
+/// [YEAR_MAX], or if the day does not exist in the resulting month and the
+/// policy is [DayOverflow::Reject].
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1` and
-/// the number of ISO weeks in the given year (52 or 53). Day must be between
-/// `1` and `7`. Bounds are checked using `debug_assert` only, so that the
-/// checks are not present in release builds, similar to integer overflow
-/// checks.
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{isoweekdate_to_rd, date_to_rd};
-///
-/// assert_eq!(isoweekdate_to_rd((2023, 19, 5)), date_to_rd((2023, 5, 12)));
-/// assert_eq!(isoweekdate_to_rd((1970, 1, 4)), date_to_rd((1970, 1, 1)));
-/// assert_eq!(isoweekdate_to_rd((2022, 52, 7)), date_to_rd((2023, 1, 1)));
-/// assert_eq!(isoweekdate_to_rd((1980, 1, 1)), date_to_rd((1979, 12, 31)));
-/// assert_eq!(isoweekdate_to_rd((1981, 53, 4)), date_to_rd((1981, 12, 31)));
-/// assert_eq!(isoweekdate_to_rd((1981, 53, 5)), date_to_rd((1982, 1, 1)));
+/// use datealgo::{add_years, DayOverflow, YEAR_MIN};
+///
+/// assert_eq!(add_years((2023, 5, 12), 10, DayOverflow::Reject), Some((2033, 5, 12)));
+/// assert_eq!(add_years((2023, 5, 12), -2024, DayOverflow::Reject), Some((-1, 5, 12)));
+/// assert_eq!(add_years((2024, 2, 29), 1, DayOverflow::Clamp), Some((2025, 2, 28)));
+/// assert_eq!(add_years((2024, 2, 29), 1, DayOverflow::RollOver), Some((2025, 3, 1)));
+/// assert_eq!(add_years((2024, 2, 29), 1, DayOverflow::Reject), None);
+/// assert_eq!(add_years((2024, 2, 29), 4, DayOverflow::Reject), Some((2028, 2, 29)));
+/// assert_eq!(add_years((YEAR_MIN, 1, 1), -1, DayOverflow::Clamp), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is hand crafted and not significantly optimized.
+/// The delta is added using 64-bit arithmetic so that no delta can overflow,
+/// after which the policy is applied if the day is past the end of the month.
 #[inline]
-pub const fn isoweekdate_to_rd((y, w, d): (i32, u8, u8)) -> i32 {
+pub const fn add_years((y, m, d): (i32, u8, u8), n: i32, overflow: DayOverflow) -> Option<(i32, u8, u8)> {
     debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
-    debug_assert!(w >= consts::WEEK_MIN && w <= isoweeks_in_year(y), "given week is out of range");
-    debug_assert!(
-        d >= consts::WEEKDAY_MIN && d <= consts::WEEKDAY_MAX,
-        "given weekday is out of range"
-    );
-    debug_assert!(
-        y != YEAR_MAX || w != consts::WEEK_MAX || d <= consts::THURSDAY,
-        "given weekday is out of range (for last week of range)"
-    );
-    let rd4 = date_to_rd((y, 1, 4));
-    let wd4 = rd_to_weekday(rd4);
-    let ys = rd4 - (wd4 - 1) as i32;
-    ys + (w as i32 - 1) * 7 + (d as i32 - 1)
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let y = y as i64 + n as i64;
+    if y < YEAR_MIN as i64 || y > YEAR_MAX as i64 {
+        return None;
+    }
+    resolve_day_overflow(y as i32, m, d, overflow)
 }
 
-/// Convert Gregorian date to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+/// Calculate whole months between two Gregorian dates
 ///
-/// Given a `(year, month, day)` tuple returns a `(year, week, day of week)`
-/// tuple. Week is the ISO week number, with the first week of the year being
-/// the week containing the first Thursday of the year. Day of week is between
-/// 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
-///
-/// Compared to Gregorian date, the first one to three days of the year might
-/// belong to a week in the previous year, and the last one to three days of the
-/// year might belong to a week in the next year. Also some years have 53 weeks
-/// instead of 52.
+/// Given two `(year, month, day)` tuples returns the number of whole months
+/// from the first date to the second, which is negative if the second date is
+/// before the first. This is the largest number of months that can be added to
+/// the first date with [add_months] and [DayOverflow::Clamp] without passing
+/// the second date, so that January 31st to February 28th is one month.
 ///
 /// # Panics
 ///
@@ -990,287 +1259,2781 @@ pub const fn isoweekdate_to_rd((y, w, d): (i32, u8, u8)) -> i32 {
 /// # Examples
 ///
 /// ```
-/// use datealgo::{date_to_isoweekdate};
+/// use datealgo::months_between;
 ///
-/// assert_eq!(date_to_isoweekdate((2023, 5, 12)), (2023, 19, 5));
-/// assert_eq!(date_to_isoweekdate((1970, 1, 1)), (1970, 1, 4));
-/// assert_eq!(date_to_isoweekdate((2023, 1, 1)), (2022, 52, 7));
-/// assert_eq!(date_to_isoweekdate((1979, 12, 31)), (1980, 1, 1));
-/// assert_eq!(date_to_isoweekdate((1981, 12, 31)), (1981, 53, 4));
-/// assert_eq!(date_to_isoweekdate((1982, 1, 1)), (1981, 53, 5));
+/// assert_eq!(months_between((2023, 5, 12), (2023, 7, 12)), 2);
+/// assert_eq!(months_between((2023, 5, 12), (2023, 7, 11)), 1);
+/// assert_eq!(months_between((2023, 1, 31), (2023, 2, 28)), 1);
+/// assert_eq!(months_between((2023, 7, 12), (2023, 5, 13)), -1);
+/// assert_eq!(months_between((2023, 5, 12), (2023, 5, 1)), 0);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Simply converts date to rata die and then rata die to ISO week date.
+/// The difference of the months is adjusted by one towards zero if the day of
+/// the first date, clamped to the length of the month of the second date, is
+/// past the day of the second date in the direction of the difference.
 #[inline]
-pub const fn date_to_isoweekdate((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
-    let rd = date_to_rd((y, m, d));
-    rd_to_isoweekdate(rd)
+pub const fn months_between((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> i32 {
+    debug_assert!(ya >= YEAR_MIN && ya <= YEAR_MAX, "given year is out of range");
+    debug_assert!(ma >= consts::MONTH_MIN && ma <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(da >= consts::DAY_MIN && da <= days_in_month(ya, ma), "given day is out of range");
+    debug_assert!(yb >= YEAR_MIN && yb <= YEAR_MAX, "given year is out of range");
+    debug_assert!(mb >= consts::MONTH_MIN && mb <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(db >= consts::DAY_MIN && db <= days_in_month(yb, mb), "given day is out of range");
+    let n = (yb - ya) * 12 + mb as i32 - ma as i32;
+    let max = days_in_month(yb, mb);
+    let d = if da < max { da } else { max };
+    if n > 0 && d > db {
+        n - 1
+    } else if n < 0 && d < db {
+        n + 1
+    } else {
+        n
+    }
 }
 
-/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to Gregorian date
-///
-/// Given a `(year, week, day of week)` tuple returns a `(year, month, day)`
-/// tuple. Week is the ISO week number, with the first week of the year being
-/// the week containing the first Thursday of the year. Day of week is between
-/// 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
+/// Calculate whole years between two Gregorian dates
 ///
-/// Compared to Gregorian date, the first one to three days of the year might
-/// belong to a week in the previous year, and the last one to three days of the
-/// year might belong to a week in the next year. Also some years have 53 weeks
-/// instead of 52.
+/// Given two `(year, month, day)` tuples returns the number of whole years
+/// from the first date to the second, which is negative if the second date is
+/// before the first. This is the largest number of years that can be added to
+/// the first date with [add_years] and [DayOverflow::Clamp] without passing
+/// the second date, so that for an age calculation a February 29th birthday is
+/// reached on February 28th in common years.
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1` and
-/// the number of ISO weeks in the given year (52 or 53). Day must be between
-/// `1` and `7`. Bounds are checked using `debug_assert` only, so that the
-/// checks are not present in release builds, similar to integer overflow
-/// checks.
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::{isoweekdate_to_date};
+/// use datealgo::whole_years_between;
 ///
-/// assert_eq!(isoweekdate_to_date((2023, 19, 5)), (2023, 5, 12));
-/// assert_eq!(isoweekdate_to_date((1970, 1, 4)), (1970, 1, 1));
-/// assert_eq!(isoweekdate_to_date((2022, 52, 7)), (2023, 1, 1));
-/// assert_eq!(isoweekdate_to_date((1980, 1, 1)), (1979, 12, 31));
-/// assert_eq!(isoweekdate_to_date((1981, 53, 4)), (1981, 12, 31));
-/// assert_eq!(isoweekdate_to_date((1981, 53, 5)), (1982, 1, 1));
+/// assert_eq!(whole_years_between((1990, 5, 12), (2023, 5, 12)), 33);
+/// assert_eq!(whole_years_between((1990, 5, 12), (2023, 5, 11)), 32);
+/// assert_eq!(whole_years_between((2000, 2, 29), (2023, 2, 28)), 23);
+/// assert_eq!(whole_years_between((2000, 2, 29), (2024, 2, 28)), 23);
+/// assert_eq!(whole_years_between((2023, 5, 12), (1990, 5, 13)), -32);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Simply converts ISO week date to rata die and then rata die to date.
+/// Same as [months_between], comparing month and day together, which avoids
+/// calculating months.
 #[inline]
-pub const fn isoweekdate_to_date((y, w, d): (i32, u8, u8)) -> (i32, u8, u8) {
-    let rd = isoweekdate_to_rd((y, w, d));
-    rd_to_date(rd)
+pub const fn whole_years_between((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> i32 {
+    debug_assert!(ya >= YEAR_MIN && ya <= YEAR_MAX, "given year is out of range");
+    debug_assert!(ma >= consts::MONTH_MIN && ma <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(da >= consts::DAY_MIN && da <= days_in_month(ya, ma), "given day is out of range");
+    debug_assert!(yb >= YEAR_MIN && yb <= YEAR_MAX, "given year is out of range");
+    debug_assert!(mb >= consts::MONTH_MIN && mb <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(db >= consts::DAY_MIN && db <= days_in_month(yb, mb), "given day is out of range");
+    let n = yb - ya;
+    // only February 29th can be clamped when changing the year
+    let da = if ma == 2 && da == 29 && !is_leap_year(yb) { 28 } else { da };
+    let a = (ma as u32) << 8 | da as u32;
+    let b = (mb as u32) << 8 | db as u32;
+    if n > 0 && a > b {
+        n - 1
+    } else if n < 0 && a < b {
+        n + 1
+    } else {
+        n
+    }
 }
 
-/// Determine the number of [ISO weeks](https://en.wikipedia.org/wiki/ISO_week_date) in the given year
+/// Calculate calendar difference between two Gregorian dates
 ///
-/// According to the ISO standard a year has 52 weeks, unless the first week of
-/// the year starts on a Thursday or the year is a leap year and the first week
-/// of the year starts on a Wednesday, in which case the year has 53 weeks.
+/// Given two `(year, month, day)` tuples returns the difference from the first
+/// date to the second as a `(years, months, days)` tuple, where all the parts
+/// have the same sign, negative if the second date is before the first. Months
+/// are between `-11` and `11`, and days between `-30` and `30`.
+///
+/// The whole months are counted as in [months_between], so adding the years
+/// and months to the first date with [add_months] and [DayOverflow::Clamp] and
+/// then adding the days always gives the second date. This means that a month
+/// from January 31st is February 28th or 29th, and a February 29th birthday is
+/// a year older on February 28th in common years.
 ///
 /// # Panics
 ///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
-/// `debug_assert` only, so that the checks are not present in release builds,
-/// similar to integer overflow checks.
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::isoweeks_in_year;
-///
-/// assert_eq!(isoweeks_in_year(2023), 52);
-/// assert_eq!(isoweeks_in_year(2024), 52);
-/// assert_eq!(isoweeks_in_year(2025), 52);
-/// assert_eq!(isoweeks_in_year(2026), 53);
-/// assert_eq!(isoweeks_in_year(2027), 52);
+/// use datealgo::date_diff_ymd;
+///
+/// assert_eq!(date_diff_ymd((1990, 5, 12), (2023, 7, 20)), (33, 2, 8));
+/// assert_eq!(date_diff_ymd((2023, 7, 20), (1990, 5, 12)), (-33, -2, -8));
+/// assert_eq!(date_diff_ymd((2023, 1, 31), (2023, 2, 28)), (0, 1, 0));
+/// assert_eq!(date_diff_ymd((2023, 1, 31), (2023, 3, 30)), (0, 1, 30));
+/// assert_eq!(date_diff_ymd((2000, 2, 29), (2001, 2, 28)), (1, 0, 0));
+/// assert_eq!(date_diff_ymd((2023, 5, 12), (2023, 5, 12)), (0, 0, 0));
 /// ```
 ///
 /// # Algorithm
 ///
-/// Algorithm is hand crafted and not significantly optimized.
+/// Whole months are calculated with [months_between], and the remaining days
+/// as the difference of [date_to_rd] between the second date and the first
+/// date moved by the whole months.
 #[inline]
-pub const fn isoweeks_in_year(y: i32) -> u8 {
-    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
-    let wd = date_to_weekday((y, 1, 1));
-    let l = is_leap_year(y);
-    match wd {
-        consts::THURSDAY => 53,
-        consts::WEDNESDAY if l => 53,
-        _ => 52,
-    }
+pub const fn date_diff_ymd((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> (i32, i8, i8) {
+    let n = months_between((ya, ma, da), (yb, mb, db));
+    let months = ya * 12 + (ma - 1) as i32 + n;
+    let (y, m) = (months.div_euclid(12), (months.rem_euclid(12) + 1) as u8);
+    let max = days_in_month(y, m);
+    let d = if da < max { da } else { max };
+    let days = date_to_rd((yb, mb, db)) - date_to_rd((y, m, d));
+    (n / 12, (n % 12) as i8, days as i8)
 }
 
-/// Convert [`std::time::SystemTime`] to seconds and nanoseconds
+/// Split total seconds to days, hours, minutes and seconds
 ///
-/// Given [`std::time::SystemTime`] returns an `Option` of `(seconds,
-/// nanoseconds)` tuple from Unix epoch (January 1st, 1970).
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(days,
+/// hours, minutes, seconds)` tuple.
 ///
-/// # Errors
+/// # Panics
 ///
-/// Returns `None` if the time is before [RD_SECONDS_MIN] or after
-/// [RD_SECONDS_MAX].
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{secs_to_dhms, date_to_rd};
+///
+/// assert_eq!(secs_to_dhms(0), (0, 0, 0, 0));
+/// assert_eq!(secs_to_dhms(86400), (1, 0, 0, 0));
+/// assert_eq!(secs_to_dhms(86399), (0, 23, 59, 59));
+/// assert_eq!(secs_to_dhms(-1), (-1, 23, 59, 59));
+/// assert_eq!(secs_to_dhms(1684574678), (date_to_rd((2023, 5, 20)), 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// See examples 14 and 15 of:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn secs_to_dhms(secs: i64) -> (i32, u8, u8, u8) {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    // Algorithm is based on the following identities valid for all n in [0, 97612919[.
+    //
+    // n / 60 = 71582789 * n / 2^32,
+    // n % 60 = 71582789 * n % 2^32 / 71582789.
+    //
+    // `SECS_IN_DAY` obviously fits within these bounds
+    let secs = if secs > RD_SECONDS_MAX { 0 } else { secs }; // allows compiler to optimize more
+    let secs = (secs + SECS_OFFSET) as u64;
+    let days = (secs / SECS_IN_DAY as u64) as u32;
+    let secs = secs % SECS_IN_DAY as u64; // secs in [0, SECS_IN_DAY[ => secs in [0, 97612919[
+
+    let prd = 71582789 * secs;
+    let mins = prd >> 32; // secs / 60
+    let ss = (prd as u32) / 71582789; // secs % 60
+
+    let prd = 71582789 * mins;
+    let hh = prd >> 32; // mins / 60
+    let mm = (prd as u32) / 71582789; // mins % 60
+
+    let days = (days as i32) - DAY_OFFSET;
+    (days, hh as u8, mm as u8, ss as u8)
+}
+
+/// Combine days, hours, minutes and seconds to total seconds
+///
+/// Given a `(days, hours, minutes, seconds)` tuple from Unix epoch (January
+/// 1st, 1970) returns the total seconds.
+///
+/// # Panics
+///
+/// Days must be between [RD_MIN] and [RD_MAX] inclusive. Hours must be between
+/// `0` and `23`. Minutes must be between `0` and `59`. Seconds must be between
+/// `0` and `59`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{dhms_to_secs, date_to_rd};
+///
+/// assert_eq!(dhms_to_secs((0, 0, 0, 0)), 0);
+/// assert_eq!(dhms_to_secs((1, 0, 0, 0)), 86400);
+/// assert_eq!(dhms_to_secs((0, 23, 59, 59)), 86399);
+/// assert_eq!(dhms_to_secs((-1, 0, 0, 0)), -86400);
+/// assert_eq!(dhms_to_secs((-1, 0, 0, 1)), -86399);
+/// assert_eq!(dhms_to_secs((date_to_rd((2023, 5, 20)), 9, 24, 38)), 1684574678)
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn dhms_to_secs((d, h, m, s): (i32, u8, u8, u8)) -> i64 {
+    debug_assert!(d >= RD_MIN && d <= RD_MAX, "given rata die is out of range");
+    debug_assert!(h >= consts::HOUR_MIN && h <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(m >= consts::MINUTE_MIN && m <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(s >= consts::SECOND_MIN && s <= consts::SECOND_MAX, "given second is out of range");
+    if d >= RD_MIN && d <= RD_MAX {
+        d as i64 * SECS_IN_DAY + h as i64 * 3600 + m as i64 * 60 + s as i64
+    } else {
+        0
+    }
+}
+
+/// Convert total seconds to year, month, day, hours, minutes and seconds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day, hours, minutes, seconds)` tuple.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::secs_to_datetime;
+///
+/// assert_eq!(secs_to_datetime(0), (1970, 1, 1, 0, 0, 0));
+/// assert_eq!(secs_to_datetime(86400), (1970, 1, 2, 0, 0, 0));
+/// assert_eq!(secs_to_datetime(86399), (1970, 1, 1, 23, 59, 59));
+/// assert_eq!(secs_to_datetime(-1), (1969, 12, 31, 23, 59, 59));
+/// assert_eq!(secs_to_datetime(1684574678), (2023, 5, 20, 9, 24, 38));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[inline]
+pub const fn secs_to_datetime(secs: i64) -> (i32, u8, u8, u8, u8, u8) {
+    let (days, hh, mm, ss) = secs_to_dhms(secs);
+    let (y, m, s) = rd_to_date(days);
+    (y, m, s, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to total seconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple from Unix epoch
+/// (January 1st, 1970) returns the total seconds.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_secs;
+///
+/// assert_eq!(datetime_to_secs((1970, 1, 1, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_secs((1970, 1, 2, 0, 0, 0)), 86400);
+/// assert_eq!(datetime_to_secs((1970, 1, 1, 23, 59, 59)), 86399);
+/// assert_eq!(datetime_to_secs((1969, 12, 31, 0, 0, 0)), -86400);
+/// assert_eq!(datetime_to_secs((1969, 12, 31, 0, 0, 1)), -86399);
+/// assert_eq!(datetime_to_secs((2023, 5, 20, 9, 24, 38)), 1684574678)
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn datetime_to_secs((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> i64 {
+    let days = date_to_rd((y, m, d));
+    dhms_to_secs((days, hh, mm, ss))
+}
+
+/// Determine if the given year is a leap year
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::is_leap_year;
+///
+/// assert_eq!(is_leap_year(2023), false);
+/// assert_eq!(is_leap_year(2024), true);
+/// assert_eq!(is_leap_year(2100), false);
+/// assert_eq!(is_leap_year(2400), true);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is Neri-Schneider from C++now 2023 conference:
+/// > <https://github.com/boostcon/cppnow_presentations_2023/blob/main/cppnow_slides/Speeding_Date_Implementing_Fast_Calendar_Algorithms.pdf>
+#[inline]
+pub const fn is_leap_year(y: i32) -> bool {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    // Using `%` instead of `&` causes compiler to emit branches instead. This
+    // is faster in a tight loop due to good branch prediction, but probably
+    // slower in a real program so we use `&`. Also `% 25` is functionally
+    // equivalent to `% 100` here, but a little cheaper to compute. If branches
+    // were to be emitted, using `% 100` would be most likely faster due to
+    // better branch prediction.
+    if (y % 25) != 0 {
+        y & 3 == 0
+    } else {
+        y & 15 == 0
+    }
+}
+
+/// Determine the number of days in the given month in the given year
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Example
+///
+/// ```
+/// use datealgo::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 1), 31);
+/// assert_eq!(days_in_month(2023, 2), 28);
+/// assert_eq!(days_in_month(2023, 4), 30);
+/// assert_eq!(days_in_month(2024, 1), 31);
+/// assert_eq!(days_in_month(2024, 2), 29);
+/// assert_eq!(days_in_month(2024, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is Neri-Schneider from C++now 2023 conference:
+/// > <https://github.com/boostcon/cppnow_presentations_2023/blob/main/cppnow_slides/Speeding_Date_Implementing_Fast_Calendar_Algorithms.pdf>
+#[inline]
+pub const fn days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m != 2 {
+        30 | (m ^ (m >> 3))
+    } else if is_leap_year(y) {
+        29
+    } else {
+        28
+    }
+}
+
+/// Convert Rata Die to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// week, day of week)` tuple. Week is the ISO week number, with the first week
+/// of the year being the week containing the first Thursday of the year. Day of
+/// week is between 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
+///
+/// Compared to Gregorian date, the first one to three days of the year might
+/// belong to a week in the previous year, and the last one to three days of the
+/// year might belong to a week in the next year. Also some years have 53 weeks
+/// instead of 52.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_isoweekdate, date_to_rd};
+///
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((2023, 5, 12))), (2023, 19, 5));
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((1970, 1, 1))), (1970, 1, 4));
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((2023, 1, 1))), (2022, 52, 7));
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((1979, 12, 31))), (1980, 1, 1));
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((1981, 12, 31))), (1981, 53, 4));
+/// assert_eq!(rd_to_isoweekdate(date_to_rd((1982, 1, 1))), (1981, 53, 5));
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is hand crafted and not significantly optimized.
+#[inline]
+pub const fn rd_to_isoweekdate(rd: i32) -> (i32, u8, u8) {
+    debug_assert!(rd >= RD_MIN && rd <= RD_MAX, "given rata die is out of range");
+    let wd = rd_to_weekday(rd);
+    let rdt = rd + (4 - wd as i32) % 7;
+    let (y, _, _) = rd_to_date(rdt);
+    let ys = date_to_rd((y, 1, 1));
+    let w = (rdt - ys) / 7 + 1;
+    (y, w as u8, wd)
+}
+
+/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to Rata Die
+///
+/// Given a `(year, week, day of week)` tuple returns the days since Unix epoch
+/// (January 1st, 1970). Week is the ISO week number, with the first week of the
+/// year being the week containing the first Thursday of the year. Day of week
+/// is between 1 and 7, with `1` meaning Monday and `7` meaning Sunday. Dates
+/// before the epoch produce negative values.
+///
+/// Compared to Gregorian date, the first one to three days of the year might
+/// belong to a week in the previous year, and the last one to three days of the
+/// year might belong to a week in the next year. Also some years have 53 weeks
+/// instead of 52.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1` and
+/// the number of ISO weeks in the given year (52 or 53). Day must be between
+/// `1` and `7`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{isoweekdate_to_rd, date_to_rd};
+///
+/// assert_eq!(isoweekdate_to_rd((2023, 19, 5)), date_to_rd((2023, 5, 12)));
+/// assert_eq!(isoweekdate_to_rd((1970, 1, 4)), date_to_rd((1970, 1, 1)));
+/// assert_eq!(isoweekdate_to_rd((2022, 52, 7)), date_to_rd((2023, 1, 1)));
+/// assert_eq!(isoweekdate_to_rd((1980, 1, 1)), date_to_rd((1979, 12, 31)));
+/// assert_eq!(isoweekdate_to_rd((1981, 53, 4)), date_to_rd((1981, 12, 31)));
+/// assert_eq!(isoweekdate_to_rd((1981, 53, 5)), date_to_rd((1982, 1, 1)));
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is hand crafted and not significantly optimized.
+#[inline]
+pub const fn isoweekdate_to_rd((y, w, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(w >= consts::WEEK_MIN && w <= isoweeks_in_year(y), "given week is out of range");
+    debug_assert!(
+        d >= consts::WEEKDAY_MIN && d <= consts::WEEKDAY_MAX,
+        "given weekday is out of range"
+    );
+    debug_assert!(
+        y != YEAR_MAX || w != consts::WEEK_MAX || d <= consts::THURSDAY,
+        "given weekday is out of range (for last week of range)"
+    );
+    let rd4 = date_to_rd((y, 1, 4));
+    let wd4 = rd_to_weekday(rd4);
+    let ys = rd4 - (wd4 - 1) as i32;
+    ys + (w as i32 - 1) * 7 + (d as i32 - 1)
+}
+
+/// Convert Gregorian date to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+///
+/// Given a `(year, month, day)` tuple returns a `(year, week, day of week)`
+/// tuple. Week is the ISO week number, with the first week of the year being
+/// the week containing the first Thursday of the year. Day of week is between
+/// 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
+///
+/// Compared to Gregorian date, the first one to three days of the year might
+/// belong to a week in the previous year, and the last one to three days of the
+/// year might belong to a week in the next year. Also some years have 53 weeks
+/// instead of 52.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_isoweekdate};
+///
+/// assert_eq!(date_to_isoweekdate((2023, 5, 12)), (2023, 19, 5));
+/// assert_eq!(date_to_isoweekdate((1970, 1, 1)), (1970, 1, 4));
+/// assert_eq!(date_to_isoweekdate((2023, 1, 1)), (2022, 52, 7));
+/// assert_eq!(date_to_isoweekdate((1979, 12, 31)), (1980, 1, 1));
+/// assert_eq!(date_to_isoweekdate((1981, 12, 31)), (1981, 53, 4));
+/// assert_eq!(date_to_isoweekdate((1982, 1, 1)), (1981, 53, 5));
+/// ```
+///
+/// # Algorithm
+///
+/// Simply converts date to rata die and then rata die to ISO week date.
+#[inline]
+pub const fn date_to_isoweekdate((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
+    let rd = date_to_rd((y, m, d));
+    rd_to_isoweekdate(rd)
+}
+
+/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to Gregorian date
+///
+/// Given a `(year, week, day of week)` tuple returns a `(year, month, day)`
+/// tuple. Week is the ISO week number, with the first week of the year being
+/// the week containing the first Thursday of the year. Day of week is between
+/// 1 and 7, with `1` meaning Monday and `7` meaning Sunday.
+///
+/// Compared to Gregorian date, the first one to three days of the year might
+/// belong to a week in the previous year, and the last one to three days of the
+/// year might belong to a week in the next year. Also some years have 53 weeks
+/// instead of 52.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Week must be between `1` and
+/// the number of ISO weeks in the given year (52 or 53). Day must be between
+/// `1` and `7`. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{isoweekdate_to_date};
+///
+/// assert_eq!(isoweekdate_to_date((2023, 19, 5)), (2023, 5, 12));
+/// assert_eq!(isoweekdate_to_date((1970, 1, 4)), (1970, 1, 1));
+/// assert_eq!(isoweekdate_to_date((2022, 52, 7)), (2023, 1, 1));
+/// assert_eq!(isoweekdate_to_date((1980, 1, 1)), (1979, 12, 31));
+/// assert_eq!(isoweekdate_to_date((1981, 53, 4)), (1981, 12, 31));
+/// assert_eq!(isoweekdate_to_date((1981, 53, 5)), (1982, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Simply converts ISO week date to rata die and then rata die to date.
+#[inline]
+pub const fn isoweekdate_to_date((y, w, d): (i32, u8, u8)) -> (i32, u8, u8) {
+    let rd = isoweekdate_to_rd((y, w, d));
+    rd_to_date(rd)
+}
+
+/// Determine the number of [ISO weeks](https://en.wikipedia.org/wiki/ISO_week_date) in the given year
+///
+/// According to the ISO standard a year has 52 weeks, unless the first week of
+/// the year starts on a Thursday or the year is a leap year and the first week
+/// of the year starts on a Wednesday, in which case the year has 53 weeks.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::isoweeks_in_year;
+///
+/// assert_eq!(isoweeks_in_year(2023), 52);
+/// assert_eq!(isoweeks_in_year(2024), 52);
+/// assert_eq!(isoweeks_in_year(2025), 52);
+/// assert_eq!(isoweeks_in_year(2026), 53);
+/// assert_eq!(isoweeks_in_year(2027), 52);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is hand crafted and not significantly optimized.
+#[inline]
+pub const fn isoweeks_in_year(y: i32) -> u8 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    let wd = date_to_weekday((y, 1, 1));
+    let l = is_leap_year(y);
+    match wd {
+        consts::THURSDAY => 53,
+        consts::WEDNESDAY if l => 53,
+        _ => 52,
+    }
+}
+
+/// Determine the number of days in the given year
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::days_in_year;
+///
+/// assert_eq!(days_in_year(2023), 365);
+/// assert_eq!(days_in_year(2024), 366);
+/// assert_eq!(days_in_year(2100), 365);
+/// assert_eq!(days_in_year(2400), 366);
+/// ```
+///
+/// # Algorithm
+///
+/// Simply adds one to 365 for leap years as determined by [is_leap_year].
+#[inline]
+pub const fn days_in_year(y: i32) -> u16 {
+    365 + is_leap_year(y) as u16
+}
+
+/// Convert Rata Die to [ordinal date](https://en.wikipedia.org/wiki/Ordinal_date)
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// day of year)` tuple. Day of year is between `1` and `366`, with `1` meaning
+/// January 1st.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, rd_to_ordinal};
+///
+/// assert_eq!(rd_to_ordinal(date_to_rd((2023, 5, 12))), (2023, 132));
+/// assert_eq!(rd_to_ordinal(date_to_rd((1970, 1, 1))), (1970, 1));
+/// assert_eq!(rd_to_ordinal(date_to_rd((2023, 12, 31))), (2023, 365));
+/// assert_eq!(rd_to_ordinal(date_to_rd((2024, 12, 31))), (2024, 366));
+/// ```
+///
+/// # Algorithm
+///
+/// Uses the year computation of the Neri-Schneider algorithm, as in
+/// [rd_to_date], giving the day of the computational year starting on March
+/// 1st, which is then shifted to start on January 1st:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn rd_to_ordinal(n: i32) -> (i32, u16) {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    let n = (n + DAY_OFFSET) as u32;
+    // century
+    let n = 4 * n + 3;
+    let c = n / 146097;
+    let r = n % 146097;
+    // year
+    let n = r | 3;
+    let p = 2939745 * n as u64;
+    let z = (p / 2u64.pow(32)) as u32;
+    let n = (p % 2u64.pow(32)) as u32 / 2939745 / 4;
+    let j = n >= 306;
+    let y = 100 * c + z + j as u32;
+    // map
+    let y = (y as i32) - YEAR_OFFSET;
+    let n = if j { n - 305 } else { n + 60 + is_leap_year(y) as u32 };
+    (y, n as u16)
+}
+
+/// Convert [ordinal date](https://en.wikipedia.org/wiki/Ordinal_date) to Rata Die
+///
+/// Given a `(year, day of year)` tuple returns the days since Unix epoch
+/// (January 1st, 1970). Day of year is between `1` and `366`, with `1`
+/// meaning January 1st. Dates before the epoch produce negative values.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Day of year must be between
+/// `1` and the number of days in the year in question. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{date_to_rd, ordinal_to_rd};
+///
+/// assert_eq!(ordinal_to_rd((2023, 132)), date_to_rd((2023, 5, 12)));
+/// assert_eq!(ordinal_to_rd((1970, 1)), 0);
+/// assert_eq!(ordinal_to_rd((2023, 365)), date_to_rd((2023, 12, 31)));
+/// assert_eq!(ordinal_to_rd((2024, 366)), date_to_rd((2024, 12, 31)));
+/// ```
+///
+/// # Algorithm
+///
+/// Uses the year computation of the Neri-Schneider algorithm, as in
+/// [date_to_rd], with January 1st being day 306 of the previous computational
+/// year starting on March 1st:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn ordinal_to_rd((y, d): (i32, u16)) -> i32 {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(d >= 1 && d <= days_in_year(y), "given day of year is out of range");
+    // year
+    let y = (y + YEAR_OFFSET) as u32 - 1;
+    // century
+    let c = y / 100;
+    // result
+    let y = 1461 * y / 4 - c + c / 4;
+    let n = y + 305 + d as u32;
+    (n as i32) - DAY_OFFSET
+}
+
+/// Convert Gregorian date to [ordinal date](https://en.wikipedia.org/wiki/Ordinal_date)
+///
+/// Given a `(year, month, day)` tuple returns a `(year, day of year)` tuple.
+/// Day of year is between `1` and `366`, with `1` meaning January 1st.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::date_to_ordinal;
+///
+/// assert_eq!(date_to_ordinal((2023, 5, 12)), (2023, 132));
+/// assert_eq!(date_to_ordinal((2023, 1, 1)), (2023, 1));
+/// assert_eq!(date_to_ordinal((2023, 3, 1)), (2023, 60));
+/// assert_eq!(date_to_ordinal((2024, 3, 1)), (2024, 61));
+/// assert_eq!(date_to_ordinal((2024, 12, 31)), (2024, 366));
+/// ```
+///
+/// # Algorithm
+///
+/// Uses the month computation of the Neri-Schneider algorithm, as in
+/// [date_to_rd], giving the day of the computational year starting on March
+/// 1st, which is then shifted to start on January 1st:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn date_to_ordinal((y, m, d): (i32, u8, u8)) -> (i32, u16) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    let j = m < 3;
+    // month
+    let m = m as u32 + 12 * j as u32;
+    let n = (979 * m - 2919) / 32 + d as u32;
+    // map
+    let n = if j { n - 306 } else { n + 59 + is_leap_year(y) as u32 };
+    (y, n as u16)
+}
+
+/// Convert [ordinal date](https://en.wikipedia.org/wiki/Ordinal_date) to Gregorian date
+///
+/// Given a `(year, day of year)` tuple returns a `(year, month, day)` tuple.
+/// Day of year is between `1` and `366`, with `1` meaning January 1st.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Day of year must be between
+/// `1` and the number of days in the year in question. Bounds are checked
+/// using `debug_assert` only, so that the checks are not present in release
+/// builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::ordinal_to_date;
+///
+/// assert_eq!(ordinal_to_date((2023, 132)), (2023, 5, 12));
+/// assert_eq!(ordinal_to_date((2023, 1)), (2023, 1, 1));
+/// assert_eq!(ordinal_to_date((2023, 60)), (2023, 3, 1));
+/// assert_eq!(ordinal_to_date((2024, 60)), (2024, 2, 29));
+/// assert_eq!(ordinal_to_date((2024, 366)), (2024, 12, 31));
+/// ```
+///
+/// # Algorithm
+///
+/// Shifts the day of year to the computational year starting on March 1st,
+/// and then uses the month and day computation of the Neri-Schneider
+/// algorithm, as in [rd_to_date]:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn ordinal_to_date((y, d): (i32, u16)) -> (i32, u8, u8) {
+    debug_assert!(y >= YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(d >= 1 && d <= days_in_year(y), "given day of year is out of range");
+    let l = is_leap_year(y) as u32;
+    let n = d as u32 - 1;
+    let j = n < 59 + l;
+    let n = if j { n + 306 } else { n - 59 - l };
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = n / 2u32.pow(16);
+    let d = n % 2u32.pow(16) / 2141;
+    // map
+    let m = if j { m - 12 } else { m };
+    let d = d + 1;
+    (y, m as u8, d as u8)
+}
+
+/// Convert Rata Die to Julian calendar date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in the [proleptic Julian
+/// calendar](https://en.wikipedia.org/wiki/Proleptic_Julian_calendar). Years
+/// are numbered astronomically, so that year `0` is 1 BC.
+///
+/// # Panics
+///
+/// Argument must be between [JULIAN_RD_MIN] and [JULIAN_RD_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_julian_date, date_to_rd};
+///
+/// assert_eq!(rd_to_julian_date(0), (1969, 12, 19));
+/// assert_eq!(rd_to_julian_date(date_to_rd((1582, 10, 15))), (1582, 10, 5));
+/// assert_eq!(rd_to_julian_date(date_to_rd((0, 12, 30))), (1, 1, 1));
+/// assert_eq!(rd_to_julian_date(date_to_rd((2023, 5, 12))), (2023, 4, 29));
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm using Euclidean Affine Functions, adapted to the
+/// four year cycle of the Julian calendar:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn rd_to_julian_date(n: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= JULIAN_RD_MIN && n <= JULIAN_RD_MAX, "given rata die is out of range");
+    let n = (n + JULIAN_DAY_OFFSET) as u32;
+    // year
+    let n = 4 * n + 3;
+    let y = n / 1461;
+    let n = n % 1461 / 4;
+    let j = n >= 306;
+    let y = y + j as u32;
+    // month and day
+    let n = 2141 * n + 197913;
+    let m = n / 2u32.pow(16);
+    let d = n % 2u32.pow(16) / 2141;
+    // map
+    let y = (y as i32) - YEAR_OFFSET;
+    let m = if j { m - 12 } else { m };
+    let d = d + 1;
+    (y, m as u8, d as u8)
+}
+
+/// Convert Julian calendar date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in the [proleptic Julian
+/// calendar](https://en.wikipedia.org/wiki/Proleptic_Julian_calendar) returns
+/// the days since Unix epoch (January 1st, 1970). Dates before the epoch
+/// produce negative values. Years are numbered astronomically, so that year
+/// `0` is 1 BC.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Month must be
+/// between `1` and `12`. Day must be between `1` and the number of days in the
+/// month in question. Bounds are checked using `debug_assert` only, so that the
+/// checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{julian_date_to_rd, date_to_rd};
+///
+/// assert_eq!(julian_date_to_rd((1969, 12, 19)), 0);
+/// assert_eq!(julian_date_to_rd((1582, 10, 5)), date_to_rd((1582, 10, 15)));
+/// assert_eq!(julian_date_to_rd((1, 1, 1)), date_to_rd((0, 12, 30)));
+/// assert_eq!(julian_date_to_rd((2023, 4, 29)), date_to_rd((2023, 5, 12)));
+/// ```
+///
+/// # Algorithm
+///
+/// Neri-Schneider algorithm using Euclidean Affine Functions, adapted to the
+/// four year cycle of the Julian calendar:
+///
+/// > Neri C, Schneider L. "*Euclidean affine functions and their application to
+/// > calendar algorithms*". Softw Pract Exper. 2022;1-34. doi:
+/// > [10.1002/spe.3172](https://onlinelibrary.wiley.com/doi/full/10.1002/spe.3172).
+#[inline]
+pub const fn julian_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= julian_days_in_month(y, m), "given day is out of range");
+    let y = (y + YEAR_OFFSET) as u32;
+    let jf = (m < 3) as u32;
+    // year
+    let y = y - jf;
+    let y = 1461 * y / 4;
+    // month
+    let m = m as u32 + 12 * jf;
+    let m = (979 * m - 2919) / 32;
+    // day
+    let d = d as u32 - 1;
+    // result
+    let n = y + m + d;
+    (n as i32) - JULIAN_DAY_OFFSET
+}
+
+/// Determine if the given year is a leap year in the Julian calendar
+///
+/// In the Julian calendar every fourth year is a leap year, without exception.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX] inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::is_julian_leap_year;
+///
+/// assert_eq!(is_julian_leap_year(2023), false);
+/// assert_eq!(is_julian_leap_year(2024), true);
+/// assert_eq!(is_julian_leap_year(1900), true);
+/// assert_eq!(is_julian_leap_year(-1), false);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple bit test, which also works for negative years in two's complement.
+#[inline]
+pub const fn is_julian_leap_year(y: i32) -> bool {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= JULIAN_YEAR_MAX, "given year is out of range");
+    y & 3 == 0
+}
+
+/// Determine the number of days in the given month in the given Julian
+/// calendar year
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [JULIAN_YEAR_MAX]. Month must be
+/// between `1` and `12`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Example
+///
+/// ```
+/// use datealgo::julian_days_in_month;
+///
+/// assert_eq!(julian_days_in_month(1900, 1), 31);
+/// assert_eq!(julian_days_in_month(1900, 2), 29);
+/// assert_eq!(julian_days_in_month(1901, 2), 28);
+/// assert_eq!(julian_days_in_month(1901, 4), 30);
+/// ```
+///
+/// # Algorithm
+///
+/// Same as [days_in_month], but with the Julian leap year rule.
+#[inline]
+pub const fn julian_days_in_month(y: i32, m: u8) -> u8 {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    if m != 2 {
+        30 | (m ^ (m >> 3))
+    } else if is_julian_leap_year(y) {
+        29
+    } else {
+        28
+    }
+}
+
+/// Determine if a date label falls before the first Gregorian date of the reform
+#[inline]
+const fn is_before_reform(y: i32, m: u8, d: u8, reform: i32) -> bool {
+    let (ry, rm, rd) = rd_to_date(reform);
+    y < ry || (y == ry && (m < rm || (m == rm && d < rd)))
+}
+
+/// Convert Rata Die to historical date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns a `(year,
+/// month, day)` tuple in a mixed calendar, which follows the Julian calendar
+/// for days before `reform` and the Gregorian calendar from `reform` onwards.
+/// The [reform] module contains the reform dates of several regions, of which
+/// [reform::GREGORIAN] is the original 1582 reform.
+///
+/// # Panics
+///
+/// Argument must be between [JULIAN_RD_MIN] and [RD_MAX] inclusive. Reform must
+/// be between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_historical_date, historical_date_to_rd, reform};
+///
+/// let rd = historical_date_to_rd((1582, 10, 4), reform::GREGORIAN);
+/// assert_eq!(rd_to_historical_date(rd, reform::GREGORIAN), (1582, 10, 4));
+/// assert_eq!(rd_to_historical_date(rd + 1, reform::GREGORIAN), (1582, 10, 15));
+/// assert_eq!(rd_to_historical_date(rd + 1, reform::GREAT_BRITAIN), (1582, 10, 5));
+/// assert_eq!(rd_to_historical_date(0, reform::GREGORIAN), (1970, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Comparison against the reform day, followed by either [rd_to_julian_date]
+/// or [rd_to_date].
+#[inline]
+pub const fn rd_to_historical_date(n: i32, reform: i32) -> (i32, u8, u8) {
+    debug_assert!(n >= JULIAN_RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    debug_assert!(reform >= RD_MIN && reform <= RD_MAX, "given reform is out of range");
+    if n < reform {
+        rd_to_julian_date(n)
+    } else {
+        rd_to_date(n)
+    }
+}
+
+/// Convert historical date to Rata Die
+///
+/// Given a `(year, month, day)` tuple in a mixed calendar, which follows the
+/// Julian calendar for days before `reform` and the Gregorian calendar from
+/// `reform` onwards, returns the days since Unix epoch (January 1st, 1970).
+/// Dates before the epoch produce negative values. The [reform] module contains
+/// the reform dates of several regions.
+///
+/// The days skipped by the reform do not exist in the mixed calendar. If the
+/// reform is set before year 200, some dates would exist twice, and such dates
+/// are always interpreted as Gregorian.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [YEAR_MAX]. Month must be
+/// between `1` and `12`. Day must be between `1` and the number of days in the
+/// month in question, and must not be skipped by the reform. Reform must be
+/// between [RD_MIN] and [RD_MAX] inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{historical_date_to_rd, date_to_rd, reform};
+///
+/// assert_eq!(historical_date_to_rd((1582, 10, 15), reform::GREGORIAN), date_to_rd((1582, 10, 15)));
+/// assert_eq!(historical_date_to_rd((1582, 10, 4), reform::GREGORIAN), date_to_rd((1582, 10, 14)));
+/// assert_eq!(historical_date_to_rd((1752, 9, 2), reform::GREAT_BRITAIN), date_to_rd((1752, 9, 13)));
+/// assert_eq!(historical_date_to_rd((1970, 1, 1), reform::GREGORIAN), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Comparison against the Gregorian date of the reform day, followed by either
+/// [julian_date_to_rd] or [date_to_rd].
+#[inline]
+pub const fn historical_date_to_rd((y, m, d): (i32, u8, u8), reform: i32) -> i32 {
+    debug_assert!(reform >= RD_MIN && reform <= RD_MAX, "given reform is out of range");
+    if is_before_reform(y, m, d, reform) {
+        let n = julian_date_to_rd((y, m, d));
+        debug_assert!(n < reform, "given date is skipped by the reform");
+        n
+    } else {
+        date_to_rd((y, m, d))
+    }
+}
+
+/// Determine the first day of the given month in historical calendar
+#[inline]
+const fn historical_month_start(y: i32, m: u8, reform: i32) -> i32 {
+    if !is_before_reform(y, m, 1, reform) {
+        return date_to_rd((y, m, 1));
+    }
+    let n = julian_date_to_rd((y, m, 1));
+    if n < reform {
+        n
+    } else {
+        reform
+    }
+}
+
+/// Determine the number of days in the given month in the given historical
+/// calendar year
+///
+/// The month in which the reform happens is shortened by the skipped days. The
+/// remaining days are not necessarily numbered from `1`, for example in Russia
+/// February 1918 starts on the 14th day.
+///
+/// # Panics
+///
+/// Year must be between [JULIAN_YEAR_MIN] and [YEAR_MAX]. Month must be
+/// between `1` and `12`. Reform must be between [RD_MIN] and [RD_MAX]
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Example
+///
+/// ```
+/// use datealgo::{historical_days_in_month, reform};
+///
+/// assert_eq!(historical_days_in_month(1582, 10, reform::GREGORIAN), 21);
+/// assert_eq!(historical_days_in_month(1700, 2, reform::GREGORIAN), 28);
+/// assert_eq!(historical_days_in_month(1700, 2, reform::GREAT_BRITAIN), 29);
+/// assert_eq!(historical_days_in_month(1752, 9, reform::GREAT_BRITAIN), 19);
+/// assert_eq!(historical_days_in_month(1918, 2, reform::RUSSIA), 15);
+/// ```
+///
+/// # Algorithm
+///
+/// Difference of the first days of the month and the following month, where
+/// the first day of a month is either the Julian first day if it is before the
+/// reform, or otherwise the later of the Gregorian first day and the reform.
+#[inline]
+pub const fn historical_days_in_month(y: i32, m: u8, reform: i32) -> u8 {
+    debug_assert!(y >= JULIAN_YEAR_MIN && y <= YEAR_MAX, "given year is out of range");
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(reform >= RD_MIN && reform <= RD_MAX, "given reform is out of range");
+    if !is_before_reform(y, m, 1, reform) {
+        return days_in_month(y, m);
+    }
+    let (ny, nm) = if m == 12 { (y + 1, 1) } else { (y, m + 1) };
+    let start = historical_month_start(y, m, reform);
+    let end = historical_month_start(ny, nm, reform);
+    (end - start) as u8
+}
+
+/// Convert Rata Die to Julian Day Number
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the [Julian
+/// Day Number](https://en.wikipedia.org/wiki/Julian_day), which counts days
+/// from noon of November 24th, 4714 BC in the proleptic Gregorian calendar.
+/// The Julian Day Number of a date is the number of the Julian day starting at
+/// noon of that date.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_jdn, date_to_rd};
+///
+/// assert_eq!(rd_to_jdn(0), 2440588);
+/// assert_eq!(rd_to_jdn(date_to_rd((2000, 1, 1))), 2451545);
+/// assert_eq!(rd_to_jdn(date_to_rd((-4713, 11, 24))), 0);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the Julian Day Number of the Unix epoch.
+#[inline]
+pub const fn rd_to_jdn(n: i32) -> i32 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    n + JDN_UNIX_EPOCH
+}
+
+/// Convert Julian Day Number to Rata Die
+///
+/// Given a [Julian Day Number](https://en.wikipedia.org/wiki/Julian_day)
+/// returns the day counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between `RD_MIN + 2440588` and `RD_MAX + 2440588`
+/// inclusive. Bounds are checked using `debug_assert` only, so that the checks
+/// are not present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{jdn_to_rd, rd_to_date};
+///
+/// assert_eq!(jdn_to_rd(2440588), 0);
+/// assert_eq!(rd_to_date(jdn_to_rd(2451545)), (2000, 1, 1));
+/// assert_eq!(rd_to_date(jdn_to_rd(0)), (-4713, 11, 24));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the Julian Day Number of the Unix epoch.
+#[inline]
+pub const fn jdn_to_rd(jdn: i32) -> i32 {
+    debug_assert!(
+        jdn >= RD_MIN + JDN_UNIX_EPOCH && jdn <= RD_MAX + JDN_UNIX_EPOCH,
+        "given julian day number is out of range"
+    );
+    jdn - JDN_UNIX_EPOCH
+}
+
+/// Convert Rata Die to Modified Julian Date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the
+/// [Modified Julian Date](https://en.wikipedia.org/wiki/Julian_day#Variants),
+/// which counts days from midnight of November 17th, 1858. MJD is defined as
+/// `JD - 2400000.5`.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_mjd, date_to_rd};
+///
+/// assert_eq!(rd_to_mjd(0), 40587);
+/// assert_eq!(rd_to_mjd(date_to_rd((1858, 11, 17))), 0);
+/// assert_eq!(rd_to_mjd(date_to_rd((2000, 1, 1))), 51544);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the Modified Julian Date of the Unix epoch.
+#[inline]
+pub const fn rd_to_mjd(n: i32) -> i32 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    n + MJD_UNIX_EPOCH
+}
+
+/// Convert Modified Julian Date to Rata Die
+///
+/// Given a [Modified Julian
+/// Date](https://en.wikipedia.org/wiki/Julian_day#Variants) returns the day
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between `RD_MIN + 40587` and `RD_MAX + 40587` inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{mjd_to_rd, rd_to_date};
+///
+/// assert_eq!(mjd_to_rd(40587), 0);
+/// assert_eq!(rd_to_date(mjd_to_rd(0)), (1858, 11, 17));
+/// assert_eq!(rd_to_date(mjd_to_rd(51544)), (2000, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the Modified Julian Date of the Unix epoch.
+#[inline]
+pub const fn mjd_to_rd(mjd: i32) -> i32 {
+    debug_assert!(
+        mjd >= RD_MIN + MJD_UNIX_EPOCH && mjd <= RD_MAX + MJD_UNIX_EPOCH,
+        "given modified julian date is out of range"
+    );
+    mjd - MJD_UNIX_EPOCH
+}
+
+/// Convert Rata Die to Reduced Julian Date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the
+/// [Reduced Julian Date](https://en.wikipedia.org/wiki/Julian_day#Variants),
+/// which is defined as `JD - 2400000`. As with the Julian Day Number, the
+/// returned value is the Reduced Julian Date at noon of the given day.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_rjd, date_to_rd};
+///
+/// assert_eq!(rd_to_rjd(0), 40588);
+/// assert_eq!(rd_to_rjd(date_to_rd((2000, 1, 1))), 51545);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the Reduced Julian Date of the Unix epoch.
+#[inline]
+pub const fn rd_to_rjd(n: i32) -> i32 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    n + RJD_UNIX_EPOCH
+}
+
+/// Convert Reduced Julian Date to Rata Die
+///
+/// Given a [Reduced Julian
+/// Date](https://en.wikipedia.org/wiki/Julian_day#Variants) returns the day
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between `RD_MIN + 40588` and `RD_MAX + 40588` inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rjd_to_rd, rd_to_date};
+///
+/// assert_eq!(rjd_to_rd(40588), 0);
+/// assert_eq!(rd_to_date(rjd_to_rd(51545)), (2000, 1, 1));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the Reduced Julian Date of the Unix epoch.
+#[inline]
+pub const fn rjd_to_rd(rjd: i32) -> i32 {
+    debug_assert!(
+        rjd >= RD_MIN + RJD_UNIX_EPOCH && rjd <= RD_MAX + RJD_UNIX_EPOCH,
+        "given reduced julian date is out of range"
+    );
+    rjd - RJD_UNIX_EPOCH
+}
+
+/// Convert Rata Die to Truncated Julian Date
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns the
+/// [Truncated Julian Date](https://en.wikipedia.org/wiki/Julian_day#Variants)
+/// as defined by NASA, which is `JD - 2440000.5` and counts days from midnight
+/// of May 24th, 1968.
+///
+/// # Panics
+///
+/// Argument must be between [RD_MIN] and [RD_MAX] inclusive. Bounds are
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{rd_to_tjd, date_to_rd};
+///
+/// assert_eq!(rd_to_tjd(0), 587);
+/// assert_eq!(rd_to_tjd(date_to_rd((1968, 5, 24))), 0);
+/// assert_eq!(rd_to_tjd(date_to_rd((1995, 10, 10))), 10000);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple addition of the Truncated Julian Date of the Unix epoch.
+#[inline]
+pub const fn rd_to_tjd(n: i32) -> i32 {
+    debug_assert!(n >= RD_MIN && n <= RD_MAX, "given rata die is out of range");
+    n + TJD_UNIX_EPOCH
+}
+
+/// Convert Truncated Julian Date to Rata Die
+///
+/// Given a [Truncated Julian
+/// Date](https://en.wikipedia.org/wiki/Julian_day#Variants) returns the day
+/// counting from Unix epoch (January 1st, 1970).
+///
+/// # Panics
+///
+/// Argument must be between `RD_MIN + 587` and `RD_MAX + 587` inclusive.
+/// Bounds are checked using `debug_assert` only, so that the checks are not
+/// present in release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{tjd_to_rd, rd_to_date};
+///
+/// assert_eq!(tjd_to_rd(587), 0);
+/// assert_eq!(rd_to_date(tjd_to_rd(0)), (1968, 5, 24));
+/// assert_eq!(rd_to_date(tjd_to_rd(10000)), (1995, 10, 10));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple subtraction of the Truncated Julian Date of the Unix epoch.
+#[inline]
+pub const fn tjd_to_rd(tjd: i32) -> i32 {
+    debug_assert!(
+        tjd >= RD_MIN + TJD_UNIX_EPOCH && tjd <= RD_MAX + TJD_UNIX_EPOCH,
+        "given truncated julian date is out of range"
+    );
+    tjd - TJD_UNIX_EPOCH
+}
+
+/// Convert seconds and nanoseconds to Julian Date day and nanoseconds
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns a `(jdn, nanos)` tuple, where `jdn` is the number of the Julian day
+/// and `nanos` is the nanoseconds elapsed since the start of that day. Julian
+/// days start at noon, so a `nanos` value of `0` is noon of the Gregorian date
+/// having the same Julian Day Number. This is an exact form of [secs_to_jd].
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{secs_to_jd_nanos, datetime_to_secs};
+///
+/// assert_eq!(secs_to_jd_nanos((0, 0)), (2440587, 43_200_000_000_000));
+/// assert_eq!(secs_to_jd_nanos((datetime_to_secs((2000, 1, 1, 12, 0, 0)), 0)), (2451545, 0));
+/// assert_eq!(secs_to_jd_nanos((datetime_to_secs((2000, 1, 1, 18, 0, 0)), 500)), (2451545, 21_600_000_000_500));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple shift of the epoch by half a day, followed by Euclidean division by
+/// the length of a day.
+#[inline]
+pub const fn secs_to_jd_nanos((secs, nsecs): (i64, u32)) -> (i32, u64) {
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given seconds value is out of range"
+    );
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds value is out of range"
+    );
+    let secs = secs + SECS_IN_DAY / 2;
+    let days = secs.div_euclid(SECS_IN_DAY) as i32 + JDN_UNIX_EPOCH - 1;
+    let secs = secs.rem_euclid(SECS_IN_DAY) as u64;
+    (days, secs * NANOS_IN_SEC + nsecs as u64)
+}
+
+/// Convert Julian Date day and nanoseconds to seconds and nanoseconds
+///
+/// Given a `(jdn, nanos)` tuple, where `jdn` is the number of the Julian day
+/// and `nanos` is the nanoseconds elapsed since the start of that day at noon,
+/// returns seconds and nanoseconds counting from Unix epoch (January 1st,
+/// 1970). This is an exact form of [jd_to_secs].
+///
+/// # Panics
+///
+/// The resulting seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX]
+/// inclusive. Nanoseconds must be between `0` and `86_399_999_999_999`. Bounds
+/// are checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{jd_nanos_to_secs, datetime_to_secs};
+///
+/// assert_eq!(jd_nanos_to_secs((2440587, 43_200_000_000_000)), (0, 0));
+/// assert_eq!(jd_nanos_to_secs((2451545, 0)), (datetime_to_secs((2000, 1, 1, 12, 0, 0)), 0));
+/// assert_eq!(jd_nanos_to_secs((2451545, 21_600_000_000_500)), (datetime_to_secs((2000, 1, 1, 18, 0, 0)), 500));
+/// ```
+///
+/// # Algorithm
+///
+/// Simple multiplication of days and division of nanoseconds, with the epoch
+/// shifted by half a day.
+#[inline]
+pub const fn jd_nanos_to_secs((jdn, nanos): (i32, u64)) -> (i64, u32) {
+    debug_assert!(nanos < NANOS_IN_DAY, "given nanoseconds value is out of range");
+    let days = (jdn - JDN_UNIX_EPOCH) as i64;
+    let secs = days * SECS_IN_DAY + SECS_IN_DAY / 2 + (nanos / NANOS_IN_SEC) as i64;
+    debug_assert!(
+        secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX,
+        "given julian date is out of range"
+    );
+    (secs, (nanos % NANOS_IN_SEC) as u32)
+}
+
+/// Convert seconds and nanoseconds to fractional Julian Date
+///
+/// Given seconds and nanoseconds counting from Unix epoch (January 1st, 1970)
+/// returns the [Julian Date](https://en.wikipedia.org/wiki/Julian_day) as a
+/// floating point value. For current dates the precision of the result is
+/// around 40 microseconds, use [secs_to_jd_nanos] for an exact result.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{secs_to_jd, datetime_to_secs};
+///
+/// assert_eq!(secs_to_jd((0, 0)), 2440587.5);
+/// assert_eq!(secs_to_jd((datetime_to_secs((2000, 1, 1, 12, 0, 0)), 0)), 2451545.0);
+/// assert_eq!(secs_to_jd((datetime_to_secs((2000, 1, 1, 18, 0, 0)), 0)), 2451545.25);
+/// ```
+///
+/// # Algorithm
+///
+/// Conversion via [secs_to_jd_nanos], so that the day and fraction of day are
+/// converted to floating point separately.
+#[inline]
+pub const fn secs_to_jd((secs, nsecs): (i64, u32)) -> f64 {
+    let (jdn, nanos) = secs_to_jd_nanos((secs, nsecs));
+    jdn as f64 + nanos as f64 / NANOS_IN_DAY as f64
+}
+
+/// Convert fractional Julian Date to seconds and nanoseconds
+///
+/// Given the [Julian Date](https://en.wikipedia.org/wiki/Julian_day) as a
+/// floating point value returns seconds and nanoseconds counting from Unix
+/// epoch (January 1st, 1970). The result is rounded to the nearest nanosecond,
+/// but for current dates the precision of the input is only around 40
+/// microseconds. Use [jd_nanos_to_secs] for an exact result.
+///
+/// # Panics
+///
+/// Argument must be a finite value, and the resulting seconds must be between
+/// [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{jd_to_secs, datetime_to_secs};
+///
+/// assert_eq!(jd_to_secs(2440587.5), (0, 0));
+/// assert_eq!(jd_to_secs(2451545.0), (datetime_to_secs((2000, 1, 1, 12, 0, 0)), 0));
+/// assert_eq!(jd_to_secs(2451545.25), (datetime_to_secs((2000, 1, 1, 18, 0, 0)), 0));
+/// ```
+///
+/// # Algorithm
+///
+/// Split into integer day and fraction of day, after which the fraction is
+/// rounded to nanoseconds and conversion done via [jd_nanos_to_secs].
+#[inline]
+pub const fn jd_to_secs(jd: f64) -> (i64, u32) {
+    debug_assert!(jd.is_finite(), "given julian date is not finite");
+    let jdn = jd as i32;
+    let jdn = if jdn as f64 > jd { jdn - 1 } else { jdn };
+    let nanos = ((jd - jdn as f64) * NANOS_IN_DAY as f64 + 0.5) as u64;
+    // rounding may carry over to the next day
+    let jdn = jdn + (nanos / NANOS_IN_DAY) as i32;
+    jd_nanos_to_secs((jdn, nanos % NANOS_IN_DAY))
+}
+
+/// Convert milliseconds to year, month, day, hours, minutes, seconds and
+/// nanoseconds
+///
+/// Given milliseconds counting from Unix epoch (January 1st, 1970) returns a
+/// `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple. Negative
+/// values are floored, so that the nanoseconds are always positive, similar to
+/// [systemtime_to_secs].
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive
+/// when converted to seconds. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::millis_to_datetime;
+///
+/// assert_eq!(millis_to_datetime(0), (1970, 1, 1, 0, 0, 0, 0));
+/// assert_eq!(millis_to_datetime(1684574678123), (2023, 5, 20, 9, 24, 38, 123_000_000));
+/// assert_eq!(millis_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_000_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division to seconds followed by [secs_to_datetime].
+#[inline]
+pub const fn millis_to_datetime(millis: i64) -> (i32, u8, u8, u8, u8, u8, u32) {
+    debug_assert!(
+        millis >= RD_SECONDS_MIN * MILLIS_IN_SEC && millis < (RD_SECONDS_MAX + 1) * MILLIS_IN_SEC,
+        "given milliseconds value is out of range"
+    );
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(millis.div_euclid(MILLIS_IN_SEC));
+    let nsec = millis.rem_euclid(MILLIS_IN_SEC) as u32 * (NANOS_IN_SEC / MILLIS_IN_SEC as u64) as u32;
+    (y, m, d, hh, mm, ss, nsec)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// milliseconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// from Unix epoch (January 1st, 1970) returns the total milliseconds.
+/// Nanoseconds are truncated to whole milliseconds, which is consistent with
+/// [millis_to_datetime] flooring negative values.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Nanoseconds must be between
+/// `0` and `999_999_999`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_millis;
+///
+/// assert_eq!(datetime_to_millis((1970, 1, 1, 0, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_millis((2023, 5, 20, 9, 24, 38, 123_456_789)), 1684574678123);
+/// assert_eq!(datetime_to_millis((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn datetime_to_millis((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i64 {
+    debug_assert!(
+        nsec >= consts::NANOSECOND_MIN && nsec <= consts::NANOSECOND_MAX,
+        "given nanoseconds value is out of range"
+    );
+    let secs = datetime_to_secs((y, m, d, hh, mm, ss));
+    secs * MILLIS_IN_SEC + (nsec / (NANOS_IN_SEC / MILLIS_IN_SEC as u64) as u32) as i64
+}
+
+/// Convert microseconds to year, month, day, hours, minutes, seconds and
+/// nanoseconds
+///
+/// Given microseconds counting from Unix epoch (January 1st, 1970) returns a
+/// `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple. Negative
+/// values are floored, so that the nanoseconds are always positive, similar to
+/// [systemtime_to_secs]. All `i64` values are within the supported range.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::micros_to_datetime;
+///
+/// assert_eq!(micros_to_datetime(0), (1970, 1, 1, 0, 0, 0, 0));
+/// assert_eq!(micros_to_datetime(1684574678123456), (2023, 5, 20, 9, 24, 38, 123_456_000));
+/// assert_eq!(micros_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_999_000));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division to seconds followed by [secs_to_datetime].
+#[inline]
+pub const fn micros_to_datetime(micros: i64) -> (i32, u8, u8, u8, u8, u8, u32) {
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(micros.div_euclid(MICROS_IN_SEC));
+    let nsec = micros.rem_euclid(MICROS_IN_SEC) as u32 * (NANOS_IN_SEC / MICROS_IN_SEC as u64) as u32;
+    (y, m, d, hh, mm, ss, nsec)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// microseconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// from Unix epoch (January 1st, 1970) returns the total microseconds.
+/// Nanoseconds are truncated to whole microseconds, which is consistent with
+/// [micros_to_datetime] flooring negative values.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Nanoseconds must be between
+/// `0` and `999_999_999`. The result must fit in `i64`, which limits the year
+/// to roughly ±292,000 years from the epoch. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_micros;
+///
+/// assert_eq!(datetime_to_micros((1970, 1, 1, 0, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_micros((2023, 5, 20, 9, 24, 38, 123_456_789)), 1684574678123456);
+/// assert_eq!(datetime_to_micros((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn datetime_to_micros((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i64 {
+    debug_assert!(
+        nsec >= consts::NANOSECOND_MIN && nsec <= consts::NANOSECOND_MAX,
+        "given nanoseconds value is out of range"
+    );
+    let secs = datetime_to_secs((y, m, d, hh, mm, ss));
+    let micros = (nsec / (NANOS_IN_SEC / MICROS_IN_SEC as u64) as u32) as i64;
+    let total = secs as i128 * MICROS_IN_SEC as i128 + micros as i128;
+    debug_assert!(
+        total >= i64::MIN as i128 && total <= i64::MAX as i128,
+        "given datetime is out of range"
+    );
+    secs.wrapping_mul(MICROS_IN_SEC).wrapping_add(micros)
+}
+
+/// Convert nanoseconds to year, month, day, hours, minutes, seconds and
+/// nanoseconds
+///
+/// Given nanoseconds counting from Unix epoch (January 1st, 1970) returns a
+/// `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple. Negative
+/// values are floored, so that the nanoseconds are always positive, similar to
+/// [systemtime_to_secs]. An `i128` is used so that the full supported range
+/// can be represented.
+///
+/// # Panics
+///
+/// Argument must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive
+/// when converted to seconds. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::nanos_to_datetime;
+///
+/// assert_eq!(nanos_to_datetime(0), (1970, 1, 1, 0, 0, 0, 0));
+/// assert_eq!(nanos_to_datetime(1684574678123456789), (2023, 5, 20, 9, 24, 38, 123_456_789));
+/// assert_eq!(nanos_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_999_999));
+/// ```
+///
+/// # Algorithm
+///
+/// Euclidean division to seconds followed by [secs_to_datetime]. Values that
+/// fit in `i64` avoid the slower 128-bit division.
+#[inline]
+pub const fn nanos_to_datetime(nanos: i128) -> (i32, u8, u8, u8, u8, u8, u32) {
+    debug_assert!(
+        nanos >= RD_SECONDS_MIN as i128 * NANOS_IN_SEC as i128 && nanos < (RD_SECONDS_MAX as i128 + 1) * NANOS_IN_SEC as i128,
+        "given nanoseconds value is out of range"
+    );
+    let (secs, nsec) = if nanos as i64 as i128 == nanos {
+        let nanos = nanos as i64;
+        (nanos.div_euclid(NANOS_IN_SEC as i64), nanos.rem_euclid(NANOS_IN_SEC as i64) as u32)
+    } else {
+        (
+            nanos.div_euclid(NANOS_IN_SEC as i128) as i64,
+            nanos.rem_euclid(NANOS_IN_SEC as i128) as u32,
+        )
+    };
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+    (y, m, d, hh, mm, ss, nsec)
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// nanoseconds
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// from Unix epoch (January 1st, 1970) returns the total nanoseconds.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Nanoseconds must be between
+/// `0` and `999_999_999`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_nanos;
+///
+/// assert_eq!(datetime_to_nanos((1970, 1, 1, 0, 0, 0, 0)), 0);
+/// assert_eq!(datetime_to_nanos((2023, 5, 20, 9, 24, 38, 123_456_789)), 1684574678123456789);
+/// assert_eq!(datetime_to_nanos((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+/// ```
+///
+/// # Algorithm
+///
+/// Algorithm is simple multiplication, method provided only as convenience.
+#[inline]
+pub const fn datetime_to_nanos((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i128 {
+    debug_assert!(
+        nsec >= consts::NANOSECOND_MIN && nsec <= consts::NANOSECOND_MAX,
+        "given nanoseconds value is out of range"
+    );
+    let secs = datetime_to_secs((y, m, d, hh, mm, ss));
+    secs as i128 * NANOS_IN_SEC as i128 + nsec as i128
+}
+
+/// Convert [`std::time::SystemTime`] to seconds and nanoseconds
+///
+/// Given [`std::time::SystemTime`] returns an `Option` of `(seconds,
+/// nanoseconds)` tuple from Unix epoch (January 1st, 1970).
+///
+/// # Errors
+///
+/// Returns `None` if the time is before [RD_SECONDS_MIN] or after
+/// [RD_SECONDS_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::systemtime_to_secs;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(systemtime_to_secs(UNIX_EPOCH), Some((0, 0)));
+/// assert_eq!(systemtime_to_secs(UNIX_EPOCH + Duration::new(1, 0)), Some((1, 0)));
+/// assert_eq!(systemtime_to_secs(UNIX_EPOCH + Duration::new(0, 1)), Some((0, 1)));
+/// assert_eq!(systemtime_to_secs(UNIX_EPOCH - Duration::new(1, 0)), Some((-1, 0)));
+/// assert_eq!(systemtime_to_secs(UNIX_EPOCH - Duration::new(0, 1)), Some((-1, 999_999_999)));
+/// ```
+///
+/// # Algorithm
+///
+/// Uses `.duration_since(UNIX_EPOCH)` and handles both positive and negative
+/// result.
+#[cfg(feature = "std")]
+#[inline]
+pub fn systemtime_to_secs(st: SystemTime) -> Option<(i64, u32)> {
+    match st.duration_since(UNIX_EPOCH) {
+        Ok(dur) => {
+            let secs = dur.as_secs();
+            let nsecs = dur.subsec_nanos();
+            if secs > RD_SECONDS_MAX as u64 {
+                return None;
+            }
+            Some((secs as i64, nsecs))
+        }
+        Err(err) => {
+            let dur = err.duration();
+            let mut secs = dur.as_secs();
+            let mut nsecs = dur.subsec_nanos();
+            if nsecs > 0 {
+                secs += 1;
+                nsecs = 1_000_000_000 - nsecs;
+            }
+            if secs > -RD_SECONDS_MIN as u64 {
+                return None;
+            }
+            Some((-(secs as i64), nsecs))
+        }
+    }
+}
+
+/// Convert seconds and nanoseconds to [`std::time::SystemTime`]
+///
+/// Given a tuple of seconds and nanoseconds counting from Unix epoch (January
+/// 1st, 1970) returns Option of [`std::time::SystemTime`].
+///
+/// # Errors
+///
+/// Returns `None` if given datetime cannot be represented as `SystemTime`.
+///
+/// # Panics
+///
+/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
+/// Nanoseconds must between `0` and `999_999_999`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::secs_to_systemtime;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(secs_to_systemtime((0, 0)), Some(UNIX_EPOCH));
+/// assert_eq!(secs_to_systemtime((0, 1)), UNIX_EPOCH.checked_add(Duration::new(0, 1)));
+/// assert_eq!(secs_to_systemtime((1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
+/// assert_eq!(secs_to_systemtime((-1, 999_999_999)), UNIX_EPOCH.checked_sub(Duration::new(0, 1)));
+/// assert_eq!(secs_to_systemtime((-1, 0)), UNIX_EPOCH.checked_sub(Duration::new(1, 0)));
+/// assert_eq!(secs_to_systemtime((-2, 999_999_999)), UNIX_EPOCH.checked_sub(Duration::new(1, 1)));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[cfg(feature = "std")]
+#[inline]
+pub fn secs_to_systemtime((secs, nsecs): (i64, u32)) -> Option<SystemTime> {
+    debug_assert!(secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX, "given seconds is out of range");
+    debug_assert!(
+        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
+        "given nanoseconds is out of range"
+    );
+    if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(secs as u64, nsecs))
+    } else if nsecs > 0 {
+        UNIX_EPOCH.checked_sub(Duration::new((-secs - 1) as u64, 1_000_000_000 - nsecs))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(-secs as u64))
+    }
+}
+
+/// Convert [`std::time::SystemTime`] to year, month, day, hours, minutes,
+/// seconds and nanoseconds
+///
+/// Given [`std::time::SystemTime`] returns an Option of `(year, month, day,
+/// hours, minutes, seconds, nanoseconds)` tuple.
+///
+/// # Errors
+///
+/// Returns `None` if the time is before [RD_SECONDS_MIN] or after
+/// [RD_SECONDS_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::systemtime_to_datetime;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH), Some((1970, 1, 1, 0, 0, 0, 0)));
+/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH + Duration::from_secs(1684574678)), Some((2023, 5, 20, 9, 24, 38, 0)));
+/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH - Duration::from_secs(1)), Some((1969, 12, 31, 23, 59, 59, 0)));
+/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH - Duration::new(0, 1)), Some((1969, 12, 31, 23, 59, 59, 999_999_999)));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[cfg(feature = "std")]
+#[inline]
+pub fn systemtime_to_datetime(st: SystemTime) -> Option<(i32, u8, u8, u8, u8, u8, u32)> {
+    let (secs, nsecs) = systemtime_to_secs(st)?;
+    let (days, hh, mm, ss) = secs_to_dhms(secs);
+    let (year, month, day) = rd_to_date(days);
+    Some((year, month, day, hh, mm, ss, nsecs))
+}
+
+/// Convert year, month, day, hours, minutes, seconds and nanoseconds to
+/// [`std::time::SystemTime`]
+///
+/// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
+/// from Unix epoch (January 1st, 1970) returns Option of
+/// [`std::time::SystemTime`].
+///
+/// # Errors
+///
+/// Returns `None` if given datetime cannot be represented as `SystemTime`.
+///
+/// # Panics
+///
+/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
+/// and `12`. Day must be between `1` and the number of days in the month in
+/// question. Hours must be between `0` and `23`. Minutes must be between `0`
+/// and `59`. Seconds must be between `0` and `59`. Nanoseconds must be between
+/// `0` and `999_999_999`. Bounds are checked using `debug_assert` only, so that
+/// the checks are not present in release builds, similar to integer overflow
+/// checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::datetime_to_systemtime;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(datetime_to_systemtime((1970, 1, 1, 0, 0, 0, 0)), Some(UNIX_EPOCH));
+/// assert_eq!(datetime_to_systemtime((1970, 1, 1, 0, 0, 1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
+/// assert_eq!(datetime_to_systemtime((2023, 5, 20, 9, 24, 38, 0)), UNIX_EPOCH.checked_add(Duration::from_secs(1684574678)));
+/// ```
+///
+/// # Algorithm
+///
+/// Combination of existing functions for convenience only.
+#[cfg(feature = "std")]
+#[inline]
+pub fn datetime_to_systemtime((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> Option<SystemTime> {
+    let days = date_to_rd((y, m, d));
+    let secs = dhms_to_secs((days, hh, mm, ss));
+    secs_to_systemtime((secs, nsec))
+}
+
+/// Validate hours, minutes and seconds
+#[inline]
+const fn validate_hms(hh: u8, mm: u8, ss: u8) -> Result<(), DateError> {
+    if hh < consts::HOUR_MIN || hh > consts::HOUR_MAX {
+        Err(DateError::HourOutOfRange)
+    } else if mm < consts::MINUTE_MIN || mm > consts::MINUTE_MAX {
+        Err(DateError::MinuteOutOfRange)
+    } else if ss < consts::SECOND_MIN || ss > consts::SECOND_MAX {
+        Err(DateError::SecondOutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// Validate Gregorian date
+///
+/// Given a `(year, month, day)` tuple returns `Ok(())` if the date is valid and
+/// within the supported range, or a [DateError] describing the first invalid
+/// field otherwise. Fields are checked in order from year to day.
+///
+/// # Errors
+///
+/// Returns [DateError::YearOutOfRange] if year is not between [YEAR_MIN] and
+/// [YEAR_MAX], [DateError::MonthOutOfRange] if month is not between `1` and
+/// `12` and [DateError::DayOutOfRange] if day is not between `1` and the number
+/// of days in the month in question.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{validate_date, DateError};
+///
+/// assert_eq!(validate_date((2024, 2, 29)), Ok(()));
+/// assert_eq!(validate_date((2023, 2, 29)), Err(DateError::DayOutOfRange { max: 28 }));
+/// assert_eq!(validate_date((2023, 13, 1)), Err(DateError::MonthOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Straightforward comparisons using [days_in_month].
+#[inline]
+pub const fn validate_date((y, m, d): (i32, u8, u8)) -> Result<(), DateError> {
+    if y < YEAR_MIN || y > YEAR_MAX {
+        Err(DateError::YearOutOfRange)
+    } else if m < consts::MONTH_MIN || m > consts::MONTH_MAX {
+        Err(DateError::MonthOutOfRange)
+    } else {
+        let max = days_in_month(y, m);
+        if d < consts::DAY_MIN || d > max {
+            Err(DateError::DayOutOfRange { max })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Validate Gregorian date and time of day
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns `Ok(())`
+/// if the datetime is valid and within the supported range, or a [DateError]
+/// describing the first invalid field otherwise. Fields are checked in order
+/// from year to seconds.
+///
+/// # Errors
+///
+/// Returns the same errors as [validate_date] for the date part, and
+/// [DateError::HourOutOfRange], [DateError::MinuteOutOfRange] or
+/// [DateError::SecondOutOfRange] if hours is not between `0` and `23`, minutes
+/// is not between `0` and `59` or seconds is not between `0` and `59`
+/// respectively.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{validate_datetime, DateError};
+///
+/// assert_eq!(validate_datetime((2023, 5, 20, 9, 24, 38)), Ok(()));
+/// assert_eq!(validate_datetime((2023, 5, 20, 24, 0, 0)), Err(DateError::HourOutOfRange));
+/// assert_eq!(validate_datetime((2023, 5, 20, 23, 59, 60)), Err(DateError::SecondOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Straightforward comparisons using [validate_date].
+#[inline]
+pub const fn validate_datetime((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> Result<(), DateError> {
+    match validate_date((y, m, d)) {
+        Ok(()) => validate_hms(hh, mm, ss),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date)
+///
+/// Given a `(year, week, day of week)` tuple returns `Ok(())` if the ISO week
+/// date is valid and within the supported range, or a [DateError] describing
+/// the first invalid field otherwise. Fields are checked in order from year to
+/// day of week.
+///
+/// # Errors
+///
+/// Returns [DateError::YearOutOfRange] if year is not between [YEAR_MIN] and
+/// [YEAR_MAX], [DateError::WeekOutOfRange] if week is not between `1` and the
+/// number of ISO weeks in the given year and [DateError::WeekdayOutOfRange] if
+/// day of week is not between `1` and `7` or the date would fall after
+/// [YEAR_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{validate_isoweekdate, DateError};
+///
+/// assert_eq!(validate_isoweekdate((2026, 53, 7)), Ok(()));
+/// assert_eq!(validate_isoweekdate((2025, 53, 1)), Err(DateError::WeekOutOfRange { weeks_in_year: 52 }));
+/// assert_eq!(validate_isoweekdate((2025, 1, 0)), Err(DateError::WeekdayOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Straightforward comparisons using [isoweeks_in_year].
+#[inline]
+pub const fn validate_isoweekdate((y, w, d): (i32, u8, u8)) -> Result<(), DateError> {
+    if y < YEAR_MIN || y > YEAR_MAX {
+        return Err(DateError::YearOutOfRange);
+    }
+    let weeks_in_year = isoweeks_in_year(y);
+    if w < consts::WEEK_MIN || w > weeks_in_year {
+        Err(DateError::WeekOutOfRange { weeks_in_year })
+    } else if d < consts::WEEKDAY_MIN || d > consts::WEEKDAY_MAX || (y == YEAR_MAX && w == consts::WEEK_MAX && d > consts::THURSDAY) {
+        Err(DateError::WeekdayOutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// Convert Rata Die to Gregorian date, checking bounds
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns an `Option`
+/// of `(year, month, day)` tuple. This is the checked counterpart of
+/// [rd_to_date].
+///
+/// # Errors
+///
+/// Returns `None` if the argument is not between [RD_MIN] and [RD_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_rd_to_date, RD_MAX};
+///
+/// assert_eq!(checked_rd_to_date(19489), Some((2023, 5, 12)));
+/// assert_eq!(checked_rd_to_date(RD_MAX + 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [rd_to_date].
+#[inline]
+pub const fn checked_rd_to_date(n: i32) -> Option<(i32, u8, u8)> {
+    if n >= RD_MIN && n <= RD_MAX {
+        Some(rd_to_date(n))
+    } else {
+        None
+    }
+}
+
+/// Convert Gregorian date to Rata Die, checking bounds
+///
+/// Given a `(year, month, day)` tuple returns an `Option` of the days since
+/// Unix epoch (January 1st, 1970). This is the checked counterpart of
+/// [date_to_rd].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12` or day is not between `1` and the number of days in
+/// the month in question.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_date_to_rd;
+///
+/// assert_eq!(checked_date_to_rd((2023, 5, 12)), Some(19489));
+/// assert_eq!(checked_date_to_rd((2023, 2, 29)), None);
+/// assert_eq!(checked_date_to_rd((2023, 13, 1)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [date_to_rd].
+#[inline]
+pub const fn checked_date_to_rd((y, m, d): (i32, u8, u8)) -> Option<i32> {
+    if validate_date((y, m, d)).is_ok() {
+        Some(date_to_rd((y, m, d)))
+    } else {
+        None
+    }
+}
+
+/// Convert Rata Die to day of week, checking bounds
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns an `Option`
+/// of the day of week. This is the checked counterpart of [rd_to_weekday].
+///
+/// # Errors
+///
+/// Returns `None` if the argument is not between [RD_MIN] and [RD_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_rd_to_weekday, RD_MIN};
+///
+/// assert_eq!(checked_rd_to_weekday(0), Some(4));
+/// assert_eq!(checked_rd_to_weekday(RD_MIN - 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [rd_to_weekday].
+#[inline]
+pub const fn checked_rd_to_weekday(n: i32) -> Option<u8> {
+    if n >= RD_MIN && n <= RD_MAX {
+        Some(rd_to_weekday(n))
+    } else {
+        None
+    }
+}
+
+/// Convert Gregorian date to day of week, checking bounds
+///
+/// Given a `(year, month, day)` tuple returns an `Option` of the day of week.
+/// This is the checked counterpart of [date_to_weekday].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12` or day is not between `1` and the number of days in
+/// the month in question.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_date_to_weekday;
+///
+/// assert_eq!(checked_date_to_weekday((2023, 5, 12)), Some(5));
+/// assert_eq!(checked_date_to_weekday((2023, 4, 31)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [date_to_weekday].
+#[inline]
+pub const fn checked_date_to_weekday((y, m, d): (i32, u8, u8)) -> Option<u8> {
+    if validate_date((y, m, d)).is_ok() {
+        Some(date_to_weekday((y, m, d)))
+    } else {
+        None
+    }
+}
+
+/// Calculate next Gregorian date given a Gregorian date, checking bounds
+///
+/// Given a `(year, month, day)` tuple returns an `Option` of the `(year, month,
+/// day)` tuple for the following Gregorian date. This is the checked
+/// counterpart of [next_date].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12`, day is not between `1` and the number of days in
+/// the month in question or the next date would be after [YEAR_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_next_date, YEAR_MAX};
+///
+/// assert_eq!(checked_next_date((2023, 12, 31)), Some((2024, 1, 1)));
+/// assert_eq!(checked_next_date((2023, 6, 31)), None);
+/// assert_eq!(checked_next_date((YEAR_MAX, 12, 31)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [next_date].
+#[inline]
+pub const fn checked_next_date((y, m, d): (i32, u8, u8)) -> Option<(i32, u8, u8)> {
+    if validate_date((y, m, d)).is_ok() && (y != YEAR_MAX || m != consts::MONTH_MAX || d != consts::DAY_MAX) {
+        Some(next_date((y, m, d)))
+    } else {
+        None
+    }
+}
+
+/// Calculate previous Gregorian date given a Gregorian date, checking bounds
+///
+/// Given a `(year, month, day)` tuple returns an `Option` of the `(year, month,
+/// day)` tuple for the preceding Gregorian date. This is the checked
+/// counterpart of [prev_date].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12`, day is not between `1` and the number of days in
+/// the month in question or the previous date would be before [YEAR_MIN].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_prev_date, YEAR_MIN};
+///
+/// assert_eq!(checked_prev_date((2024, 1, 1)), Some((2023, 12, 31)));
+/// assert_eq!(checked_prev_date((2023, 1, 0)), None);
+/// assert_eq!(checked_prev_date((YEAR_MIN, 1, 1)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [prev_date].
+#[inline]
+pub const fn checked_prev_date((y, m, d): (i32, u8, u8)) -> Option<(i32, u8, u8)> {
+    if validate_date((y, m, d)).is_ok() && (y != YEAR_MIN || m != consts::MONTH_MIN || d != consts::DAY_MIN) {
+        Some(prev_date((y, m, d)))
+    } else {
+        None
+    }
+}
+
+/// Split total seconds to days, hours, minutes and seconds, checking bounds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns an
+/// `Option` of `(days, hours, minutes, seconds)` tuple. This is the checked
+/// counterpart of [secs_to_dhms].
+///
+/// # Errors
+///
+/// Returns `None` if the argument is not between [RD_SECONDS_MIN] and
+/// [RD_SECONDS_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_secs_to_dhms, RD_SECONDS_MAX};
+///
+/// assert_eq!(checked_secs_to_dhms(-1), Some((-1, 23, 59, 59)));
+/// assert_eq!(checked_secs_to_dhms(RD_SECONDS_MAX + 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [secs_to_dhms].
+#[inline]
+pub const fn checked_secs_to_dhms(secs: i64) -> Option<(i32, u8, u8, u8)> {
+    if secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX {
+        Some(secs_to_dhms(secs))
+    } else {
+        None
+    }
+}
+
+/// Combine days, hours, minutes and seconds to total seconds, checking bounds
+///
+/// Given a `(days, hours, minutes, seconds)` tuple from Unix epoch (January
+/// 1st, 1970) returns an `Option` of the total seconds. This is the checked
+/// counterpart of [dhms_to_secs].
+///
+/// # Errors
+///
+/// Returns `None` if days is not between [RD_MIN] and [RD_MAX], hours is not
+/// between `0` and `23`, minutes is not between `0` and `59` or seconds is not
+/// between `0` and `59`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_dhms_to_secs;
+///
+/// assert_eq!(checked_dhms_to_secs((1, 0, 0, 0)), Some(86400));
+/// assert_eq!(checked_dhms_to_secs((1, 24, 0, 0)), None);
+/// assert_eq!(checked_dhms_to_secs((1, 0, 0, 60)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [dhms_to_secs].
+#[inline]
+pub const fn checked_dhms_to_secs((d, h, m, s): (i32, u8, u8, u8)) -> Option<i64> {
+    if d >= RD_MIN && d <= RD_MAX && validate_hms(h, m, s).is_ok() {
+        Some(dhms_to_secs((d, h, m, s)))
+    } else {
+        None
+    }
+}
+
+/// Convert total seconds to year, month, day, hours, minutes and seconds,
+/// checking bounds
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) returns an
+/// `Option` of `(year, month, day, hours, minutes, seconds)` tuple. This is
+/// the checked counterpart of [secs_to_datetime].
+///
+/// # Errors
+///
+/// Returns `None` if the argument is not between [RD_SECONDS_MIN] and
+/// [RD_SECONDS_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_secs_to_datetime, RD_SECONDS_MIN};
+///
+/// assert_eq!(checked_secs_to_datetime(1684574678), Some((2023, 5, 20, 9, 24, 38)));
+/// assert_eq!(checked_secs_to_datetime(RD_SECONDS_MIN - 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [secs_to_datetime].
+#[inline]
+pub const fn checked_secs_to_datetime(secs: i64) -> Option<(i32, u8, u8, u8, u8, u8)> {
+    if secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX {
+        Some(secs_to_datetime(secs))
+    } else {
+        None
+    }
+}
+
+/// Convert year, month, day, hours, minutes and seconds to total seconds,
+/// checking bounds
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple from Unix epoch
+/// (January 1st, 1970) returns an `Option` of the total seconds. This is the
+/// checked counterpart of [datetime_to_secs].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12`, day is not between `1` and the number of days in
+/// the month in question, hours is not between `0` and `23`, minutes is not
+/// between `0` and `59` or seconds is not between `0` and `59`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_datetime_to_secs;
+///
+/// assert_eq!(checked_datetime_to_secs((2023, 5, 20, 9, 24, 38)), Some(1684574678));
+/// assert_eq!(checked_datetime_to_secs((2023, 5, 20, 9, 60, 38)), None);
+/// assert_eq!(checked_datetime_to_secs((2023, 5, 32, 9, 24, 38)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [datetime_to_secs].
+#[inline]
+pub const fn checked_datetime_to_secs((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> Option<i64> {
+    if validate_datetime((y, m, d, hh, mm, ss)).is_ok() {
+        Some(datetime_to_secs((y, m, d, hh, mm, ss)))
+    } else {
+        None
+    }
+}
+
+/// Determine if the given year is a leap year, checking bounds
+///
+/// This is the checked counterpart of [is_leap_year].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX] inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_is_leap_year, YEAR_MAX};
+///
+/// assert_eq!(checked_is_leap_year(2024), Some(true));
+/// assert_eq!(checked_is_leap_year(YEAR_MAX + 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [is_leap_year].
+#[inline]
+pub const fn checked_is_leap_year(y: i32) -> Option<bool> {
+    if y >= YEAR_MIN && y <= YEAR_MAX {
+        Some(is_leap_year(y))
+    } else {
+        None
+    }
+}
+
+/// Determine the number of days in the given month in the given year, checking
+/// bounds
+///
+/// This is the checked counterpart of [days_in_month].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX] or month is
+/// not between `1` and `12`.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_days_in_month;
+///
+/// assert_eq!(checked_days_in_month(2024, 2), Some(29));
+/// assert_eq!(checked_days_in_month(2024, 0), None);
+/// assert_eq!(checked_days_in_month(2024, 13), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [days_in_month].
+#[inline]
+pub const fn checked_days_in_month(y: i32, m: u8) -> Option<u8> {
+    if y >= YEAR_MIN && y <= YEAR_MAX && m >= consts::MONTH_MIN && m <= consts::MONTH_MAX {
+        Some(days_in_month(y, m))
+    } else {
+        None
+    }
+}
+
+/// Convert Rata Die to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date),
+/// checking bounds
+///
+/// Given a day counting from Unix epoch (January 1st, 1970) returns an `Option`
+/// of `(year, week, day of week)` tuple. This is the checked counterpart of
+/// [rd_to_isoweekdate].
+///
+/// # Errors
+///
+/// Returns `None` if the argument is not between [RD_MIN] and [RD_MAX]
+/// inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_rd_to_isoweekdate, date_to_rd, RD_MAX};
+///
+/// assert_eq!(checked_rd_to_isoweekdate(date_to_rd((2023, 5, 12))), Some((2023, 19, 5)));
+/// assert_eq!(checked_rd_to_isoweekdate(RD_MAX + 1), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [rd_to_isoweekdate].
+#[inline]
+pub const fn checked_rd_to_isoweekdate(rd: i32) -> Option<(i32, u8, u8)> {
+    if rd >= RD_MIN && rd <= RD_MAX {
+        Some(rd_to_isoweekdate(rd))
+    } else {
+        None
+    }
+}
+
+/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to Rata
+/// Die, checking bounds
+///
+/// Given a `(year, week, day of week)` tuple returns an `Option` of the days
+/// since Unix epoch (January 1st, 1970). This is the checked counterpart of
+/// [isoweekdate_to_rd].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], week is not
+/// between `1` and the number of ISO weeks in the given year, day is not
+/// between `1` and `7` or the resulting date would be after [RD_MAX].
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::{checked_isoweekdate_to_rd, date_to_rd};
+///
+/// assert_eq!(checked_isoweekdate_to_rd((2026, 53, 1)), Some(date_to_rd((2026, 12, 28))));
+/// assert_eq!(checked_isoweekdate_to_rd((2025, 53, 1)), None);
+/// assert_eq!(checked_isoweekdate_to_rd((2025, 1, 8)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [isoweekdate_to_rd].
+#[inline]
+pub const fn checked_isoweekdate_to_rd((y, w, d): (i32, u8, u8)) -> Option<i32> {
+    if validate_isoweekdate((y, w, d)).is_ok() {
+        Some(isoweekdate_to_rd((y, w, d)))
+    } else {
+        None
+    }
+}
+
+/// Convert Gregorian date to [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date),
+/// checking bounds
+///
+/// Given a `(year, month, day)` tuple returns an `Option` of `(year, week, day
+/// of week)` tuple. This is the checked counterpart of [date_to_isoweekdate].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12` or day is not between `1` and the number of days in
+/// the month in question.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::checked_date_to_isoweekdate;
+///
+/// assert_eq!(checked_date_to_isoweekdate((2023, 1, 1)), Some((2022, 52, 7)));
+/// assert_eq!(checked_date_to_isoweekdate((2023, 0, 1)), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Bounds checks followed by [date_to_isoweekdate].
+#[inline]
+pub const fn checked_date_to_isoweekdate((y, m, d): (i32, u8, u8)) -> Option<(i32, u8, u8)> {
+    if validate_date((y, m, d)).is_ok() {
+        Some(date_to_isoweekdate((y, m, d)))
+    } else {
+        None
+    }
+}
+
+/// Convert [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date) to
+/// Gregorian date, checking bounds
+///
+/// Given a `(year, week, day of week)` tuple returns an `Option` of `(year,
+/// month, day)` tuple. This is the checked counterpart of
+/// [isoweekdate_to_date].
+///
+/// # Errors
+///
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], week is not
+/// between `1` and the number of ISO weeks in the given year, day is not
+/// between `1` and `7` or the resulting date would be after [YEAR_MAX].
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::systemtime_to_secs;
-/// use std::time::{Duration, UNIX_EPOCH};
+/// use datealgo::checked_isoweekdate_to_date;
 ///
-/// assert_eq!(systemtime_to_secs(UNIX_EPOCH), Some((0, 0)));
-/// assert_eq!(systemtime_to_secs(UNIX_EPOCH + Duration::new(1, 0)), Some((1, 0)));
-/// assert_eq!(systemtime_to_secs(UNIX_EPOCH + Duration::new(0, 1)), Some((0, 1)));
-/// assert_eq!(systemtime_to_secs(UNIX_EPOCH - Duration::new(1, 0)), Some((-1, 0)));
-/// assert_eq!(systemtime_to_secs(UNIX_EPOCH - Duration::new(0, 1)), Some((-1, 999_999_999)));
+/// assert_eq!(checked_isoweekdate_to_date((1981, 53, 5)), Some((1982, 1, 1)));
+/// assert_eq!(checked_isoweekdate_to_date((1982, 53, 5)), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Uses `.duration_since(UNIX_EPOCH)` and handles both positive and negative
-/// result.
-#[cfg(feature = "std")]
+/// Bounds checks followed by [isoweekdate_to_date].
 #[inline]
-pub fn systemtime_to_secs(st: SystemTime) -> Option<(i64, u32)> {
-    match st.duration_since(UNIX_EPOCH) {
-        Ok(dur) => {
-            let secs = dur.as_secs();
-            let nsecs = dur.subsec_nanos();
-            if secs > RD_SECONDS_MAX as u64 {
-                return None;
-            }
-            Some((secs as i64, nsecs))
-        }
-        Err(err) => {
-            let dur = err.duration();
-            let mut secs = dur.as_secs();
-            let mut nsecs = dur.subsec_nanos();
-            if nsecs > 0 {
-                secs += 1;
-                nsecs = 1_000_000_000 - nsecs;
-            }
-            if secs > -RD_SECONDS_MIN as u64 {
-                return None;
-            }
-            Some((-(secs as i64), nsecs))
-        }
+pub const fn checked_isoweekdate_to_date((y, w, d): (i32, u8, u8)) -> Option<(i32, u8, u8)> {
+    if validate_isoweekdate((y, w, d)).is_ok() {
+        Some(isoweekdate_to_date((y, w, d)))
+    } else {
+        None
     }
 }
 
-/// Convert seconds and nanoseconds to [`std::time::SystemTime`]
+/// Determine the number of [ISO weeks](https://en.wikipedia.org/wiki/ISO_week_date)
+/// in the given year, checking bounds
 ///
-/// Given a tuple of seconds and nanoseconds counting from Unix epoch (January
-/// 1st, 1970) returns Option of [`std::time::SystemTime`].
+/// This is the checked counterpart of [isoweeks_in_year].
 ///
 /// # Errors
 ///
-/// Returns `None` if given datetime cannot be represented as `SystemTime`.
-///
-/// # Panics
-///
-/// Seconds must be between [RD_SECONDS_MIN] and [RD_SECONDS_MAX] inclusive.
-/// Nanoseconds must between `0` and `999_999_999`. Bounds are checked using
-/// `debug_assert` only, so that the checks are not present in release builds,
-/// similar to integer overflow checks.
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX] inclusive.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::secs_to_systemtime;
-/// use std::time::{Duration, UNIX_EPOCH};
+/// use datealgo::{checked_isoweeks_in_year, YEAR_MIN};
 ///
-/// assert_eq!(secs_to_systemtime((0, 0)), Some(UNIX_EPOCH));
-/// assert_eq!(secs_to_systemtime((0, 1)), UNIX_EPOCH.checked_add(Duration::new(0, 1)));
-/// assert_eq!(secs_to_systemtime((1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
-/// assert_eq!(secs_to_systemtime((-1, 999_999_999)), UNIX_EPOCH.checked_sub(Duration::new(0, 1)));
-/// assert_eq!(secs_to_systemtime((-1, 0)), UNIX_EPOCH.checked_sub(Duration::new(1, 0)));
-/// assert_eq!(secs_to_systemtime((-2, 999_999_999)), UNIX_EPOCH.checked_sub(Duration::new(1, 1)));
+/// assert_eq!(checked_isoweeks_in_year(2026), Some(53));
+/// assert_eq!(checked_isoweeks_in_year(YEAR_MIN - 1), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Combination of existing functions for convenience only.
-#[cfg(feature = "std")]
+/// Bounds checks followed by [isoweeks_in_year].
 #[inline]
-pub fn secs_to_systemtime((secs, nsecs): (i64, u32)) -> Option<SystemTime> {
-    debug_assert!(secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX, "given seconds is out of range");
-    debug_assert!(
-        nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX,
-        "given nanoseconds is out of range"
-    );
-    if secs >= 0 {
-        UNIX_EPOCH.checked_add(Duration::new(secs as u64, nsecs))
-    } else if nsecs > 0 {
-        UNIX_EPOCH.checked_sub(Duration::new((-secs - 1) as u64, 1_000_000_000 - nsecs))
+pub const fn checked_isoweeks_in_year(y: i32) -> Option<u8> {
+    if y >= YEAR_MIN && y <= YEAR_MAX {
+        Some(isoweeks_in_year(y))
     } else {
-        UNIX_EPOCH.checked_sub(Duration::from_secs(-secs as u64))
+        None
     }
 }
 
-/// Convert [`std::time::SystemTime`] to year, month, day, hours, minutes,
-/// seconds and nanoseconds
+/// Convert seconds and nanoseconds to [`std::time::SystemTime`], checking
+/// bounds
 ///
-/// Given [`std::time::SystemTime`] returns an Option of `(year, month, day,
-/// hours, minutes, seconds, nanoseconds)` tuple.
+/// Given a tuple of seconds and nanoseconds counting from Unix epoch (January
+/// 1st, 1970) returns Option of [`std::time::SystemTime`]. This is the checked
+/// counterpart of [secs_to_systemtime].
 ///
 /// # Errors
 ///
-/// Returns `None` if the time is before [RD_SECONDS_MIN] or after
-/// [RD_SECONDS_MAX].
+/// Returns `None` if seconds is not between [RD_SECONDS_MIN] and
+/// [RD_SECONDS_MAX], nanoseconds is not between `0` and `999_999_999` or the
+/// result cannot be represented as `SystemTime`.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::systemtime_to_datetime;
+/// use datealgo::checked_secs_to_systemtime;
 /// use std::time::{Duration, UNIX_EPOCH};
 ///
-/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH), Some((1970, 1, 1, 0, 0, 0, 0)));
-/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH + Duration::from_secs(1684574678)), Some((2023, 5, 20, 9, 24, 38, 0)));
-/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH - Duration::from_secs(1)), Some((1969, 12, 31, 23, 59, 59, 0)));
-/// assert_eq!(systemtime_to_datetime(UNIX_EPOCH - Duration::new(0, 1)), Some((1969, 12, 31, 23, 59, 59, 999_999_999)));
+/// assert_eq!(checked_secs_to_systemtime((1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
+/// assert_eq!(checked_secs_to_systemtime((1, 1_000_000_000)), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Combination of existing functions for convenience only.
+/// Bounds checks followed by [secs_to_systemtime].
 #[cfg(feature = "std")]
 #[inline]
-pub fn systemtime_to_datetime(st: SystemTime) -> Option<(i32, u8, u8, u8, u8, u8, u32)> {
-    let (secs, nsecs) = systemtime_to_secs(st)?;
-    let (days, hh, mm, ss) = secs_to_dhms(secs);
-    let (year, month, day) = rd_to_date(days);
-    Some((year, month, day, hh, mm, ss, nsecs))
+pub fn checked_secs_to_systemtime((secs, nsecs): (i64, u32)) -> Option<SystemTime> {
+    if secs >= RD_SECONDS_MIN && secs <= RD_SECONDS_MAX && nsecs >= consts::NANOSECOND_MIN && nsecs <= consts::NANOSECOND_MAX {
+        secs_to_systemtime((secs, nsecs))
+    } else {
+        None
+    }
 }
 
 /// Convert year, month, day, hours, minutes, seconds and nanoseconds to
-/// [`std::time::SystemTime`]
+/// [`std::time::SystemTime`], checking bounds
 ///
 /// Given a `(year, month, day, hours, minutes, seconds, nanoseconds)` tuple
 /// from Unix epoch (January 1st, 1970) returns Option of
-/// [`std::time::SystemTime`].
+/// [`std::time::SystemTime`]. This is the checked counterpart of
+/// [datetime_to_systemtime].
 ///
 /// # Errors
 ///
-/// Returns `None` if given datetime cannot be represented as `SystemTime`.
-///
-/// # Panics
-///
-/// Year must be between [YEAR_MIN] and [YEAR_MAX]. Month must be between `1`
-/// and `12`. Day must be between `1` and the number of days in the month in
-/// question. Hours must be between `0` and `23`. Minutes must be between `0`
-/// and `59`. Seconds must be between `0` and `59`. Nanoseconds must be between
-/// `0` and `999_999_999`. Bounds are checked using `debug_assert` only, so that
-/// the checks are not present in release builds, similar to integer overflow
-/// checks.
+/// Returns `None` if year is not between [YEAR_MIN] and [YEAR_MAX], month is
+/// not between `1` and `12`, day is not between `1` and the number of days in
+/// the month in question, hours is not between `0` and `23`, minutes is not
+/// between `0` and `59`, seconds is not between `0` and `59`, nanoseconds is
+/// not between `0` and `999_999_999` or the result cannot be represented as
+/// `SystemTime`.
 ///
 /// # Examples
 ///
 /// ```
-/// use datealgo::datetime_to_systemtime;
+/// use datealgo::checked_datetime_to_systemtime;
 /// use std::time::{Duration, UNIX_EPOCH};
 ///
-/// assert_eq!(datetime_to_systemtime((1970, 1, 1, 0, 0, 0, 0)), Some(UNIX_EPOCH));
-/// assert_eq!(datetime_to_systemtime((1970, 1, 1, 0, 0, 1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
-/// assert_eq!(datetime_to_systemtime((2023, 5, 20, 9, 24, 38, 0)), UNIX_EPOCH.checked_add(Duration::from_secs(1684574678)));
+/// assert_eq!(checked_datetime_to_systemtime((1970, 1, 1, 0, 0, 1, 0)), UNIX_EPOCH.checked_add(Duration::new(1, 0)));
+/// assert_eq!(checked_datetime_to_systemtime((1970, 2, 30, 0, 0, 0, 0)), None);
 /// ```
 ///
 /// # Algorithm
 ///
-/// Combination of existing functions for convenience only.
+/// Bounds checks followed by [datetime_to_systemtime].
 #[cfg(feature = "std")]
 #[inline]
-pub fn datetime_to_systemtime((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> Option<SystemTime> {
-    let days = date_to_rd((y, m, d));
-    let secs = dhms_to_secs((days, hh, mm, ss));
-    secs_to_systemtime((secs, nsec))
+pub fn checked_datetime_to_systemtime((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> Option<SystemTime> {
+    if validate_datetime((y, m, d, hh, mm, ss)).is_ok() && nsec >= consts::NANOSECOND_MIN && nsec <= consts::NANOSECOND_MAX {
+        datetime_to_systemtime((y, m, d, hh, mm, ss, nsec))
+    } else {
+        None
+    }
 }
 
 #[cfg(feature = "asmdump")]
@@ -1297,6 +4060,30 @@ pub mod asm {
         super::date_to_weekday((y, m, d))
     }
     #[inline(never)]
+    pub const fn weekday_on_or_after(n: i32, wd: u8) -> i32 {
+        super::weekday_on_or_after(n, wd)
+    }
+    #[inline(never)]
+    pub const fn weekday_on_or_before(n: i32, wd: u8) -> i32 {
+        super::weekday_on_or_before(n, wd)
+    }
+    #[inline(never)]
+    pub const fn next_weekday(n: i32, wd: u8) -> i32 {
+        super::next_weekday(n, wd)
+    }
+    #[inline(never)]
+    pub const fn prev_weekday(n: i32, wd: u8) -> i32 {
+        super::prev_weekday(n, wd)
+    }
+    #[inline(never)]
+    pub const fn nth_weekday_of_month(y: i32, m: u8, wd: u8, n: u8) -> Option<u8> {
+        super::nth_weekday_of_month(y, m, wd, n)
+    }
+    #[inline(never)]
+    pub const fn last_weekday_of_month(y: i32, m: u8, wd: u8) -> u8 {
+        super::last_weekday_of_month(y, m, wd)
+    }
+    #[inline(never)]
     pub const fn next_date((y, m, d): (i32, u8, u8)) -> (i32, u8, u8) {
         super::next_date((y, m, d))
     }
@@ -1305,6 +4092,26 @@ pub mod asm {
         super::prev_date((y, m, d))
     }
     #[inline(never)]
+    pub const fn add_months((y, m, d): (i32, u8, u8), n: i32, overflow: super::DayOverflow) -> Option<(i32, u8, u8)> {
+        super::add_months((y, m, d), n, overflow)
+    }
+    #[inline(never)]
+    pub const fn add_years((y, m, d): (i32, u8, u8), n: i32, overflow: super::DayOverflow) -> Option<(i32, u8, u8)> {
+        super::add_years((y, m, d), n, overflow)
+    }
+    #[inline(never)]
+    pub const fn months_between((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> i32 {
+        super::months_between((ya, ma, da), (yb, mb, db))
+    }
+    #[inline(never)]
+    pub const fn whole_years_between((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> i32 {
+        super::whole_years_between((ya, ma, da), (yb, mb, db))
+    }
+    #[inline(never)]
+    pub const fn date_diff_ymd((ya, ma, da): (i32, u8, u8), (yb, mb, db): (i32, u8, u8)) -> (i32, i8, i8) {
+        super::date_diff_ymd((ya, ma, da), (yb, mb, db))
+    }
+    #[inline(never)]
     pub const fn secs_to_dhms(secs: i64) -> (i32, u8, u8, u8) {
         super::secs_to_dhms(secs)
     }
@@ -1348,6 +4155,261 @@ pub mod asm {
     pub const fn isoweeks_in_year(y: i32) -> u8 {
         super::isoweeks_in_year(y)
     }
+    #[inline(never)]
+    pub const fn days_in_year(y: i32) -> u16 {
+        super::days_in_year(y)
+    }
+    #[inline(never)]
+    pub const fn rd_to_ordinal(n: i32) -> (i32, u16) {
+        super::rd_to_ordinal(n)
+    }
+    #[inline(never)]
+    pub const fn ordinal_to_rd((y, d): (i32, u16)) -> i32 {
+        super::ordinal_to_rd((y, d))
+    }
+    #[inline(never)]
+    pub const fn date_to_ordinal((y, m, d): (i32, u8, u8)) -> (i32, u16) {
+        super::date_to_ordinal((y, m, d))
+    }
+    #[inline(never)]
+    pub const fn ordinal_to_date((y, d): (i32, u16)) -> (i32, u8, u8) {
+        super::ordinal_to_date((y, d))
+    }
+    #[inline(never)]
+    pub const fn rd_to_julian_date(n: i32) -> (i32, u8, u8) {
+        super::rd_to_julian_date(n)
+    }
+    #[inline(never)]
+    pub const fn julian_date_to_rd((y, m, d): (i32, u8, u8)) -> i32 {
+        super::julian_date_to_rd((y, m, d))
+    }
+    #[inline(never)]
+    pub const fn is_julian_leap_year(y: i32) -> bool {
+        super::is_julian_leap_year(y)
+    }
+    #[inline(never)]
+    pub const fn julian_days_in_month(y: i32, m: u8) -> u8 {
+        super::julian_days_in_month(y, m)
+    }
+    #[inline(never)]
+    pub const fn rd_to_historical_date(n: i32, reform: i32) -> (i32, u8, u8) {
+        super::rd_to_historical_date(n, reform)
+    }
+    #[inline(never)]
+    pub const fn historical_date_to_rd((y, m, d): (i32, u8, u8), reform: i32) -> i32 {
+        super::historical_date_to_rd((y, m, d), reform)
+    }
+    #[inline(never)]
+    pub const fn historical_days_in_month(y: i32, m: u8, reform: i32) -> u8 {
+        super::historical_days_in_month(y, m, reform)
+    }
+    #[inline(never)]
+    pub const fn rd_to_jdn(n: i32) -> i32 {
+        super::rd_to_jdn(n)
+    }
+    #[inline(never)]
+    pub const fn jdn_to_rd(jdn: i32) -> i32 {
+        super::jdn_to_rd(jdn)
+    }
+    #[inline(never)]
+    pub const fn secs_to_jd_nanos((secs, nsecs): (i64, u32)) -> (i32, u64) {
+        super::secs_to_jd_nanos((secs, nsecs))
+    }
+    #[inline(never)]
+    pub const fn jd_nanos_to_secs((jdn, nanos): (i32, u64)) -> (i64, u32) {
+        super::jd_nanos_to_secs((jdn, nanos))
+    }
+    #[inline(never)]
+    pub const fn secs_to_jd((secs, nsecs): (i64, u32)) -> f64 {
+        super::secs_to_jd((secs, nsecs))
+    }
+    #[inline(never)]
+    pub const fn jd_to_secs(jd: f64) -> (i64, u32) {
+        super::jd_to_secs(jd)
+    }
+    #[inline(never)]
+    pub const fn millis_to_datetime(millis: i64) -> (i32, u8, u8, u8, u8, u8, u32) {
+        super::millis_to_datetime(millis)
+    }
+    #[inline(never)]
+    pub const fn datetime_to_millis((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i64 {
+        super::datetime_to_millis((y, m, d, hh, mm, ss, nsec))
+    }
+    #[inline(never)]
+    pub const fn micros_to_datetime(micros: i64) -> (i32, u8, u8, u8, u8, u8, u32) {
+        super::micros_to_datetime(micros)
+    }
+    #[inline(never)]
+    pub const fn datetime_to_micros((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i64 {
+        super::datetime_to_micros((y, m, d, hh, mm, ss, nsec))
+    }
+    #[inline(never)]
+    pub const fn nanos_to_datetime(nanos: i128) -> (i32, u8, u8, u8, u8, u8, u32) {
+        super::nanos_to_datetime(nanos)
+    }
+    #[inline(never)]
+    pub const fn datetime_to_nanos((y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32)) -> i128 {
+        super::datetime_to_nanos((y, m, d, hh, mm, ss, nsec))
+    }
+    #[inline(never)]
+    pub const fn utc_secs_to_tai_secs(secs: i64, table: &[(i64, u8)]) -> i64 {
+        super::leapsec::utc_secs_to_tai_secs(secs, table)
+    }
+    #[inline(never)]
+    pub const fn tai_secs_to_utc_secs(tai: i64, table: &[(i64, u8)]) -> i64 {
+        super::leapsec::tai_secs_to_utc_secs(tai, table)
+    }
+    #[inline(never)]
+    pub const fn tai_secs_to_datetime(tai: i64, table: &[(i64, u8)]) -> (i32, u8, u8, u8, u8, u8) {
+        super::leapsec::tai_secs_to_datetime(tai, table)
+    }
+    #[inline(never)]
+    pub const fn dos_to_datetime((date, time): (u16, u16)) -> Result<(i32, u8, u8, u8, u8, u8), super::DateError> {
+        super::dos::dos_to_datetime((date, time))
+    }
+    #[inline(never)]
+    pub const fn datetime_to_dos((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> (u16, u16) {
+        super::dos::datetime_to_dos((y, m, d, hh, mm, ss))
+    }
+    #[inline(never)]
+    pub const fn ds3231_to_datetime(regs: [u8; 7]) -> Result<(i32, u8, u8, u8, u8, u8), super::DateError> {
+        super::rtc::ds3231_to_datetime(regs)
+    }
+    #[inline(never)]
+    pub const fn datetime_to_ds3231((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> [u8; 7] {
+        super::rtc::datetime_to_ds3231((y, m, d, hh, mm, ss))
+    }
+    #[inline(never)]
+    pub const fn format_rfc3339(
+        (y, m, d, hh, mm, ss, nsec): (i32, u8, u8, u8, u8, u8, u32),
+        digits: u8,
+        offset: Option<i32>,
+        out: &mut [u8; super::iso8601::RFC3339_MAX_LEN],
+    ) -> Option<usize> {
+        super::iso8601::format_rfc3339((y, m, d, hh, mm, ss, nsec), digits, offset, out)
+    }
+    #[allow(clippy::type_complexity)]
+    #[inline(never)]
+    pub const fn parse_rfc3339(s: &[u8]) -> Result<((i32, u8, u8, u8, u8, u8, u32), i32), super::ParseError> {
+        super::iso8601::parse_rfc3339(s)
+    }
+    #[allow(clippy::type_complexity)]
+    #[inline(never)]
+    pub const fn parse_datetime(s: &[u8]) -> Result<((i32, u8, u8, u8, u8, u8, u32), Option<i32>), super::ParseError> {
+        super::iso8601::parse_datetime(s)
+    }
+    #[inline(never)]
+    pub const fn format_http_date(secs: i64, out: &mut [u8; super::http::HTTP_DATE_LEN]) {
+        super::http::format_http_date(secs, out)
+    }
+    #[inline(never)]
+    pub const fn parse_http_date(s: &[u8], now: i64) -> Result<i64, super::ParseError> {
+        super::http::parse_http_date(s, now)
+    }
+    #[inline(never)]
+    pub const fn format_rfc2822(secs: i64, offset: i32, out: &mut [u8; super::rfc2822::RFC2822_LEN]) {
+        super::rfc2822::format_rfc2822(secs, offset, out)
+    }
+    #[inline(never)]
+    pub const fn parse_rfc2822(s: &[u8]) -> Result<(i64, i32), super::ParseError> {
+        super::rfc2822::parse_rfc2822(s)
+    }
+    #[inline(never)]
+    pub const fn format_utc_time(secs: i64, out: &mut [u8; super::asn1::UTC_TIME_LEN]) {
+        super::asn1::format_utc_time(secs, out)
+    }
+    #[inline(never)]
+    pub const fn parse_utc_time(s: &[u8]) -> Result<i64, super::ParseError> {
+        super::asn1::parse_utc_time(s)
+    }
+    #[inline(never)]
+    pub const fn format_generalized_time(t: (i64, u32), out: &mut [u8; super::asn1::GENERALIZED_TIME_MAX_LEN]) -> usize {
+        super::asn1::format_generalized_time(t, out)
+    }
+    #[inline(never)]
+    pub const fn parse_generalized_time(s: &[u8]) -> Result<(i64, u32), super::ParseError> {
+        super::asn1::parse_generalized_time(s)
+    }
+    #[inline(never)]
+    pub fn strftime_to_slice(fmt: &str, dt: (i32, u8, u8, u8, u8, u8), out: &mut [u8]) -> Option<usize> {
+        super::strftime::strftime_to_slice(fmt, dt, out)
+    }
+    #[inline(never)]
+    pub fn strptime(s: &[u8], fmt: &str) -> Result<(i32, u8, u8, u8, u8, u8), super::ParseError> {
+        super::strftime::strptime(s, fmt)
+    }
+    #[inline(never)]
+    pub const fn gregorian_easter(y: i32) -> (i32, u8, u8) {
+        super::easter::gregorian_easter(y)
+    }
+    #[inline(never)]
+    pub const fn gregorian_easter_rd(y: i32) -> i32 {
+        super::easter::gregorian_easter_rd(y)
+    }
+    #[inline(never)]
+    pub const fn orthodox_easter(y: i32) -> (i32, u8, u8) {
+        super::easter::orthodox_easter(y)
+    }
+    #[inline(never)]
+    pub const fn orthodox_easter_rd(y: i32) -> i32 {
+        super::easter::orthodox_easter_rd(y)
+    }
+    #[inline(never)]
+    pub const fn is_holiday(rd: i32, rules: &[super::holiday::HolidayRule]) -> bool {
+        super::holiday::is_holiday(rd, rules)
+    }
+    #[inline(never)]
+    pub const fn holidays_in_year(y: i32, rules: &[super::holiday::HolidayRule], out: &mut [(i32, &'static str, bool)]) -> Option<usize> {
+        super::holiday::holidays_in_year(y, rules, out)
+    }
+    #[inline(never)]
+    pub const fn ntp_to_secs(ts: u64, era: i32) -> (i64, u32) {
+        super::epoch::ntp_to_secs(ts, era)
+    }
+    #[inline(never)]
+    pub const fn secs_to_ntp((secs, nsecs): (i64, u32)) -> (i32, u64) {
+        super::epoch::secs_to_ntp((secs, nsecs))
+    }
+    #[inline(never)]
+    pub const fn filetime_to_secs(ft: u64) -> (i64, u32) {
+        super::epoch::filetime_to_secs(ft)
+    }
+    #[inline(never)]
+    pub const fn secs_to_filetime((secs, nsecs): (i64, u32)) -> u64 {
+        super::epoch::secs_to_filetime((secs, nsecs))
+    }
+    #[inline(never)]
+    pub const fn serial_1900_to_date(serial: i32) -> (i32, u8, u8) {
+        super::excel::serial_1900_to_date(serial)
+    }
+    #[inline(never)]
+    pub const fn serial_1900_to_datetime(serial: f64) -> (i32, u8, u8, u8, u8, u8, u32) {
+        super::excel::serial_1900_to_datetime(serial)
+    }
+    #[inline(never)]
+    pub const fn rd_to_gps_week(rd: i32) -> i32 {
+        super::gnss::rd_to_gps_week(rd)
+    }
+    #[inline(never)]
+    pub const fn resolve_week(week: u16, bits: u8, reference: i32) -> i32 {
+        super::gnss::resolve_week(week, bits, reference)
+    }
+    #[inline(never)]
+    pub const fn checked_rd_to_date(n: i32) -> Option<(i32, u8, u8)> {
+        super::checked_rd_to_date(n)
+    }
+    #[inline(never)]
+    pub const fn checked_date_to_rd((y, m, d): (i32, u8, u8)) -> Option<i32> {
+        super::checked_date_to_rd((y, m, d))
+    }
+    #[inline(never)]
+    pub const fn checked_secs_to_datetime(secs: i64) -> Option<(i32, u8, u8, u8, u8, u8)> {
+        super::checked_secs_to_datetime(secs)
+    }
+    #[inline(never)]
+    pub const fn checked_datetime_to_secs((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> Option<i64> {
+        super::checked_datetime_to_secs((y, m, d, hh, mm, ss))
+    }
     #[cfg(feature = "std")]
     #[inline(never)]
     pub fn systemtime_to_secs(st: SystemTime) -> Option<(i64, u32)> {
@@ -0,0 +1,315 @@
+//! Internet Message Format dates
+//!
+//! [RFC 5322](https://www.rfc-editor.org/rfc/rfc5322#section-3.3), which
+//! obsoletes RFC 2822 and RFC 822, defines the format of dates in the `Date`
+//! header of email messages, such as `Fri, 21 Nov 1997 09:55:06 -0600`.
+//!
+//! Messages are written in the current format, but the parser also accepts
+//! the obsolete syntax that must be understood when reading old messages:
+//! comments and folding whitespace between all elements, two and three digit
+//! years, and alphabetic time zones. Following RFC 5322, two digit years from
+//! `00` to `49` are in the 2000s and the rest, as well as three digit years,
+//! are counted from 1900. The North American time zones such as `EST` and
+//! `PDT` are converted to their offsets, while military single letter zones
+//! and unknown zones are taken as `-0000`, as their meaning is ambiguous.
+
+use crate::iso8601::{digits2, NOT_DIGITS};
+use crate::util::{find_abbr, matches, write_2, write_abbr};
+use crate::{date_to_weekday, datetime_to_secs, secs_to_datetime, validate_datetime, ParseError, MONTH_ABBRS, WEEKDAY_ABBRS};
+
+/// Length of a date written by [format_rfc2822]
+pub const RFC2822_LEN: usize = 31;
+
+/// Obsolete North American time zones and their offsets in hours
+const ZONES: [(&str, i32); 10] = [
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+    ("GMT", 0),
+    ("UT", 0),
+];
+
+/// Format seconds and offset as an Internet Message Format date
+///
+/// Given seconds counting from Unix epoch (January 1st, 1970) and an offset
+/// from UTC in seconds writes the local date and time, such as
+/// `Fri, 21 Nov 1997 09:55:06 -0600`, into the output buffer.
+///
+/// # Panics
+///
+/// The local date and time must be between years `1900` and `9999`, as
+/// earlier years are not allowed by RFC 5322. Offset must be a whole number of
+/// minutes between `-86399` and `86399` seconds, the same range
+/// [parse_rfc2822] returns. Bounds are checked using `debug_assert` only, so
+/// that the checks are not present in release builds, similar to integer
+/// overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rfc2822::{format_rfc2822, RFC2822_LEN};
+///
+/// let mut out = [0u8; RFC2822_LEN];
+/// format_rfc2822(880127706, -21600, &mut out);
+/// assert_eq!(&out, b"Fri, 21 Nov 1997 09:55:06 -0600");
+/// format_rfc2822(0, 0, &mut out);
+/// assert_eq!(&out, b"Thu, 01 Jan 1970 00:00:00 +0000");
+/// ```
+///
+/// # Algorithm
+///
+/// Uses [secs_to_datetime] and [date_to_weekday], with digits written
+/// directly at fixed positions.
+#[inline]
+pub const fn format_rfc2822(secs: i64, offset: i32, out: &mut [u8; RFC2822_LEN]) {
+    debug_assert!(
+        offset > -86400 && offset < 86400 && offset % 60 == 0,
+        "given offset is out of range"
+    );
+    let local = secs + offset as i64;
+    debug_assert!(local >= -2208988800 && local <= 253402300799, "given seconds is out of range");
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(local);
+    let y = y as u32;
+    write_abbr(out, 0, WEEKDAY_ABBRS[date_to_weekday((y as i32, m, d)) as usize - 1]);
+    out[3] = b',';
+    out[4] = b' ';
+    write_2(out, 5, d);
+    out[7] = b' ';
+    write_abbr(out, 8, MONTH_ABBRS[m as usize - 1]);
+    out[11] = b' ';
+    write_2(out, 12, (y / 100) as u8);
+    write_2(out, 14, (y % 100) as u8);
+    out[16] = b' ';
+    write_2(out, 17, hh);
+    out[19] = b':';
+    write_2(out, 20, mm);
+    out[22] = b':';
+    write_2(out, 23, ss);
+    out[25] = b' ';
+    out[26] = if offset < 0 { b'-' } else { b'+' };
+    let offset = offset.unsigned_abs() / 60;
+    write_2(out, 27, (offset / 60) as u8);
+    write_2(out, 29, (offset % 60) as u8);
+}
+
+/// Skip comments and folding whitespace, returning the position after them
+///
+/// Comments are enclosed in parentheses, may be nested and may contain quoted
+/// pairs escaped with a backslash.
+#[inline]
+const fn skip_cfws(s: &[u8], mut i: usize) -> Result<usize, ParseError> {
+    let mut depth = 0;
+    while i < s.len() {
+        match s[i] {
+            b'(' => depth += 1,
+            b')' if depth > 0 => depth -= 1,
+            b'\\' if depth > 0 => i += 1,
+            b' ' | b'\t' | b'\r' | b'\n' => {}
+            _ if depth > 0 => {}
+            _ => break,
+        }
+        i += 1;
+    }
+    if depth > 0 {
+        return Err(ParseError::InvalidFormat);
+    }
+    Ok(i)
+}
+
+/// Parse up to the given number of digits at the given position, returning
+/// the value and the position after the digits
+#[inline]
+const fn parse_number(s: &[u8], start: usize, max: usize) -> (u32, usize) {
+    let mut i = start;
+    let mut v = 0;
+    while i < s.len() && i - start < max && s[i].is_ascii_digit() {
+        v = v * 10 + (s[i] - b'0') as u32;
+        i += 1;
+    }
+    (v, i)
+}
+
+/// Parse a time zone at the given position, returning the offset in seconds
+/// and the position after the zone
+#[inline]
+const fn parse_zone(s: &[u8], i: usize) -> Result<(i32, usize), ParseError> {
+    if i >= s.len() {
+        return Err(ParseError::InvalidFormat);
+    }
+    if s[i] == b'+' || s[i] == b'-' {
+        if i + 5 > s.len() {
+            return Err(ParseError::InvalidFormat);
+        }
+        let hh = digits2(s, i + 1);
+        let mm = digits2(s, i + 3);
+        if (hh | mm) & NOT_DIGITS != 0 || (i + 5 < s.len() && s[i + 5].is_ascii_digit()) {
+            return Err(ParseError::InvalidFormat);
+        }
+        if hh > 23 || mm > 59 {
+            return Err(ParseError::OutOfRange);
+        }
+        let offset = (hh * 3600 + mm * 60) as i32;
+        return Ok((if s[i] == b'-' { -offset } else { offset }, i + 5));
+    }
+    let mut j = i;
+    while j < s.len() && s[j].is_ascii_alphabetic() {
+        j += 1;
+    }
+    if j == i {
+        return Err(ParseError::InvalidFormat);
+    }
+    let mut k = 0;
+    while k < ZONES.len() {
+        let (name, hours) = ZONES[k];
+        if j - i == name.len() && matches(s, i, name) {
+            return Ok((hours * 3600, j));
+        }
+        k += 1;
+    }
+    Ok((0, j))
+}
+
+/// Parse an Internet Message Format date
+///
+/// Given a date as found in the `Date` header of an email message returns the
+/// time as seconds counting from Unix epoch (January 1st, 1970) and the offset
+/// from UTC in seconds. Both the current and the obsolete syntax of RFC 5322
+/// are accepted, and names are matched ignoring case. Seconds may be omitted.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input is not a date,
+/// [ParseError::OutOfRange] if the date, time or offset is not valid, and
+/// [ParseError::WeekdayMismatch] if the day of week is given but does not
+/// match the date. Leap seconds are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rfc2822::parse_rfc2822;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 09:55:06 -0600"), Ok((880127706, -21600)));
+/// assert_eq!(parse_rfc2822(b"21 Nov 97 09:55:06 GMT"), Ok((880106106, 0)));
+/// assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 10:55 EDT"), Ok((880124100, -14400)));
+/// assert_eq!(parse_rfc2822(b"Fri,(Friday)21 Nov\r\n 1997 09:55:06 -0600 (CST)"), Ok((880127706, -21600)));
+/// assert_eq!(parse_rfc2822(b"Thu, 21 Nov 1997 09:55:06 -0600"), Err(ParseError::WeekdayMismatch));
+/// assert_eq!(parse_rfc2822(b"Fri, 31 Nov 1997 09:55:06 -0600"), Err(ParseError::OutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Recursive descent over the grammar, with the result validated by
+/// [validate_datetime] before converting with [datetime_to_secs].
+#[inline]
+pub const fn parse_rfc2822(s: &[u8]) -> Result<(i64, i32), ParseError> {
+    let mut i = match skip_cfws(s, 0) {
+        Ok(i) => i,
+        Err(e) => return Err(e),
+    };
+    let wd = if i < s.len() && s[i].is_ascii_alphabetic() {
+        let wd = match find_abbr(s, i, &WEEKDAY_ABBRS) {
+            Some(wd) => wd,
+            None => return Err(ParseError::InvalidFormat),
+        };
+        i = match skip_cfws(s, i + 3) {
+            Ok(i) => i,
+            Err(e) => return Err(e),
+        };
+        if i >= s.len() || s[i] != b',' {
+            return Err(ParseError::InvalidFormat);
+        }
+        i = match skip_cfws(s, i + 1) {
+            Ok(i) => i,
+            Err(e) => return Err(e),
+        };
+        Some(wd as u8 + 1)
+    } else {
+        None
+    };
+    let (d, j) = parse_number(s, i, 2);
+    if j == i {
+        return Err(ParseError::InvalidFormat);
+    }
+    i = match skip_cfws(s, j) {
+        Ok(i) => i,
+        Err(e) => return Err(e),
+    };
+    let m = match find_abbr(s, i, &MONTH_ABBRS) {
+        Some(m) => m as u8 + 1,
+        None => return Err(ParseError::InvalidFormat),
+    };
+    i = match skip_cfws(s, i + 3) {
+        Ok(i) => i,
+        Err(e) => return Err(e),
+    };
+    let (y, j) = parse_number(s, i, 9);
+    let y = match j - i {
+        2 if y < 50 => y as i32 + 2000,
+        2 | 3 => y as i32 + 1900,
+        4..=8 => y as i32,
+        9 => return Err(ParseError::OutOfRange),
+        _ => return Err(ParseError::InvalidFormat),
+    };
+    if j < s.len() && s[j].is_ascii_digit() {
+        return Err(ParseError::OutOfRange);
+    }
+    let mut fields = [0u32; 3];
+    let mut n = 0;
+    i = j;
+    while n < 3 {
+        i = match skip_cfws(s, i) {
+            Ok(i) => i,
+            Err(e) => return Err(e),
+        };
+        if n > 0 {
+            if i >= s.len() || s[i] != b':' {
+                break;
+            }
+            i = match skip_cfws(s, i + 1) {
+                Ok(i) => i,
+                Err(e) => return Err(e),
+            };
+        }
+        let (v, j) = parse_number(s, i, 2);
+        if j != i + 2 {
+            return Err(ParseError::InvalidFormat);
+        }
+        fields[n] = v;
+        n += 1;
+        i = j;
+    }
+    if n < 2 {
+        return Err(ParseError::InvalidFormat);
+    }
+    i = match skip_cfws(s, i) {
+        Ok(i) => i,
+        Err(e) => return Err(e),
+    };
+    let (offset, j) = match parse_zone(s, i) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    i = match skip_cfws(s, j) {
+        Ok(i) => i,
+        Err(e) => return Err(e),
+    };
+    if i != s.len() {
+        return Err(ParseError::InvalidFormat);
+    }
+    let dt = (y, m, d as u8, fields[0] as u8, fields[1] as u8, fields[2] as u8);
+    if validate_datetime(dt).is_err() {
+        return Err(ParseError::OutOfRange);
+    }
+    if let Some(wd) = wd {
+        if wd != date_to_weekday((y, m, d as u8)) {
+            return Err(ParseError::WeekdayMismatch);
+        }
+    }
+    Ok((datetime_to_secs(dt) - offset as i64, offset))
+}
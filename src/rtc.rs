@@ -0,0 +1,462 @@
+//! Real-time clock registers
+//!
+//! Hardware real-time clocks keep the current date and time in a block of
+//! registers, usually one register per field with the value in [binary-coded
+//! decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal). Only the last
+//! two digits of the year are stored, with a century bit or register to extend
+//! the range, and the day of week is kept in a separate register that the clock
+//! simply increments at midnight.
+//!
+//! This module converts between the register blocks of common clock chips and
+//! `(year, month, day, hours, minutes, seconds)` tuples, which can be further
+//! converted with [datetime_to_secs](crate::datetime_to_secs). When decoding,
+//! register values that are not valid BCD or are out of range for the field
+//! return a [DateError] for that field, and the day of week register is
+//! ignored. When encoding, the day of week register is filled in using
+//! [date_to_weekday].
+//!
+//! Supported clocks:
+//!
+//! - Maxim DS1307, DS3231 and compatibles: [ds3231_to_datetime] and
+//!   [datetime_to_ds3231]
+//! - NXP PCF8563 and compatibles: [pcf8563_to_datetime] and
+//!   [datetime_to_pcf8563]
+//! - Motorola MC146818, the PC CMOS clock, and compatibles:
+//!   [mc146818_to_datetime] and [datetime_to_mc146818]
+
+use crate::{consts, date_to_weekday, days_in_month, validate_datetime, DateError};
+
+/// Value returned by the internal decoding functions for invalid register
+/// contents, out of range for every field
+const INVALID: u8 = 0xff;
+
+/// Base year for clocks with a century bit
+const CENTURY_BIT_BASE_YEAR: i32 = 2000;
+
+/// Bit in the MC146818 status register B for binary instead of BCD values
+pub const MC146818_BINARY: u8 = 0x04;
+
+/// Bit in the MC146818 status register B for 24 hour instead of 12 hour mode
+pub const MC146818_24H: u8 = 0x02;
+
+/// Convert binary-coded decimal to binary
+///
+/// Given a byte with two decimal digits in its upper and lower nibbles returns
+/// the binary value, or `None` if either nibble is not a decimal digit.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::bcd_to_bin;
+///
+/// assert_eq!(bcd_to_bin(0x00), Some(0));
+/// assert_eq!(bcd_to_bin(0x59), Some(59));
+/// assert_eq!(bcd_to_bin(0x5a), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple shifts and multiplication.
+#[inline]
+pub const fn bcd_to_bin(bcd: u8) -> Option<u8> {
+    let hi = bcd >> 4;
+    let lo = bcd & 0xf;
+    if hi > 9 || lo > 9 {
+        None
+    } else {
+        Some(hi * 10 + lo)
+    }
+}
+
+/// Convert binary to binary-coded decimal
+///
+/// Given a value between `0` and `99` returns a byte with the two decimal digits
+/// in its upper and lower nibbles.
+///
+/// # Panics
+///
+/// Value must be between `0` and `99`. Bounds are checked using `debug_assert`
+/// only, so that the checks are not present in release builds, similar to
+/// integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::bin_to_bcd;
+///
+/// assert_eq!(bin_to_bcd(0), 0x00);
+/// assert_eq!(bin_to_bcd(59), 0x59);
+/// ```
+///
+/// # Algorithm
+///
+/// Simple division and shifts.
+#[inline]
+pub const fn bin_to_bcd(bin: u8) -> u8 {
+    debug_assert!(bin <= 99, "given value is out of range");
+    ((bin / 10) << 4) | (bin % 10)
+}
+
+/// Decode a register value in BCD or binary, returning [INVALID] for bad BCD
+#[inline]
+const fn decode(v: u8, binary: bool) -> u8 {
+    if binary {
+        return v;
+    }
+    match bcd_to_bin(v) {
+        Some(v) => v,
+        None => INVALID,
+    }
+}
+
+/// Encode a register value in BCD or binary
+#[inline]
+const fn encode(v: u8, binary: bool) -> u8 {
+    if binary {
+        v
+    } else {
+        bin_to_bcd(v)
+    }
+}
+
+/// Decode an hours register in 12 hour mode, with the given PM flag bit
+#[inline]
+const fn decode_12h(v: u8, pm_bit: u8, binary: bool) -> u8 {
+    let h = decode(v & !pm_bit, binary);
+    if h < 1 || h > 12 {
+        return INVALID;
+    }
+    let h = if h == 12 { 0 } else { h };
+    if v & pm_bit != 0 {
+        h + 12
+    } else {
+        h
+    }
+}
+
+/// Encode hours to an hours register in 12 hour mode, with the given PM flag
+/// bit
+#[inline]
+const fn encode_12h(hh: u8, pm_bit: u8, binary: bool) -> u8 {
+    let h = match hh % 12 {
+        0 => 12,
+        h => h,
+    };
+    let h = encode(h, binary);
+    if hh >= 12 {
+        h | pm_bit
+    } else {
+        h
+    }
+}
+
+/// Validate decoded fields, reporting an invalid year separately
+#[inline]
+const fn check(y: i32, yy: u8, m: u8, d: u8, hh: u8, mm: u8, ss: u8) -> Result<(i32, u8, u8, u8, u8, u8), DateError> {
+    if yy > 99 {
+        return Err(DateError::YearOutOfRange);
+    }
+    let y = y + yy as i32;
+    match validate_datetime((y, m, d, hh, mm, ss)) {
+        Ok(()) => Ok((y, m, d, hh, mm, ss)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Debug assertions shared by the encoding functions
+#[inline]
+const fn debug_check((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) {
+    debug_assert!(m >= consts::MONTH_MIN && m <= consts::MONTH_MAX, "given month is out of range");
+    debug_assert!(d >= consts::DAY_MIN && d <= days_in_month(y, m), "given day is out of range");
+    debug_assert!(hh >= consts::HOUR_MIN && hh <= consts::HOUR_MAX, "given hour is out of range");
+    debug_assert!(mm >= consts::MINUTE_MIN && mm <= consts::MINUTE_MAX, "given minute is out of range");
+    debug_assert!(ss >= consts::SECOND_MIN && ss <= consts::SECOND_MAX, "given second is out of range");
+}
+
+/// Convert DS3231 registers to year, month, day, hours, minutes and seconds
+///
+/// Given the seven timekeeping registers `0x00` to `0x06` of a DS3231, DS1307
+/// or compatible clock returns a `(year, month, day, hours, minutes, seconds)`
+/// tuple. The century bit in the month register adds 100 years to the base
+/// year of 2000. Both 12 and 24 hour modes are supported. Bit 7 of the seconds
+/// register, which halts the oscillator on the DS1307, is ignored.
+///
+/// # Errors
+///
+/// Returns [DateError] describing the first invalid field if any register
+/// value is not valid BCD, or the resulting date and time is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::ds3231_to_datetime;
+/// use datealgo::DateError;
+///
+/// assert_eq!(ds3231_to_datetime([0x38, 0x24, 0x09, 0x07, 0x07, 0x05, 0x23]), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(ds3231_to_datetime([0x38, 0x24, 0x69, 0x07, 0x07, 0x05, 0x23]), Ok((2023, 5, 7, 21, 24, 38)));
+/// assert_eq!(ds3231_to_datetime([0x00, 0x00, 0x00, 0x01, 0x01, 0x81, 0x00]), Ok((2100, 1, 1, 0, 0, 0)));
+/// assert_eq!(ds3231_to_datetime([0x00, 0x00, 0x00, 0x01, 0x30, 0x02, 0x23]), Err(DateError::DayOutOfRange { max: 28 }));
+/// ```
+///
+/// # Algorithm
+///
+/// BCD decoding of each register followed by [validate_datetime].
+#[inline]
+pub const fn ds3231_to_datetime(regs: [u8; 7]) -> Result<(i32, u8, u8, u8, u8, u8), DateError> {
+    let ss = decode(regs[0] & 0x7f, false);
+    let mm = decode(regs[1], false);
+    let hh = if regs[2] & 0x40 != 0 {
+        decode_12h(regs[2] & 0x3f, 0x20, false)
+    } else {
+        decode(regs[2], false)
+    };
+    let d = decode(regs[4], false);
+    let m = decode(regs[5] & 0x7f, false);
+    let century = if regs[5] & 0x80 != 0 { 100 } else { 0 };
+    let yy = decode(regs[6], false);
+    check(CENTURY_BIT_BASE_YEAR + century, yy, m, d, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to DS3231 registers
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns the
+/// seven timekeeping registers `0x00` to `0x06` of a DS3231, DS1307 or
+/// compatible clock, in 24 hour mode. The day of week register is set to `1`
+/// for Monday through `7` for Sunday.
+///
+/// # Panics
+///
+/// Year must be between `2000` and `2199`. Month must be between `1` and `12`.
+/// Day must be between `1` and the number of days in the month in question.
+/// Hours must be between `0` and `23`. Minutes must be between `0` and `59`.
+/// Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::datetime_to_ds3231;
+///
+/// assert_eq!(datetime_to_ds3231((2023, 5, 7, 9, 24, 38)), [0x38, 0x24, 0x09, 0x07, 0x07, 0x05, 0x23]);
+/// assert_eq!(datetime_to_ds3231((2100, 1, 1, 0, 0, 0)), [0x00, 0x00, 0x00, 0x05, 0x01, 0x81, 0x00]);
+/// ```
+///
+/// # Algorithm
+///
+/// BCD encoding of each field, with [date_to_weekday] for the day of week.
+#[inline]
+pub const fn datetime_to_ds3231((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> [u8; 7] {
+    debug_assert!(
+        y >= CENTURY_BIT_BASE_YEAR && y < CENTURY_BIT_BASE_YEAR + 200,
+        "given year is out of range"
+    );
+    debug_check((y, m, d, hh, mm, ss));
+    let yy = (y - CENTURY_BIT_BASE_YEAR) as u8;
+    let century = if yy >= 100 { 0x80 } else { 0 };
+    [
+        bin_to_bcd(ss),
+        bin_to_bcd(mm),
+        bin_to_bcd(hh),
+        date_to_weekday((y, m, d)),
+        bin_to_bcd(d),
+        bin_to_bcd(m) | century,
+        bin_to_bcd(yy % 100),
+    ]
+}
+
+/// Convert PCF8563 registers to year, month, day, hours, minutes and seconds
+///
+/// Given the seven time and date registers `0x02` to `0x08` of a PCF8563 or
+/// compatible clock returns a `(year, month, day, hours, minutes, seconds)`
+/// tuple. The century bit in the month register adds 100 years to the base
+/// year of 2000. Some systems use the opposite polarity for the century bit,
+/// in which case bit 7 of the month register should be flipped before
+/// decoding. The voltage low flag in bit 7 of the seconds register is ignored,
+/// and should be checked separately to find out if the clock has lost time.
+///
+/// # Errors
+///
+/// Returns [DateError] describing the first invalid field if any register
+/// value is not valid BCD, or the resulting date and time is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::pcf8563_to_datetime;
+/// use datealgo::DateError;
+///
+/// assert_eq!(pcf8563_to_datetime([0x38, 0x24, 0x09, 0x07, 0x00, 0x05, 0x23]), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(pcf8563_to_datetime([0xb8, 0x24, 0x09, 0x07, 0x00, 0x05, 0x23]), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(pcf8563_to_datetime([0x38, 0x24, 0x09, 0x07, 0x00, 0x13, 0x23]), Err(DateError::MonthOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// BCD decoding of each register followed by [validate_datetime].
+#[inline]
+pub const fn pcf8563_to_datetime(regs: [u8; 7]) -> Result<(i32, u8, u8, u8, u8, u8), DateError> {
+    let ss = decode(regs[0] & 0x7f, false);
+    let mm = decode(regs[1] & 0x7f, false);
+    let hh = decode(regs[2] & 0x3f, false);
+    let d = decode(regs[3] & 0x3f, false);
+    let m = decode(regs[5] & 0x1f, false);
+    let century = if regs[5] & 0x80 != 0 { 100 } else { 0 };
+    let yy = decode(regs[6], false);
+    check(CENTURY_BIT_BASE_YEAR + century, yy, m, d, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to PCF8563 registers
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple returns the
+/// seven time and date registers `0x02` to `0x08` of a PCF8563 or compatible
+/// clock. The day of week register is set to `0` for Sunday through `6` for
+/// Saturday, and the voltage low flag is cleared.
+///
+/// # Panics
+///
+/// Year must be between `2000` and `2199`. Month must be between `1` and `12`.
+/// Day must be between `1` and the number of days in the month in question.
+/// Hours must be between `0` and `23`. Minutes must be between `0` and `59`.
+/// Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::datetime_to_pcf8563;
+///
+/// assert_eq!(datetime_to_pcf8563((2023, 5, 7, 9, 24, 38)), [0x38, 0x24, 0x09, 0x07, 0x00, 0x05, 0x23]);
+/// assert_eq!(datetime_to_pcf8563((2100, 1, 1, 0, 0, 0)), [0x00, 0x00, 0x00, 0x01, 0x05, 0x81, 0x00]);
+/// ```
+///
+/// # Algorithm
+///
+/// BCD encoding of each field, with [date_to_weekday] for the day of week.
+#[inline]
+pub const fn datetime_to_pcf8563((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8)) -> [u8; 7] {
+    debug_assert!(
+        y >= CENTURY_BIT_BASE_YEAR && y < CENTURY_BIT_BASE_YEAR + 200,
+        "given year is out of range"
+    );
+    debug_check((y, m, d, hh, mm, ss));
+    let yy = (y - CENTURY_BIT_BASE_YEAR) as u8;
+    let century = if yy >= 100 { 0x80 } else { 0 };
+    [
+        bin_to_bcd(ss),
+        bin_to_bcd(mm),
+        bin_to_bcd(hh),
+        bin_to_bcd(d),
+        date_to_weekday((y, m, d)) % 7,
+        bin_to_bcd(m) | century,
+        bin_to_bcd(yy % 100),
+    ]
+}
+
+/// Convert MC146818 registers to year, month, day, hours, minutes and seconds
+///
+/// Given the seven time and date registers of an MC146818 or compatible clock,
+/// read from the addresses `0x00`, `0x02`, `0x04`, `0x06`, `0x07`, `0x08` and
+/// `0x09` in that order, the century register and the status register B
+/// returns a `(year, month, day, hours, minutes, seconds)` tuple. The century
+/// register is commonly at address `0x32` on PCs, and should be given as
+/// `0x20` in BCD or `20` in binary if the clock does not have one. The format
+/// of the values is selected by the [MC146818_BINARY] and [MC146818_24H] bits
+/// of the status register B.
+///
+/// # Errors
+///
+/// Returns [DateError] describing the first invalid field if any register
+/// value is not valid BCD, or the resulting date and time is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::{mc146818_to_datetime, MC146818_24H, MC146818_BINARY};
+/// use datealgo::DateError;
+///
+/// assert_eq!(mc146818_to_datetime([0x38, 0x24, 0x09, 0x01, 0x07, 0x05, 0x23], 0x20, MC146818_24H), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(mc146818_to_datetime([0x38, 0x24, 0x89, 0x01, 0x07, 0x05, 0x23], 0x20, 0), Ok((2023, 5, 7, 21, 24, 38)));
+/// assert_eq!(mc146818_to_datetime([38, 24, 9, 1, 7, 5, 23], 20, MC146818_BINARY | MC146818_24H), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(mc146818_to_datetime([0x38, 0x24, 0x09, 0x01, 0x07, 0x05, 0x23], 0x20, 0), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(mc146818_to_datetime([0x38, 0x24, 0x00, 0x01, 0x07, 0x05, 0x23], 0x20, 0), Err(DateError::HourOutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// BCD or binary decoding of each register followed by [validate_datetime].
+#[inline]
+pub const fn mc146818_to_datetime(regs: [u8; 7], century: u8, status_b: u8) -> Result<(i32, u8, u8, u8, u8, u8), DateError> {
+    let binary = status_b & MC146818_BINARY != 0;
+    let ss = decode(regs[0], binary);
+    let mm = decode(regs[1], binary);
+    let hh = if status_b & MC146818_24H != 0 {
+        decode(regs[2], binary)
+    } else {
+        decode_12h(regs[2], 0x80, binary)
+    };
+    let d = decode(regs[4], binary);
+    let m = decode(regs[5], binary);
+    let yy = decode(regs[6], binary);
+    let cc = decode(century, binary);
+    if cc > 99 {
+        return Err(DateError::YearOutOfRange);
+    }
+    check(cc as i32 * 100, yy, m, d, hh, mm, ss)
+}
+
+/// Convert year, month, day, hours, minutes and seconds to MC146818 registers
+///
+/// Given a `(year, month, day, hours, minutes, seconds)` tuple and the status
+/// register B returns the seven time and date registers of an MC146818 or
+/// compatible clock, to be written to the addresses `0x00`, `0x02`, `0x04`,
+/// `0x06`, `0x07`, `0x08` and `0x09` in that order, and the century register.
+/// The format of the values is selected by the [MC146818_BINARY] and
+/// [MC146818_24H] bits of the status register B. The day of week register is
+/// set to `1` for Sunday through `7` for Saturday.
+///
+/// # Panics
+///
+/// Year must be between `0` and `9999`. Month must be between `1` and `12`.
+/// Day must be between `1` and the number of days in the month in question.
+/// Hours must be between `0` and `23`. Minutes must be between `0` and `59`.
+/// Seconds must be between `0` and `59`. Bounds are checked using
+/// `debug_assert` only, so that the checks are not present in release builds,
+/// similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::rtc::{datetime_to_mc146818, MC146818_24H, MC146818_BINARY};
+///
+/// assert_eq!(datetime_to_mc146818((2023, 5, 7, 9, 24, 38), MC146818_24H), ([0x38, 0x24, 0x09, 0x01, 0x07, 0x05, 0x23], 0x20));
+/// assert_eq!(datetime_to_mc146818((2023, 5, 7, 21, 24, 38), 0), ([0x38, 0x24, 0x89, 0x01, 0x07, 0x05, 0x23], 0x20));
+/// assert_eq!(datetime_to_mc146818((2023, 5, 7, 9, 24, 38), MC146818_BINARY | MC146818_24H), ([38, 24, 9, 1, 7, 5, 23], 20));
+/// ```
+///
+/// # Algorithm
+///
+/// BCD or binary encoding of each field, with [date_to_weekday] for the day
+/// of week.
+#[inline]
+pub const fn datetime_to_mc146818((y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8), status_b: u8) -> ([u8; 7], u8) {
+    debug_assert!(y >= 0 && y <= 9999, "given year is out of range");
+    debug_check((y, m, d, hh, mm, ss));
+    let binary = status_b & MC146818_BINARY != 0;
+    let hours = if status_b & MC146818_24H != 0 {
+        encode(hh, binary)
+    } else {
+        encode_12h(hh, 0x80, binary)
+    };
+    let regs = [
+        encode(ss, binary),
+        encode(mm, binary),
+        hours,
+        date_to_weekday((y, m, d)) % 7 + 1,
+        encode(d, binary),
+        encode(m, binary),
+        encode((y % 100) as u8, binary),
+    ];
+    (regs, encode((y / 100) as u8, binary))
+}
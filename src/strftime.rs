@@ -0,0 +1,474 @@
+//! `strftime` and `strptime` style formatting and parsing
+//!
+//! Dates and times are formatted and parsed according to a format string in
+//! the style of the C library functions `strftime()` and `strptime()`, without
+//! allocation and independent of locale. The supported conversion
+//! specifications are:
+//!
+//! | Spec | Meaning                                              | Example      |
+//! |------|------------------------------------------------------|--------------|
+//! | `%Y` | Year, at least four digits, with a sign above `9999` | `2023`       |
+//! | `%C` | Year divided by 100, rounded down                    | `20`         |
+//! | `%y` | Year modulo 100                                      | `23`         |
+//! | `%m` | Month                                                | `05`         |
+//! | `%b` | Abbreviated month name                               | `May`        |
+//! | `%B` | Full month name                                      | `May`        |
+//! | `%d` | Day of month                                         | `07`         |
+//! | `%e` | Day of month, padded with a space                    | ` 7`         |
+//! | `%j` | Day of year                                          | `127`        |
+//! | `%a` | Abbreviated weekday name                             | `Sun`        |
+//! | `%A` | Full weekday name                                    | `Sunday`     |
+//! | `%u` | Day of week, from Monday as `1` to Sunday as `7`     | `7`          |
+//! | `%w` | Day of week, from Sunday as `0` to Saturday as `6`   | `0`          |
+//! | `%G` | ISO 8601 week-based year, written like `%Y`          | `2023`       |
+//! | `%V` | ISO 8601 week number                                 | `18`         |
+//! | `%U` | Week number, starting from the first Sunday          | `19`         |
+//! | `%W` | Week number, starting from the first Monday          | `18`         |
+//! | `%H` | Hours                                                | `09`         |
+//! | `%M` | Minutes                                              | `24`         |
+//! | `%S` | Seconds                                              | `38`         |
+//! | `%s` | Seconds counting from Unix epoch                     | `1683451478` |
+//! | `%%` | A literal `%`                                        | `%`          |
+//!
+//! Numeric fields are padded with zeros by default, except `%e` which is
+//! padded with a space. Like in GNU `strftime()`, the padding can be changed
+//! by putting a flag between `%` and the conversion: `-` for no padding, `_`
+//! for spaces and `0` for zeros.
+
+use core::fmt::{self, Write};
+
+use crate::util::{matches, SliceWriter};
+use crate::{
+    date_to_ordinal, date_to_rd, datetime_to_secs, days_in_year, isoweekdate_to_rd, ordinal_to_rd, rd_to_date, rd_to_isoweekdate,
+    rd_to_weekday, secs_to_datetime, validate_date, validate_datetime, validate_isoweekdate, ParseError, MONTH_ABBRS, MONTH_NAMES,
+    RD_SECONDS_MAX, RD_SECONDS_MIN, WEEKDAY_ABBRS, WEEKDAY_NAMES,
+};
+
+/// Padding of a numeric field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pad {
+    None,
+    Zero,
+    Space,
+}
+
+/// Write a number padded to the given width
+#[inline]
+fn write_num<W: Write>(out: &mut W, v: i64, width: usize, pad: Pad) -> fmt::Result {
+    match pad {
+        Pad::None => write!(out, "{}", v),
+        Pad::Zero if v < 0 => write!(out, "-{:01$}", v.unsigned_abs(), width),
+        Pad::Zero => write!(out, "{:01$}", v, width),
+        Pad::Space => write!(out, "{:1$}", v, width),
+    }
+}
+
+/// Format date and time according to a format string
+///
+/// Given a format string and date and time as year, month, day, hours,
+/// minutes and seconds writes the formatted date and time into the given
+/// writer. See the [module documentation](self) for the supported conversion
+/// specifications.
+///
+/// # Errors
+///
+/// Returns an error if the format string contains an unsupported conversion
+/// specification, or if the writer returns an error.
+///
+/// # Panics
+///
+/// Date and time must be valid, as checked by [validate_datetime]. This is
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::strftime::strftime;
+///
+/// let mut s = String::new();
+/// strftime("%a, %e %B %Y %H:%M:%S", (2023, 5, 7, 9, 24, 38), &mut s).unwrap();
+/// assert_eq!(s, "Sun,  7 May 2023 09:24:38");
+/// s.clear();
+/// strftime("%G-W%V-%u, day %-j", (2023, 5, 7, 9, 24, 38), &mut s).unwrap();
+/// assert_eq!(s, "2023-W18-7, day 127");
+/// ```
+///
+/// # Algorithm
+///
+/// Formatting with [core::fmt], with the day of week from [rd_to_weekday], the
+/// ISO week date from [rd_to_isoweekdate] and the day of year from
+/// [date_to_ordinal].
+#[inline]
+pub fn strftime<W: Write>(fmt: &str, (y, m, d, hh, mm, ss): (i32, u8, u8, u8, u8, u8), out: &mut W) -> fmt::Result {
+    debug_assert!(validate_datetime((y, m, d, hh, mm, ss)).is_ok(), "given date and time is not valid");
+    let rd = date_to_rd((y, m, d));
+    let wd = rd_to_weekday(rd) as i64;
+    let yday = date_to_ordinal((y, m, d)).1 as i64 - 1;
+    let f = fmt.as_bytes();
+    let (mut start, mut i) = (0, 0);
+    while i < f.len() {
+        if f[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        out.write_str(&fmt[start..i])?;
+        let pad = match f.get(i + 1) {
+            Some(b'-') => Some(Pad::None),
+            Some(b'_') => Some(Pad::Space),
+            Some(b'0') => Some(Pad::Zero),
+            _ => None,
+        };
+        i += 1 + pad.is_some() as usize;
+        let spec = *f.get(i).ok_or(fmt::Error)?;
+        i += 1;
+        start = i;
+        let (v, width, default) = match spec {
+            b'Y' => (y as i64, 4, Pad::Zero),
+            b'C' => (y.div_euclid(100) as i64, 2, Pad::Zero),
+            b'y' => (y.rem_euclid(100) as i64, 2, Pad::Zero),
+            b'm' => (m as i64, 2, Pad::Zero),
+            b'd' => (d as i64, 2, Pad::Zero),
+            b'e' => (d as i64, 2, Pad::Space),
+            b'j' => (yday + 1, 3, Pad::Zero),
+            b'u' => (wd, 1, Pad::Zero),
+            b'w' => (wd % 7, 1, Pad::Zero),
+            b'G' => (rd_to_isoweekdate(rd).0 as i64, 4, Pad::Zero),
+            b'V' => (rd_to_isoweekdate(rd).1 as i64, 2, Pad::Zero),
+            b'U' => ((yday + 7 - wd % 7) / 7, 2, Pad::Zero),
+            b'W' => ((yday + 8 - wd) / 7, 2, Pad::Zero),
+            b'H' => (hh as i64, 2, Pad::Zero),
+            b'M' => (mm as i64, 2, Pad::Zero),
+            b'S' => (ss as i64, 2, Pad::Zero),
+            b's' => (datetime_to_secs((y, m, d, hh, mm, ss)), 1, Pad::None),
+            b'a' | b'A' | b'b' | b'B' | b'%' => {
+                out.write_str(match spec {
+                    b'a' => WEEKDAY_ABBRS[wd as usize - 1],
+                    b'A' => WEEKDAY_NAMES[wd as usize - 1],
+                    b'b' => MONTH_ABBRS[m as usize - 1],
+                    b'B' => MONTH_NAMES[m as usize - 1],
+                    _ => "%",
+                })?;
+                continue;
+            }
+            _ => return Err(fmt::Error),
+        };
+        if (spec == b'Y' || spec == b'G') && v > 9999 {
+            out.write_char('+')?;
+        }
+        write_num(out, v, width, pad.unwrap_or(default))?;
+    }
+    out.write_str(&fmt[start..])
+}
+
+/// Format date and time according to a format string into a byte buffer
+///
+/// Given a format string and date and time as year, month, day, hours,
+/// minutes and seconds writes the formatted date and time into the output
+/// buffer and returns the number of bytes written, like [strftime].
+///
+/// Returns `None` if the format string contains an unsupported conversion
+/// specification or the output buffer is too small.
+///
+/// # Panics
+///
+/// Date and time must be valid, as checked by [validate_datetime]. This is
+/// checked using `debug_assert` only, so that the checks are not present in
+/// release builds, similar to integer overflow checks.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::strftime::strftime_to_slice;
+///
+/// let mut out = [0u8; 32];
+/// let len = strftime_to_slice("%d/%m/%y %H:%M", (2023, 5, 7, 9, 24, 38), &mut out);
+/// assert_eq!(&out[..len.unwrap()], b"07/05/23 09:24");
+/// assert_eq!(strftime_to_slice("%A %A %A %A %A %A", (2023, 5, 7, 9, 24, 38), &mut out), None);
+/// assert_eq!(strftime_to_slice("%Q", (2023, 5, 7, 9, 24, 38), &mut out), None);
+/// ```
+///
+/// # Algorithm
+///
+/// Uses [strftime] with a writer that fails when the buffer is full.
+#[inline]
+pub fn strftime_to_slice(fmt: &str, dt: (i32, u8, u8, u8, u8, u8), out: &mut [u8]) -> Option<usize> {
+    let mut w = SliceWriter { buf: out, len: 0 };
+    strftime(fmt, dt, &mut w).ok()?;
+    Some(w.len)
+}
+
+/// Fields collected while parsing, before they are resolved into a date
+#[derive(Debug, Default)]
+struct Fields {
+    year: Option<i32>,
+    century: Option<i32>,
+    yy: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    yday: Option<i32>,
+    iso_year: Option<i32>,
+    iso_week: Option<u8>,
+    week_sun: Option<i32>,
+    week_mon: Option<i32>,
+    weekday: Option<u8>,
+    hh: u8,
+    mm: u8,
+    ss: u8,
+    secs: Option<i64>,
+}
+
+/// Rata die of January 1st and the number of days in the given year
+#[inline]
+fn year_start(year: Option<i32>) -> Result<(i32, i32), ParseError> {
+    let y = year.ok_or(ParseError::InvalidFormat)?;
+    validate_date((y, 1, 1)).map_err(|_| ParseError::OutOfRange)?;
+    Ok((ordinal_to_rd((y, 1)), days_in_year(y) as i32))
+}
+
+impl Fields {
+    /// Resolve the collected fields into a date and time
+    #[inline]
+    fn resolve(&self) -> Result<(i32, u8, u8, u8, u8, u8), ParseError> {
+        if let Some(secs) = self.secs {
+            if secs < RD_SECONDS_MIN || secs > RD_SECONDS_MAX {
+                return Err(ParseError::OutOfRange);
+            }
+            return Ok(secs_to_datetime(secs));
+        }
+        let year = match (self.year, self.century, self.yy) {
+            (Some(y), _, _) => Some(y),
+            (None, Some(c), yy) => Some(c * 100 + yy.unwrap_or(0)),
+            (None, None, Some(yy)) if yy < 69 => Some(2000 + yy),
+            (None, None, Some(yy)) => Some(1900 + yy),
+            (None, None, None) => None,
+        };
+        let rd = if self.month.is_some() || self.day.is_some() {
+            let date = (
+                year.ok_or(ParseError::InvalidFormat)?,
+                self.month.unwrap_or(1),
+                self.day.unwrap_or(1),
+            );
+            validate_date(date).map_err(|_| ParseError::OutOfRange)?;
+            self.check_weekday(date_to_rd(date))?
+        } else if let Some(yday) = self.yday {
+            let (jan1, days) = year_start(year)?;
+            if yday < 1 || yday > days {
+                return Err(ParseError::OutOfRange);
+            }
+            self.check_weekday(jan1 + yday - 1)?
+        } else if let Some(w) = self.iso_week {
+            let date = (
+                self.iso_year.or(year).ok_or(ParseError::InvalidFormat)?,
+                w,
+                self.weekday.unwrap_or(1),
+            );
+            validate_isoweekdate(date).map_err(|_| ParseError::OutOfRange)?;
+            isoweekdate_to_rd(date)
+        } else if let Some(w) = self.week_mon {
+            let (jan1, days) = year_start(year)?;
+            let first = (8 - rd_to_weekday(jan1) as i32) % 7;
+            in_year(jan1 + first + (w - 1) * 7 + self.weekday.unwrap_or(1) as i32 - 1, jan1, days)?
+        } else if let Some(w) = self.week_sun {
+            let (jan1, days) = year_start(year)?;
+            let first = (7 - rd_to_weekday(jan1) as i32 % 7) % 7;
+            in_year(jan1 + first + (w - 1) * 7 + self.weekday.unwrap_or(7) as i32 % 7, jan1, days)?
+        } else {
+            year_start(year)?.0
+        };
+        let (y, m, d) = rd_to_date(rd);
+        let dt = (y, m, d, self.hh, self.mm, self.ss);
+        validate_datetime(dt).map_err(|_| ParseError::OutOfRange)?;
+        Ok(dt)
+    }
+
+    /// Check that the parsed day of week, if any, matches the date
+    #[inline]
+    fn check_weekday(&self, rd: i32) -> Result<i32, ParseError> {
+        match self.weekday {
+            Some(wd) if wd != rd_to_weekday(rd) => Err(ParseError::WeekdayMismatch),
+            _ => Ok(rd),
+        }
+    }
+}
+
+/// Check that a rata die computed from a week number is within the year
+#[inline]
+fn in_year(rd: i32, jan1: i32, days: i32) -> Result<i32, ParseError> {
+    if rd < jan1 || rd >= jan1 + days {
+        return Err(ParseError::OutOfRange);
+    }
+    Ok(rd)
+}
+
+/// Parse up to the given number of digits at the given position, after
+/// optional spaces, returning the value and the position after the digits
+#[inline]
+fn parse_number(s: &[u8], mut i: usize, max: usize) -> Result<(i64, usize), ParseError> {
+    while i < s.len() && s[i] == b' ' {
+        i += 1;
+    }
+    let start = i;
+    let mut v = 0;
+    while i < s.len() && i - start < max && s[i].is_ascii_digit() {
+        v = v * 10 + (s[i] - b'0') as i64;
+        i += 1;
+    }
+    if i == start {
+        return Err(ParseError::InvalidFormat);
+    }
+    Ok((v, i))
+}
+
+/// Parse a signed number at the given position, after optional spaces, with
+/// up to the given number of digits if it has a sign and four if it does not
+#[inline]
+fn parse_signed(s: &[u8], mut i: usize, max: usize) -> Result<(i64, usize), ParseError> {
+    while i < s.len() && s[i] == b' ' {
+        i += 1;
+    }
+    match s.get(i) {
+        Some(b'-') => parse_number(s, i + 1, max).map(|(v, i)| (-v, i)),
+        Some(b'+') => parse_number(s, i + 1, max),
+        _ => parse_number(s, i, 4),
+    }
+}
+
+/// Parse a full or abbreviated name at the given position, returning its
+/// index and the position after the name
+#[inline]
+fn parse_name(s: &[u8], i: usize, names: &[&str], abbrs: &[&str]) -> Result<(i64, usize), ParseError> {
+    for (k, (name, abbr)) in names.iter().zip(abbrs).enumerate() {
+        if matches(s, i, name) {
+            return Ok((k as i64, i + name.len()));
+        }
+        if matches(s, i, abbr) {
+            return Ok((k as i64, i + abbr.len()));
+        }
+    }
+    Err(ParseError::InvalidFormat)
+}
+
+/// Parse date and time according to a format string
+///
+/// Given a date and time and the format string it was written with returns
+/// year, month, day, hours, minutes and seconds. See the
+/// [module documentation](self) for the supported conversion specifications.
+/// Padding flags are accepted but ignored, as numeric fields are parsed with
+/// or without padding. Whitespace in the format string matches any amount of
+/// whitespace in the input, and names are matched ignoring case, in either
+/// full or abbreviated form.
+///
+/// The date is resolved from the parsed fields in the following order:
+///
+/// - `%s` gives the date and time, and other fields are ignored
+/// - Month or day, with the other defaulting to `1`
+/// - Day of year from `%j`
+/// - ISO 8601 week date from `%V` and the day of week, with the year from
+///   `%G`, defaulting to Monday
+/// - Week number from `%W` or `%U` and the day of week, defaulting to the
+///   first day of the week
+/// - January 1st of the year
+///
+/// The year is taken from `%Y`, or `%C` and `%y` together, or `%y` alone, in
+/// which case `69` to `99` are in the 1900s and `00` to `68` in the 2000s as
+/// specified by POSIX. Years with more than four digits must have a sign, as
+/// written by [strftime], so that `%Y%m%d` can be parsed. Missing hours, minutes and seconds are `0`.
+///
+/// # Errors
+///
+/// Returns [ParseError::InvalidFormat] if the input does not match the format
+/// string, the format string contains an unsupported conversion specification
+/// or there is no year, [ParseError::OutOfRange] if the date or time is not
+/// valid, and [ParseError::WeekdayMismatch] if a day of week is given but does
+/// not match a date given as month and day or day of year.
+///
+/// # Examples
+///
+/// ```
+/// use datealgo::strftime::strptime;
+/// use datealgo::ParseError;
+///
+/// assert_eq!(strptime(b"Sun,  7 May 2023 09:24:38", "%a, %e %B %Y %H:%M:%S"), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(strptime(b"2023-W18-7", "%G-W%V-%u"), Ok((2023, 5, 7, 0, 0, 0)));
+/// assert_eq!(strptime(b"2023.127", "%Y.%j"), Ok((2023, 5, 7, 0, 0, 0)));
+/// assert_eq!(strptime(b"20230507", "%Y%m%d"), Ok((2023, 5, 7, 0, 0, 0)));
+/// assert_eq!(strptime(b"1683451478", "%s"), Ok((2023, 5, 7, 9, 24, 38)));
+/// assert_eq!(strptime(b"Mon, 7 May 2023", "%a, %d %b %Y"), Err(ParseError::WeekdayMismatch));
+/// assert_eq!(strptime(b"31/04/2023", "%d/%m/%Y"), Err(ParseError::OutOfRange));
+/// ```
+///
+/// # Algorithm
+///
+/// Fields are parsed in one pass over the format string and resolved into a
+/// rata die using [date_to_rd] or [isoweekdate_to_rd], with the result
+/// validated by [validate_datetime].
+#[inline]
+pub fn strptime(s: &[u8], fmt: &str) -> Result<(i32, u8, u8, u8, u8, u8), ParseError> {
+    let f = fmt.as_bytes();
+    let mut fields = Fields::default();
+    let (mut i, mut j) = (0, 0);
+    while j < f.len() {
+        let c = f[j];
+        j += 1;
+        if c.is_ascii_whitespace() {
+            while i < s.len() && s[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            continue;
+        }
+        if c != b'%' {
+            if s.get(i) != Some(&c) {
+                return Err(ParseError::InvalidFormat);
+            }
+            i += 1;
+            continue;
+        }
+        if let Some(b'-' | b'_' | b'0') = f.get(j) {
+            j += 1;
+        }
+        let spec = *f.get(j).ok_or(ParseError::InvalidFormat)?;
+        j += 1;
+        let (v, next) = match spec {
+            b'Y' | b'G' => parse_signed(s, i, 7)?,
+            b'C' | b'y' | b'm' | b'd' | b'e' | b'V' | b'U' | b'W' | b'H' | b'M' | b'S' => parse_number(s, i, 2)?,
+            b'j' => parse_number(s, i, 3)?,
+            b'u' | b'w' => parse_number(s, i, 1)?,
+            b's' if s.get(i) == Some(&b'-') => parse_number(s, i + 1, 18).map(|(v, i)| (-v, i))?,
+            b's' => parse_number(s, i, 18)?,
+            b'a' | b'A' => parse_name(s, i, &WEEKDAY_NAMES, &WEEKDAY_ABBRS)?,
+            b'b' | b'B' => parse_name(s, i, &MONTH_NAMES, &MONTH_ABBRS)?,
+            b'%' if s.get(i) == Some(&b'%') => (0, i + 1),
+            _ => return Err(ParseError::InvalidFormat),
+        };
+        i = next;
+        match spec {
+            b'Y' => fields.year = Some(v as i32),
+            b'C' => fields.century = Some(v as i32),
+            b'y' => fields.yy = Some(v as i32),
+            b'm' => fields.month = Some(v as u8),
+            b'b' | b'B' => fields.month = Some(v as u8 + 1),
+            b'd' | b'e' => fields.day = Some(v as u8),
+            b'j' => fields.yday = Some(v as i32),
+            b'G' => fields.iso_year = Some(v as i32),
+            b'V' => fields.iso_week = Some(v as u8),
+            b'U' | b'W' if v > 53 => return Err(ParseError::OutOfRange),
+            b'U' => fields.week_sun = Some(v as i32),
+            b'W' => fields.week_mon = Some(v as i32),
+            b'u' if v < 1 || v > 7 => return Err(ParseError::OutOfRange),
+            b'w' if v > 6 => return Err(ParseError::OutOfRange),
+            b'u' => fields.weekday = Some(v as u8),
+            b'w' => fields.weekday = Some(if v == 0 { 7 } else { v as u8 }),
+            b'a' | b'A' => fields.weekday = Some(v as u8 + 1),
+            b'H' => fields.hh = v as u8,
+            b'M' => fields.mm = v as u8,
+            b'S' => fields.ss = v as u8,
+            b's' => fields.secs = Some(v),
+            _ => {}
+        }
+    }
+    if i != s.len() {
+        return Err(ParseError::InvalidFormat);
+    }
+    fields.resolve()
+}
@@ -0,0 +1,68 @@
+//! Helpers shared by the formatting and parsing modules
+
+use core::fmt;
+
+/// Writer appending to a byte slice, failing when the slice is full
+pub(crate) struct SliceWriter<'a> {
+    pub(crate) buf: &'a mut [u8],
+    pub(crate) len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Write two decimal digits at the given position
+#[inline]
+pub(crate) const fn write_2(out: &mut [u8], i: usize, v: u8) {
+    out[i] = b'0' + v / 10;
+    out[i + 1] = b'0' + v % 10;
+}
+
+/// Write a three letter name at the given position
+#[inline]
+pub(crate) const fn write_abbr(out: &mut [u8], i: usize, name: &str) {
+    let name = name.as_bytes();
+    out[i] = name[0];
+    out[i + 1] = name[1];
+    out[i + 2] = name[2];
+}
+
+/// Check if the input at the given position matches a word, ignoring case
+#[inline]
+pub(crate) const fn matches(s: &[u8], i: usize, word: &str) -> bool {
+    let word = word.as_bytes();
+    if i + word.len() > s.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < word.len() {
+        if !s[i + j].eq_ignore_ascii_case(&word[j]) {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Find the three letter name at the given position, returning its index
+#[inline]
+pub(crate) const fn find_abbr(s: &[u8], i: usize, names: &[&str]) -> Option<usize> {
+    let mut k = 0;
+    while k < names.len() {
+        if matches(s, i, names[k]) {
+            return Some(k);
+        }
+        k += 1;
+    }
+    None
+}
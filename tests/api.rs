@@ -9,6 +9,8 @@ fn test_consts() {
     assert_eq!(RD_MAX, 536824295);
     assert_eq!(RD_SECONDS_MIN, -46387741132800);
     assert_eq!(RD_SECONDS_MAX, 46381619174399);
+    assert_eq!(JULIAN_RD_MIN, -536894842);
+    assert_eq!(JULIAN_RD_MAX, 536824008);
 }
 
 #[test]
@@ -80,6 +82,116 @@ fn test_date_to_weekday() {
     assert_eq!(date_to_weekday((YEAR_MAX, 12, 31)), 4);
 }
 
+#[test]
+fn test_weekday_on_or_after() {
+    let rd = date_to_rd((2023, 5, 12));
+    assert_eq!(weekday_on_or_after(rd, consts::MONDAY), rd + 3);
+    assert_eq!(weekday_on_or_after(rd, consts::THURSDAY), rd + 6);
+    assert_eq!(weekday_on_or_after(rd, consts::FRIDAY), rd);
+    assert_eq!(weekday_on_or_after(rd, consts::SATURDAY), rd + 1);
+    assert_eq!(weekday_on_or_after(rd, consts::SUNDAY), rd + 2);
+    assert_eq!(weekday_on_or_after(RD_MIN, rd_to_weekday(RD_MIN)), RD_MIN);
+    assert_eq!(weekday_on_or_after(RD_MAX, rd_to_weekday(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_weekday_on_or_before() {
+    let rd = date_to_rd((2023, 5, 12));
+    assert_eq!(weekday_on_or_before(rd, consts::MONDAY), rd - 4);
+    assert_eq!(weekday_on_or_before(rd, consts::THURSDAY), rd - 1);
+    assert_eq!(weekday_on_or_before(rd, consts::FRIDAY), rd);
+    assert_eq!(weekday_on_or_before(rd, consts::SATURDAY), rd - 6);
+    assert_eq!(weekday_on_or_before(rd, consts::SUNDAY), rd - 5);
+    assert_eq!(weekday_on_or_before(RD_MIN, rd_to_weekday(RD_MIN)), RD_MIN);
+    assert_eq!(weekday_on_or_before(RD_MAX, rd_to_weekday(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_next_weekday() {
+    let rd = date_to_rd((2023, 5, 12));
+    assert_eq!(next_weekday(rd, consts::MONDAY), rd + 3);
+    assert_eq!(next_weekday(rd, consts::THURSDAY), rd + 6);
+    assert_eq!(next_weekday(rd, consts::FRIDAY), rd + 7);
+    assert_eq!(next_weekday(rd, consts::SATURDAY), rd + 1);
+    assert_eq!(next_weekday(rd, consts::SUNDAY), rd + 2);
+    assert_eq!(next_weekday(RD_MIN, rd_to_weekday(RD_MIN)), RD_MIN + 7);
+    assert_eq!(next_weekday(RD_MAX - 1, rd_to_weekday(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_prev_weekday() {
+    let rd = date_to_rd((2023, 5, 12));
+    assert_eq!(prev_weekday(rd, consts::MONDAY), rd - 4);
+    assert_eq!(prev_weekday(rd, consts::THURSDAY), rd - 1);
+    assert_eq!(prev_weekday(rd, consts::FRIDAY), rd - 7);
+    assert_eq!(prev_weekday(rd, consts::SATURDAY), rd - 6);
+    assert_eq!(prev_weekday(rd, consts::SUNDAY), rd - 5);
+    assert_eq!(prev_weekday(RD_MIN + 1, rd_to_weekday(RD_MIN)), RD_MIN);
+    assert_eq!(prev_weekday(RD_MAX, rd_to_weekday(RD_MAX)), RD_MAX - 7);
+}
+
+#[test]
+fn test_nth_weekday_of_month() {
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::MONDAY, 1), Some(1));
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::SUNDAY, 1), Some(7));
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::FRIDAY, 3), Some(19));
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::MONDAY, 5), Some(29));
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::WEDNESDAY, 5), Some(31));
+    assert_eq!(nth_weekday_of_month(2023, 5, consts::THURSDAY, 5), None);
+    assert_eq!(nth_weekday_of_month(2023, 11, consts::THURSDAY, 4), Some(23));
+    assert_eq!(nth_weekday_of_month(2021, 2, consts::MONDAY, 4), Some(22));
+    assert_eq!(nth_weekday_of_month(2021, 2, consts::MONDAY, 5), None);
+    assert_eq!(nth_weekday_of_month(2020, 2, consts::SATURDAY, 5), Some(29));
+    assert_eq!(nth_weekday_of_month(2020, 2, consts::SUNDAY, 5), None);
+    assert_eq!(nth_weekday_of_month(-1, 12, consts::FRIDAY, 5), Some(31));
+    assert_eq!(nth_weekday_of_month(YEAR_MIN, 1, rd_to_weekday(RD_MIN), 1), Some(1));
+    assert_eq!(nth_weekday_of_month(YEAR_MAX, 12, rd_to_weekday(RD_MAX), 5), Some(31));
+}
+
+#[test]
+fn test_last_weekday_of_month() {
+    assert_eq!(last_weekday_of_month(2023, 5, consts::MONDAY), 29);
+    assert_eq!(last_weekday_of_month(2023, 5, consts::WEDNESDAY), 31);
+    assert_eq!(last_weekday_of_month(2023, 5, consts::THURSDAY), 25);
+    assert_eq!(last_weekday_of_month(2023, 4, consts::SUNDAY), 30);
+    assert_eq!(last_weekday_of_month(2023, 4, consts::MONDAY), 24);
+    assert_eq!(last_weekday_of_month(2021, 2, consts::SUNDAY), 28);
+    assert_eq!(last_weekday_of_month(2021, 2, consts::MONDAY), 22);
+    assert_eq!(last_weekday_of_month(2020, 2, consts::SATURDAY), 29);
+    assert_eq!(last_weekday_of_month(2020, 2, consts::SUNDAY), 23);
+    assert_eq!(last_weekday_of_month(YEAR_MIN, 1, rd_to_weekday(RD_MIN)), 29);
+    assert_eq!(last_weekday_of_month(YEAR_MAX, 12, rd_to_weekday(RD_MAX)), 31);
+}
+
+#[test]
+fn test_weekday_edges() {
+    // every weekday from every day of two 400 year cycles at each end of the range
+    for rd in (RD_MIN..RD_MIN + 292194).chain(RD_MAX - 292193..=RD_MAX) {
+        let w = rd_to_weekday(rd);
+        for wd in consts::WEEKDAY_MIN..=consts::WEEKDAY_MAX {
+            if rd <= RD_MAX - 7 {
+                let n = next_weekday(rd, wd);
+                assert!(n > rd && n <= rd + 7 && rd_to_weekday(n) == wd);
+                assert_eq!(weekday_on_or_after(rd, wd), if w == wd { rd } else { n });
+            }
+            if rd >= RD_MIN + 7 {
+                let p = prev_weekday(rd, wd);
+                assert!(p < rd && p >= rd - 7 && rd_to_weekday(p) == wd);
+                assert_eq!(weekday_on_or_before(rd, wd), if w == wd { rd } else { p });
+            }
+        }
+        let (y, m, d) = rd_to_date(rd);
+        let n = (d - 1) / 7 + 1;
+        assert_eq!(nth_weekday_of_month(y, m, w, n), Some(d));
+        if d + 7 > days_in_month(y, m) {
+            assert_eq!(last_weekday_of_month(y, m, w), d);
+            if n < 5 {
+                assert_eq!(nth_weekday_of_month(y, m, w, n + 1), None);
+            }
+        }
+    }
+}
+
 #[test]
 fn test_next_date() {
     assert_eq!(next_date((2021, 1, 1)), (2021, 1, 2));
@@ -114,6 +226,172 @@ fn test_prev_date() {
     assert_eq!(prev_date((YEAR_MIN, 1, 2)), (YEAR_MIN, 1, 1));
 }
 
+#[test]
+fn test_add_months() {
+    use DayOverflow::*;
+    assert_eq!(add_months((2021, 1, 15), 0, Reject), Some((2021, 1, 15)));
+    assert_eq!(add_months((2021, 1, 15), 1, Reject), Some((2021, 2, 15)));
+    assert_eq!(add_months((2021, 1, 15), 11, Reject), Some((2021, 12, 15)));
+    assert_eq!(add_months((2021, 1, 15), 12, Reject), Some((2022, 1, 15)));
+    assert_eq!(add_months((2021, 1, 15), -1, Reject), Some((2020, 12, 15)));
+    assert_eq!(add_months((2021, 1, 15), -12, Reject), Some((2020, 1, 15)));
+    assert_eq!(add_months((2021, 1, 15), -13, Reject), Some((2019, 12, 15)));
+    assert_eq!(add_months((1, 3, 1), -3, Reject), Some((0, 12, 1)));
+    assert_eq!(add_months((0, 1, 1), -1, Reject), Some((-1, 12, 1)));
+    assert_eq!(add_months((-1, 12, 1), 1, Reject), Some((0, 1, 1)));
+    assert_eq!(add_months((2021, 1, 31), 1, Clamp), Some((2021, 2, 28)));
+    assert_eq!(add_months((2021, 1, 31), 1, RollOver), Some((2021, 3, 3)));
+    assert_eq!(add_months((2021, 1, 31), 1, Reject), None);
+    assert_eq!(add_months((2020, 1, 31), 1, Clamp), Some((2020, 2, 29)));
+    assert_eq!(add_months((2020, 1, 31), 1, RollOver), Some((2020, 3, 2)));
+    assert_eq!(add_months((2020, 1, 30), 1, RollOver), Some((2020, 3, 1)));
+    assert_eq!(add_months((2020, 1, 29), 1, Reject), Some((2020, 2, 29)));
+    assert_eq!(add_months((2021, 3, 31), -1, Clamp), Some((2021, 2, 28)));
+    assert_eq!(add_months((2021, 3, 31), 1, Clamp), Some((2021, 4, 30)));
+    assert_eq!(add_months((2021, 3, 31), 1, RollOver), Some((2021, 5, 1)));
+    assert_eq!(add_months((2021, 5, 31), -1, RollOver), Some((2021, 5, 1)));
+    assert_eq!(add_months((2021, 11, 30), 1, Reject), Some((2021, 12, 30)));
+    assert_eq!(add_months((YEAR_MAX, 11, 30), 1, Reject), Some((YEAR_MAX, 12, 30)));
+    assert_eq!(add_months((YEAR_MAX, 12, 31), 1, Clamp), None);
+    assert_eq!(add_months((YEAR_MIN, 2, 1), -1, Reject), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(add_months((YEAR_MIN, 1, 1), -1, Clamp), None);
+    assert_eq!(
+        add_months((YEAR_MIN, 1, 31), (YEAR_MAX - YEAR_MIN) * 12 + 11, Reject),
+        Some((YEAR_MAX, 12, 31))
+    );
+    assert_eq!(
+        add_months((YEAR_MAX, 12, 31), (YEAR_MIN - YEAR_MAX) * 12 - 11, Reject),
+        Some((YEAR_MIN, 1, 31))
+    );
+    assert_eq!(add_months((2021, 1, 1), i32::MAX, Clamp), None);
+    assert_eq!(add_months((2021, 1, 1), i32::MIN, Clamp), None);
+    assert_eq!(add_months((YEAR_MAX, 12, 31), i32::MIN, Clamp), None);
+}
+
+#[test]
+fn test_add_years() {
+    use DayOverflow::*;
+    assert_eq!(add_years((2021, 1, 15), 0, Reject), Some((2021, 1, 15)));
+    assert_eq!(add_years((2021, 1, 15), 1, Reject), Some((2022, 1, 15)));
+    assert_eq!(add_years((2021, 1, 15), -2022, Reject), Some((-1, 1, 15)));
+    assert_eq!(add_years((2020, 2, 29), 1, Clamp), Some((2021, 2, 28)));
+    assert_eq!(add_years((2020, 2, 29), 1, RollOver), Some((2021, 3, 1)));
+    assert_eq!(add_years((2020, 2, 29), 1, Reject), None);
+    assert_eq!(add_years((2020, 2, 29), 4, Reject), Some((2024, 2, 29)));
+    assert_eq!(add_years((2020, 2, 29), -4, Reject), Some((2016, 2, 29)));
+    assert_eq!(add_years((2000, 2, 29), 100, Clamp), Some((2100, 2, 28)));
+    assert_eq!(add_years((2000, 2, 29), 400, Reject), Some((2400, 2, 29)));
+    assert_eq!(add_years((2000, 2, 29), -2000, Reject), Some((0, 2, 29)));
+    assert_eq!(add_years((2000, 2, 29), -2001, RollOver), Some((-1, 3, 1)));
+    assert_eq!(add_years((2021, 2, 28), -1, Reject), Some((2020, 2, 28)));
+    assert_eq!(add_years((YEAR_MAX - 1, 12, 31), 1, Reject), Some((YEAR_MAX, 12, 31)));
+    assert_eq!(add_years((YEAR_MAX, 1, 1), 1, Clamp), None);
+    assert_eq!(add_years((YEAR_MIN + 1, 1, 1), -1, Reject), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(add_years((YEAR_MIN, 12, 31), -1, Clamp), None);
+    assert_eq!(add_years((YEAR_MIN, 1, 1), YEAR_MAX - YEAR_MIN, Reject), Some((YEAR_MAX, 1, 1)));
+    assert_eq!(add_years((2021, 1, 1), i32::MAX, Clamp), None);
+    assert_eq!(add_years((2021, 1, 1), i32::MIN, Clamp), None);
+}
+
+#[test]
+fn test_months_between() {
+    assert_eq!(months_between((2021, 1, 15), (2021, 1, 15)), 0);
+    assert_eq!(months_between((2021, 1, 15), (2021, 1, 31)), 0);
+    assert_eq!(months_between((2021, 1, 15), (2021, 1, 1)), 0);
+    assert_eq!(months_between((2021, 1, 15), (2021, 2, 14)), 0);
+    assert_eq!(months_between((2021, 1, 15), (2021, 2, 15)), 1);
+    assert_eq!(months_between((2021, 1, 15), (2020, 12, 16)), 0);
+    assert_eq!(months_between((2021, 1, 15), (2020, 12, 15)), -1);
+    assert_eq!(months_between((2021, 1, 31), (2021, 2, 27)), 0);
+    assert_eq!(months_between((2021, 1, 31), (2021, 2, 28)), 1);
+    assert_eq!(months_between((2020, 1, 31), (2020, 2, 28)), 0);
+    assert_eq!(months_between((2020, 1, 31), (2020, 2, 29)), 1);
+    assert_eq!(months_between((2021, 3, 31), (2021, 2, 28)), -1);
+    assert_eq!(months_between((2021, 3, 31), (2021, 4, 30)), 1);
+    assert_eq!(months_between((2021, 3, 30), (2021, 4, 29)), 0);
+    assert_eq!(months_between((2021, 1, 1), (2022, 1, 1)), 12);
+    assert_eq!(months_between((0, 1, 1), (-1, 12, 1)), -1);
+    assert_eq!(
+        months_between((YEAR_MIN, 1, 1), (YEAR_MAX, 12, 31)),
+        (YEAR_MAX - YEAR_MIN) * 12 + 11
+    );
+    assert_eq!(
+        months_between((YEAR_MAX, 12, 31), (YEAR_MIN, 1, 1)),
+        (YEAR_MIN - YEAR_MAX) * 12 - 11
+    );
+}
+
+#[test]
+fn test_whole_years_between() {
+    assert_eq!(whole_years_between((2021, 5, 15), (2021, 5, 15)), 0);
+    assert_eq!(whole_years_between((2021, 5, 15), (2022, 5, 14)), 0);
+    assert_eq!(whole_years_between((2021, 5, 15), (2022, 5, 15)), 1);
+    assert_eq!(whole_years_between((2021, 5, 15), (2022, 4, 30)), 0);
+    assert_eq!(whole_years_between((2021, 5, 15), (2022, 6, 1)), 1);
+    assert_eq!(whole_years_between((2021, 5, 15), (2020, 5, 16)), 0);
+    assert_eq!(whole_years_between((2021, 5, 15), (2020, 5, 15)), -1);
+    assert_eq!(whole_years_between((2020, 2, 29), (2021, 2, 27)), 0);
+    assert_eq!(whole_years_between((2020, 2, 29), (2021, 2, 28)), 1);
+    assert_eq!(whole_years_between((2020, 2, 29), (2024, 2, 28)), 3);
+    assert_eq!(whole_years_between((2020, 2, 29), (2024, 2, 29)), 4);
+    assert_eq!(whole_years_between((2020, 2, 29), (2019, 3, 1)), 0);
+    assert_eq!(whole_years_between((2020, 2, 29), (2019, 2, 28)), -1);
+    assert_eq!(whole_years_between((2020, 2, 29), (2016, 2, 28)), -4);
+    assert_eq!(whole_years_between((2020, 2, 29), (2016, 3, 1)), -3);
+    assert_eq!(whole_years_between((2021, 2, 28), (2024, 2, 29)), 3);
+    assert_eq!(whole_years_between((YEAR_MIN, 1, 1), (YEAR_MAX, 12, 31)), YEAR_MAX - YEAR_MIN);
+    assert_eq!(whole_years_between((YEAR_MAX, 12, 31), (YEAR_MIN, 1, 1)), YEAR_MIN - YEAR_MAX);
+}
+
+#[test]
+fn test_date_diff_ymd() {
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2021, 5, 15)), (0, 0, 0));
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2021, 5, 16)), (0, 0, 1));
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2021, 5, 14)), (0, 0, -1));
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2021, 6, 14)), (0, 0, 30));
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2021, 4, 16)), (0, 0, -29));
+    assert_eq!(date_diff_ymd((2021, 5, 15), (2023, 8, 1)), (2, 2, 17));
+    assert_eq!(date_diff_ymd((2023, 8, 1), (2021, 5, 15)), (-2, -2, -17));
+    assert_eq!(date_diff_ymd((2021, 1, 31), (2021, 2, 28)), (0, 1, 0));
+    assert_eq!(date_diff_ymd((2021, 1, 31), (2021, 3, 1)), (0, 1, 1));
+    assert_eq!(date_diff_ymd((2021, 1, 31), (2021, 3, 31)), (0, 2, 0));
+    assert_eq!(date_diff_ymd((2021, 3, 31), (2021, 2, 28)), (0, -1, 0));
+    assert_eq!(date_diff_ymd((2021, 3, 31), (2021, 2, 27)), (0, -1, -1));
+    assert_eq!(date_diff_ymd((2021, 3, 31), (2021, 1, 31)), (0, -2, 0));
+    assert_eq!(date_diff_ymd((2021, 3, 31), (2021, 1, 1)), (0, -2, -30));
+    assert_eq!(date_diff_ymd((2020, 2, 29), (2021, 2, 28)), (1, 0, 0));
+    assert_eq!(date_diff_ymd((2020, 2, 29), (2021, 3, 1)), (1, 0, 1));
+    assert_eq!(date_diff_ymd((2020, 2, 29), (2021, 2, 27)), (0, 11, 29));
+    assert_eq!(date_diff_ymd((2020, 2, 29), (2024, 2, 29)), (4, 0, 0));
+    assert_eq!(date_diff_ymd((2020, 2, 29), (2019, 2, 28)), (-1, 0, 0));
+    assert_eq!(date_diff_ymd((0, 1, 1), (-1, 12, 31)), (0, 0, -1));
+    assert_eq!(date_diff_ymd((-1, 12, 31), (0, 1, 1)), (0, 0, 1));
+    assert_eq!(date_diff_ymd((YEAR_MIN, 1, 1), (YEAR_MAX, 12, 31)), (YEAR_MAX - YEAR_MIN, 11, 30));
+    assert_eq!(date_diff_ymd((YEAR_MAX, 12, 31), (YEAR_MIN, 1, 1)), (YEAR_MIN - YEAR_MAX, -11, -30));
+}
+
+#[test]
+fn test_date_diff_edges() {
+    // every pair of dates in a leap year and the years around it
+    let start = date_to_rd((2019, 1, 1));
+    let end = date_to_rd((2021, 12, 31));
+    for a in start..=end {
+        let da = rd_to_date(a);
+        for b in start..=end {
+            let db = rd_to_date(b);
+            let n = months_between(da, db);
+            let anchor = add_months(da, n, DayOverflow::Clamp).unwrap();
+            let dir = (b - a).signum();
+            let next = add_months(da, n + dir, DayOverflow::Clamp).unwrap();
+            assert!((date_to_rd(anchor) - b).signum() * dir <= 0);
+            assert!((date_to_rd(next) - b).signum() * dir > 0 || a == b);
+            let (y, m, d) = date_diff_ymd(da, db);
+            assert_eq!((y * 12 + m as i32, date_to_rd(anchor) + d as i32), (n, b));
+            assert_eq!(whole_years_between(da, db), n / 12);
+        }
+    }
+}
+
 #[test]
 fn test_secs_to_dhms() {
     assert_eq!(secs_to_dhms(RD_SECONDS_MIN), (RD_MIN, 0, 0, 0));
@@ -358,6 +636,384 @@ fn test_isoweeks_in_year() {
     assert_eq!(isoweeks_in_year(YEAR_MAX), 53);
 }
 
+#[test]
+fn test_days_in_year() {
+    assert_eq!(days_in_year(-400), 366);
+    assert_eq!(days_in_year(-100), 365);
+    assert_eq!(days_in_year(-4), 366);
+    assert_eq!(days_in_year(-1), 365);
+    assert_eq!(days_in_year(0), 366);
+    assert_eq!(days_in_year(1900), 365);
+    assert_eq!(days_in_year(2000), 366);
+    assert_eq!(days_in_year(2023), 365);
+    assert_eq!(days_in_year(2024), 366);
+    assert_eq!(days_in_year(YEAR_MIN), 365);
+    assert_eq!(days_in_year(YEAR_MAX), 366);
+}
+
+#[test]
+fn test_rd_to_ordinal() {
+    assert_eq!(rd_to_ordinal(-719528), (0, 1));
+    assert_eq!(rd_to_ordinal(date_to_rd((0, 2, 29))), (0, 60));
+    assert_eq!(rd_to_ordinal(date_to_rd((0, 3, 1))), (0, 61));
+    assert_eq!(rd_to_ordinal(date_to_rd((0, 12, 31))), (0, 366));
+    assert_eq!(rd_to_ordinal(date_to_rd((-1, 12, 31))), (-1, 365));
+    assert_eq!(rd_to_ordinal(0), (1970, 1));
+    assert_eq!(rd_to_ordinal(date_to_rd((1900, 3, 1))), (1900, 60));
+    assert_eq!(rd_to_ordinal(date_to_rd((2000, 3, 1))), (2000, 61));
+    assert_eq!(rd_to_ordinal(date_to_rd((2023, 2, 28))), (2023, 59));
+    assert_eq!(rd_to_ordinal(date_to_rd((2023, 5, 12))), (2023, 132));
+    assert_eq!(rd_to_ordinal(RD_MIN), (YEAR_MIN, 1));
+    assert_eq!(rd_to_ordinal(RD_MAX), (YEAR_MAX, 366));
+}
+
+#[test]
+fn test_ordinal_to_rd() {
+    assert_eq!(ordinal_to_rd((0, 1)), -719528);
+    assert_eq!(ordinal_to_rd((0, 60)), date_to_rd((0, 2, 29)));
+    assert_eq!(ordinal_to_rd((0, 366)), date_to_rd((0, 12, 31)));
+    assert_eq!(ordinal_to_rd((-1, 365)), date_to_rd((-1, 12, 31)));
+    assert_eq!(ordinal_to_rd((1970, 1)), 0);
+    assert_eq!(ordinal_to_rd((1900, 60)), date_to_rd((1900, 3, 1)));
+    assert_eq!(ordinal_to_rd((2000, 61)), date_to_rd((2000, 3, 1)));
+    assert_eq!(ordinal_to_rd((2023, 132)), date_to_rd((2023, 5, 12)));
+    assert_eq!(ordinal_to_rd((YEAR_MIN, 1)), RD_MIN);
+    assert_eq!(ordinal_to_rd((YEAR_MAX, 366)), RD_MAX);
+}
+
+#[test]
+fn test_date_to_ordinal() {
+    assert_eq!(date_to_ordinal((0, 1, 1)), (0, 1));
+    assert_eq!(date_to_ordinal((0, 2, 29)), (0, 60));
+    assert_eq!(date_to_ordinal((0, 12, 31)), (0, 366));
+    assert_eq!(date_to_ordinal((1900, 2, 28)), (1900, 59));
+    assert_eq!(date_to_ordinal((1900, 3, 1)), (1900, 60));
+    assert_eq!(date_to_ordinal((1900, 12, 31)), (1900, 365));
+    assert_eq!(date_to_ordinal((2023, 5, 12)), (2023, 132));
+    assert_eq!(date_to_ordinal((YEAR_MIN, 1, 1)), (YEAR_MIN, 1));
+    assert_eq!(date_to_ordinal((YEAR_MAX, 12, 31)), (YEAR_MAX, 366));
+}
+
+#[test]
+fn test_ordinal_to_date() {
+    assert_eq!(ordinal_to_date((0, 1)), (0, 1, 1));
+    assert_eq!(ordinal_to_date((0, 59)), (0, 2, 28));
+    assert_eq!(ordinal_to_date((0, 60)), (0, 2, 29));
+    assert_eq!(ordinal_to_date((0, 366)), (0, 12, 31));
+    assert_eq!(ordinal_to_date((1900, 59)), (1900, 2, 28));
+    assert_eq!(ordinal_to_date((1900, 60)), (1900, 3, 1));
+    assert_eq!(ordinal_to_date((1900, 365)), (1900, 12, 31));
+    assert_eq!(ordinal_to_date((2023, 132)), (2023, 5, 12));
+    assert_eq!(ordinal_to_date((YEAR_MIN, 1)), (YEAR_MIN, 1, 1));
+    assert_eq!(ordinal_to_date((YEAR_MAX, 366)), (YEAR_MAX, 12, 31));
+}
+
+#[test]
+fn test_ordinal_edges() {
+    let cycle = 146097;
+    let ranges = [
+        RD_MIN..RD_MIN + 2 * cycle,
+        date_to_rd((-400, 1, 1))..date_to_rd((2400, 1, 1)),
+        RD_MAX - 2 * cycle..RD_MAX + 1,
+    ];
+    for range in ranges {
+        let mut prev = rd_to_ordinal(range.start - (range.start > RD_MIN) as i32);
+        for rd in range {
+            let (y, m, d) = rd_to_date(rd);
+            let (oy, od) = rd_to_ordinal(rd);
+            assert_eq!(oy, y);
+            if rd > RD_MIN {
+                if od == 1 {
+                    assert_eq!(prev, (y - 1, days_in_year(y - 1)));
+                } else {
+                    assert_eq!(prev, (y, od - 1));
+                }
+            }
+            assert_eq!(date_to_ordinal((y, m, d)), (oy, od));
+            assert_eq!(ordinal_to_rd((oy, od)), rd);
+            assert_eq!(ordinal_to_date((oy, od)), (y, m, d));
+            prev = (oy, od);
+        }
+    }
+}
+
+#[test]
+fn test_julian_date_to_rd() {
+    assert_eq!(julian_date_to_rd((0, 3, 1)), -719470);
+    assert_eq!(julian_date_to_rd((1969, 12, 19)), 0);
+    assert_eq!(julian_date_to_rd((1582, 10, 4)), -141428);
+    assert_eq!(julian_date_to_rd((1582, 10, 5)), date_to_rd((1582, 10, 15)));
+    assert_eq!(julian_date_to_rd((1, 1, 1)), date_to_rd((0, 12, 30)));
+    assert_eq!(julian_date_to_rd((0, 12, 31)), date_to_rd((0, 12, 29)));
+    assert_eq!(julian_date_to_rd((200, 3, 1)), date_to_rd((200, 3, 1)));
+    assert_eq!(julian_date_to_rd((JULIAN_YEAR_MIN, 1, 1)), JULIAN_RD_MIN);
+    assert_eq!(julian_date_to_rd((JULIAN_YEAR_MAX, 12, 31)), JULIAN_RD_MAX);
+}
+
+#[test]
+fn test_rd_to_julian_date() {
+    assert_eq!(rd_to_julian_date(-719470), (0, 3, 1));
+    assert_eq!(rd_to_julian_date(0), (1969, 12, 19));
+    assert_eq!(rd_to_julian_date(-141428), (1582, 10, 4));
+    assert_eq!(rd_to_julian_date(date_to_rd((1582, 10, 15))), (1582, 10, 5));
+    assert_eq!(rd_to_julian_date(date_to_rd((0, 12, 30))), (1, 1, 1));
+    assert_eq!(rd_to_julian_date(date_to_rd((0, 12, 29))), (0, 12, 31));
+    assert_eq!(rd_to_julian_date(date_to_rd((200, 3, 1))), (200, 3, 1));
+    assert_eq!(rd_to_julian_date(JULIAN_RD_MIN), (JULIAN_YEAR_MIN, 1, 1));
+    assert_eq!(rd_to_julian_date(JULIAN_RD_MAX), (JULIAN_YEAR_MAX, 12, 31));
+}
+
+#[test]
+fn test_is_julian_leap_year() {
+    assert!(is_julian_leap_year(0));
+    assert!(!is_julian_leap_year(1));
+    assert!(is_julian_leap_year(4));
+    assert!(is_julian_leap_year(100));
+    assert!(is_julian_leap_year(1900));
+    assert!(!is_julian_leap_year(-1));
+    assert!(is_julian_leap_year(-4));
+    assert!(is_julian_leap_year(-100));
+    assert!(is_julian_leap_year(JULIAN_YEAR_MIN));
+    assert!(!is_julian_leap_year(JULIAN_YEAR_MAX));
+}
+
+#[test]
+fn test_julian_days_in_month() {
+    for m in 1..=12 {
+        if m != 2 {
+            assert_eq!(julian_days_in_month(1900, m), days_in_month(1900, m));
+        }
+    }
+    assert_eq!(julian_days_in_month(1900, 2), 29);
+    assert_eq!(julian_days_in_month(1901, 2), 28);
+    assert_eq!(julian_days_in_month(-100, 2), 29);
+    assert_eq!(julian_days_in_month(-101, 2), 28);
+}
+
+#[test]
+fn test_rd_to_historical_date() {
+    assert_eq!(rd_to_historical_date(reform::GREGORIAN - 1, reform::GREGORIAN), (1582, 10, 4));
+    assert_eq!(rd_to_historical_date(reform::GREGORIAN, reform::GREGORIAN), (1582, 10, 15));
+    assert_eq!(
+        rd_to_historical_date(reform::GREAT_BRITAIN - 1, reform::GREAT_BRITAIN),
+        (1752, 9, 2)
+    );
+    assert_eq!(rd_to_historical_date(reform::GREAT_BRITAIN, reform::GREAT_BRITAIN), (1752, 9, 14));
+    assert_eq!(rd_to_historical_date(reform::RUSSIA - 1, reform::RUSSIA), (1918, 1, 31));
+    assert_eq!(rd_to_historical_date(reform::RUSSIA, reform::RUSSIA), (1918, 2, 14));
+    assert_eq!(rd_to_historical_date(0, reform::GREGORIAN), (1970, 1, 1));
+    assert_eq!(rd_to_historical_date(0, RD_MAX), (1969, 12, 19));
+    assert_eq!(rd_to_historical_date(0, RD_MIN), (1970, 1, 1));
+    assert_eq!(rd_to_historical_date(JULIAN_RD_MIN, reform::GREGORIAN), (JULIAN_YEAR_MIN, 1, 1));
+    assert_eq!(rd_to_historical_date(RD_MAX, reform::GREGORIAN), (YEAR_MAX, 12, 31));
+}
+
+#[test]
+fn test_historical_date_to_rd() {
+    assert_eq!(historical_date_to_rd((1582, 10, 4), reform::GREGORIAN), reform::GREGORIAN - 1);
+    assert_eq!(historical_date_to_rd((1582, 10, 15), reform::GREGORIAN), reform::GREGORIAN);
+    assert_eq!(historical_date_to_rd((1582, 12, 9), reform::FRANCE), reform::FRANCE - 1);
+    assert_eq!(historical_date_to_rd((1610, 8, 22), reform::PRUSSIA), reform::PRUSSIA - 1);
+    assert_eq!(historical_date_to_rd((1700, 2, 18), reform::DENMARK), reform::DENMARK - 1);
+    assert_eq!(
+        historical_date_to_rd((1752, 9, 2), reform::GREAT_BRITAIN),
+        reform::GREAT_BRITAIN - 1
+    );
+    assert_eq!(historical_date_to_rd((1753, 2, 17), reform::SWEDEN), reform::SWEDEN - 1);
+    assert_eq!(historical_date_to_rd((1918, 1, 31), reform::RUSSIA), reform::RUSSIA - 1);
+    assert_eq!(historical_date_to_rd((1923, 2, 15), reform::GREECE), reform::GREECE - 1);
+    assert_eq!(
+        historical_date_to_rd((1700, 2, 29), reform::GREAT_BRITAIN),
+        date_to_rd((1700, 3, 11))
+    );
+    assert_eq!(historical_date_to_rd((1970, 1, 1), reform::GREGORIAN), 0);
+    assert_eq!(historical_date_to_rd((JULIAN_YEAR_MIN, 1, 1), reform::GREGORIAN), JULIAN_RD_MIN);
+    assert_eq!(historical_date_to_rd((YEAR_MAX, 12, 31), reform::GREGORIAN), RD_MAX);
+}
+
+#[test]
+fn test_historical_days_in_month() {
+    assert_eq!(historical_days_in_month(1582, 9, reform::GREGORIAN), 30);
+    assert_eq!(historical_days_in_month(1582, 10, reform::GREGORIAN), 21);
+    assert_eq!(historical_days_in_month(1582, 12, reform::FRANCE), 21);
+    assert_eq!(historical_days_in_month(1610, 8, reform::PRUSSIA), 22);
+    assert_eq!(historical_days_in_month(1610, 9, reform::PRUSSIA), 29);
+    assert_eq!(historical_days_in_month(1700, 2, reform::DENMARK), 18);
+    assert_eq!(historical_days_in_month(1700, 2, reform::GREAT_BRITAIN), 29);
+    assert_eq!(historical_days_in_month(1752, 9, reform::GREAT_BRITAIN), 19);
+    assert_eq!(historical_days_in_month(1753, 2, reform::SWEDEN), 17);
+    assert_eq!(historical_days_in_month(1918, 1, reform::RUSSIA), 31);
+    assert_eq!(historical_days_in_month(1918, 2, reform::RUSSIA), 15);
+    assert_eq!(historical_days_in_month(1923, 2, reform::GREECE), 15);
+    assert_eq!(historical_days_in_month(1900, 2, reform::GREGORIAN), 28);
+    assert_eq!(historical_days_in_month(1500, 2, reform::GREGORIAN), 29);
+    assert_eq!(historical_days_in_month(JULIAN_YEAR_MIN, 1, reform::GREGORIAN), 31);
+    assert_eq!(historical_days_in_month(YEAR_MAX, 12, reform::GREGORIAN), 31);
+}
+
+#[test]
+fn test_rd_to_jdn() {
+    assert_eq!(rd_to_jdn(0), 2440588);
+    assert_eq!(rd_to_jdn(-2440588), 0);
+    assert_eq!(rd_to_jdn(RD_MIN), -534454564);
+    assert_eq!(rd_to_jdn(RD_MAX), 539264883);
+    assert_eq!(jdn_to_rd(2440588), 0);
+    assert_eq!(jdn_to_rd(0), -2440588);
+    assert_eq!(jdn_to_rd(-534454564), RD_MIN);
+    assert_eq!(jdn_to_rd(539264883), RD_MAX);
+}
+
+#[test]
+fn test_rd_to_mjd() {
+    assert_eq!(rd_to_mjd(0), 40587);
+    assert_eq!(rd_to_mjd(date_to_rd((1858, 11, 17))), 0);
+    assert_eq!(mjd_to_rd(40587), 0);
+    assert_eq!(mjd_to_rd(0), date_to_rd((1858, 11, 17)));
+    assert_eq!(mjd_to_rd(rd_to_mjd(RD_MIN)), RD_MIN);
+    assert_eq!(mjd_to_rd(rd_to_mjd(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_rd_to_rjd() {
+    assert_eq!(rd_to_rjd(0), 40588);
+    assert_eq!(rd_to_rjd(date_to_rd((1858, 11, 16))), 0);
+    assert_eq!(rjd_to_rd(40588), 0);
+    assert_eq!(rjd_to_rd(rd_to_rjd(RD_MIN)), RD_MIN);
+    assert_eq!(rjd_to_rd(rd_to_rjd(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_rd_to_tjd() {
+    assert_eq!(rd_to_tjd(0), 587);
+    assert_eq!(rd_to_tjd(date_to_rd((1968, 5, 24))), 0);
+    assert_eq!(tjd_to_rd(587), 0);
+    assert_eq!(tjd_to_rd(rd_to_tjd(RD_MIN)), RD_MIN);
+    assert_eq!(tjd_to_rd(rd_to_tjd(RD_MAX)), RD_MAX);
+}
+
+#[test]
+fn test_secs_to_jd_nanos() {
+    assert_eq!(secs_to_jd_nanos((0, 0)), (2440587, 43_200_000_000_000));
+    assert_eq!(secs_to_jd_nanos((43199, 999_999_999)), (2440587, 86_399_999_999_999));
+    assert_eq!(secs_to_jd_nanos((43200, 0)), (2440588, 0));
+    assert_eq!(secs_to_jd_nanos((-43200, 0)), (2440587, 0));
+    assert_eq!(secs_to_jd_nanos((-43201, 0)), (2440586, 86_399_000_000_000));
+    assert_eq!(secs_to_jd_nanos((RD_SECONDS_MIN, 0)), (rd_to_jdn(RD_MIN) - 1, 43_200_000_000_000));
+    assert_eq!(
+        secs_to_jd_nanos((RD_SECONDS_MAX, 999_999_999)),
+        (rd_to_jdn(RD_MAX), 43_199_999_999_999)
+    );
+}
+
+#[test]
+fn test_jd_nanos_to_secs() {
+    assert_eq!(jd_nanos_to_secs((2440587, 43_200_000_000_000)), (0, 0));
+    assert_eq!(jd_nanos_to_secs((2440587, 86_399_999_999_999)), (43199, 999_999_999));
+    assert_eq!(jd_nanos_to_secs((2440588, 0)), (43200, 0));
+    assert_eq!(jd_nanos_to_secs((2440587, 0)), (-43200, 0));
+    assert_eq!(jd_nanos_to_secs((2440586, 86_399_000_000_000)), (-43201, 0));
+    assert_eq!(jd_nanos_to_secs((rd_to_jdn(RD_MIN) - 1, 43_200_000_000_000)), (RD_SECONDS_MIN, 0));
+    assert_eq!(
+        jd_nanos_to_secs((rd_to_jdn(RD_MAX), 43_199_999_999_999)),
+        (RD_SECONDS_MAX, 999_999_999)
+    );
+}
+
+#[test]
+fn test_secs_to_jd() {
+    assert_eq!(secs_to_jd((0, 0)), 2440587.5);
+    assert_eq!(secs_to_jd((43200, 0)), 2440588.0);
+    assert_eq!(secs_to_jd((-43200, 0)), 2440587.0);
+    assert_eq!(secs_to_jd((-2440587 * 86400 - 43200, 0)), 0.0);
+    assert_eq!(secs_to_jd((-2440588 * 86400 - 43200, 0)), -1.0);
+    assert_eq!(secs_to_jd((RD_SECONDS_MIN, 0)), -534454564.5);
+    assert_eq!(secs_to_jd((RD_SECONDS_MAX - 43199, 0)), 539264883.0);
+}
+
+#[test]
+fn test_jd_to_secs() {
+    assert_eq!(jd_to_secs(2440587.5), (0, 0));
+    assert_eq!(jd_to_secs(2440588.0), (43200, 0));
+    assert_eq!(jd_to_secs(2440587.0), (-43200, 0));
+    assert_eq!(jd_to_secs(0.0), (-2440587 * 86400 - 43200, 0));
+    assert_eq!(jd_to_secs(-1.0), (-2440588 * 86400 - 43200, 0));
+    assert_eq!(jd_to_secs(-0.5), (-2440587 * 86400 - 86400, 0));
+    assert_eq!(jd_to_secs(2440587.999_999_999_9), (43200, 0));
+    assert_eq!(jd_to_secs(-534454564.5), (RD_SECONDS_MIN, 0));
+}
+
+#[test]
+fn test_millis_to_datetime() {
+    assert_eq!(millis_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_000_000));
+    assert_eq!(millis_to_datetime(-1000), (1969, 12, 31, 23, 59, 59, 0));
+    assert_eq!(millis_to_datetime(-1001), (1969, 12, 31, 23, 59, 58, 999_000_000));
+    assert_eq!(millis_to_datetime(RD_SECONDS_MIN * 1000), (YEAR_MIN, 1, 1, 0, 0, 0, 0));
+    assert_eq!(
+        millis_to_datetime(RD_SECONDS_MAX * 1000 + 999),
+        (YEAR_MAX, 12, 31, 23, 59, 59, 999_000_000)
+    );
+}
+
+#[test]
+fn test_datetime_to_millis() {
+    assert_eq!(datetime_to_millis((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+    assert_eq!(datetime_to_millis((1969, 12, 31, 23, 59, 58, 999_000_000)), -1001);
+    assert_eq!(datetime_to_millis((YEAR_MIN, 1, 1, 0, 0, 0, 0)), RD_SECONDS_MIN * 1000);
+    assert_eq!(
+        datetime_to_millis((YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999)),
+        RD_SECONDS_MAX * 1000 + 999
+    );
+}
+
+#[test]
+fn test_micros_to_datetime() {
+    assert_eq!(micros_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_999_000));
+    assert_eq!(micros_to_datetime(-1_000_001), (1969, 12, 31, 23, 59, 58, 999_999_000));
+    assert_eq!(micros_to_datetime(i64::MIN), (-290308, 12, 21, 19, 59, 5, 224_192_000));
+    assert_eq!(micros_to_datetime(i64::MAX), (294247, 1, 10, 4, 0, 54, 775_807_000));
+}
+
+#[test]
+fn test_datetime_to_micros() {
+    assert_eq!(datetime_to_micros((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+    assert_eq!(datetime_to_micros((1969, 12, 31, 23, 59, 58, 999_999_000)), -1_000_001);
+    assert_eq!(datetime_to_micros((-290308, 12, 21, 19, 59, 5, 224_192_000)), i64::MIN);
+    assert_eq!(datetime_to_micros((294247, 1, 10, 4, 0, 54, 775_807_999)), i64::MAX);
+}
+
+#[test]
+fn test_nanos_to_datetime() {
+    assert_eq!(nanos_to_datetime(-1), (1969, 12, 31, 23, 59, 59, 999_999_999));
+    assert_eq!(nanos_to_datetime(-1_000_000_001), (1969, 12, 31, 23, 59, 58, 999_999_999));
+    assert_eq!(nanos_to_datetime(i64::MIN as i128), (1677, 9, 21, 0, 12, 43, 145_224_192));
+    assert_eq!(nanos_to_datetime(i64::MAX as i128), (2262, 4, 11, 23, 47, 16, 854_775_807));
+    assert_eq!(nanos_to_datetime(i64::MIN as i128 - 1), (1677, 9, 21, 0, 12, 43, 145_224_191));
+    assert_eq!(nanos_to_datetime(i64::MAX as i128 + 1), (2262, 4, 11, 23, 47, 16, 854_775_808));
+    assert_eq!(
+        nanos_to_datetime(RD_SECONDS_MIN as i128 * 1_000_000_000),
+        (YEAR_MIN, 1, 1, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        nanos_to_datetime(RD_SECONDS_MAX as i128 * 1_000_000_000 + 999_999_999),
+        (YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999)
+    );
+}
+
+#[test]
+fn test_datetime_to_nanos() {
+    assert_eq!(datetime_to_nanos((1969, 12, 31, 23, 59, 59, 999_999_999)), -1);
+    assert_eq!(datetime_to_nanos((1677, 9, 21, 0, 12, 43, 145_224_192)), i64::MIN as i128);
+    assert_eq!(datetime_to_nanos((2262, 4, 11, 23, 47, 16, 854_775_807)), i64::MAX as i128);
+    assert_eq!(
+        datetime_to_nanos((YEAR_MIN, 1, 1, 0, 0, 0, 0)),
+        RD_SECONDS_MIN as i128 * 1_000_000_000
+    );
+    assert_eq!(
+        datetime_to_nanos((YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999)),
+        RD_SECONDS_MAX as i128 * 1_000_000_000 + 999_999_999
+    );
+}
+
 #[test]
 fn test_systemtime_to_secs() {
     assert_eq!(systemtime_to_secs(UNIX_EPOCH), Some((0, 0)));
@@ -425,3 +1081,302 @@ fn test_datetime_to_systemtime() {
         UNIX_EPOCH.checked_sub(Duration::from_secs(-RD_SECONDS_MIN as u64))
     );
 }
+
+#[test]
+fn test_checked_rd_to_date() {
+    assert_eq!(checked_rd_to_date(0), Some((1970, 1, 1)));
+    assert_eq!(checked_rd_to_date(RD_MIN), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(checked_rd_to_date(RD_MAX), Some((YEAR_MAX, 12, 31)));
+    assert_eq!(checked_rd_to_date(RD_MIN - 1), None);
+    assert_eq!(checked_rd_to_date(RD_MAX + 1), None);
+    assert_eq!(checked_rd_to_date(i32::MIN), None);
+    assert_eq!(checked_rd_to_date(i32::MAX), None);
+}
+
+#[test]
+fn test_checked_date_to_rd() {
+    assert_eq!(checked_date_to_rd((1970, 1, 1)), Some(0));
+    assert_eq!(checked_date_to_rd((YEAR_MIN, 1, 1)), Some(RD_MIN));
+    assert_eq!(checked_date_to_rd((YEAR_MAX, 12, 31)), Some(RD_MAX));
+    assert_eq!(checked_date_to_rd((YEAR_MIN - 1, 12, 31)), None);
+    assert_eq!(checked_date_to_rd((YEAR_MAX + 1, 1, 1)), None);
+    assert_eq!(checked_date_to_rd((2023, 0, 1)), None);
+    assert_eq!(checked_date_to_rd((2023, 13, 1)), None);
+    assert_eq!(checked_date_to_rd((2023, 1, 0)), None);
+    assert_eq!(checked_date_to_rd((2023, 1, 32)), None);
+    assert_eq!(checked_date_to_rd((2023, 2, 29)), None);
+    assert_eq!(checked_date_to_rd((2024, 2, 29)), Some(19782));
+    assert_eq!(checked_date_to_rd((2024, 2, 30)), None);
+    assert_eq!(checked_date_to_rd((2023, 4, 30)), Some(19477));
+    assert_eq!(checked_date_to_rd((2023, 4, 31)), None);
+    assert_eq!(checked_date_to_rd((i32::MIN, 1, 1)), None);
+    assert_eq!(checked_date_to_rd((i32::MAX, 12, 31)), None);
+    assert_eq!(checked_date_to_rd((2023, u8::MAX, u8::MAX)), None);
+}
+
+#[test]
+fn test_checked_rd_to_weekday() {
+    assert_eq!(checked_rd_to_weekday(0), Some(4));
+    assert_eq!(checked_rd_to_weekday(RD_MIN), Some(1));
+    assert_eq!(checked_rd_to_weekday(RD_MAX), Some(4));
+    assert_eq!(checked_rd_to_weekday(RD_MIN - 1), None);
+    assert_eq!(checked_rd_to_weekday(RD_MAX + 1), None);
+}
+
+#[test]
+fn test_checked_date_to_weekday() {
+    assert_eq!(checked_date_to_weekday((1970, 1, 1)), Some(4));
+    assert_eq!(checked_date_to_weekday((YEAR_MIN, 1, 1)), Some(1));
+    assert_eq!(checked_date_to_weekday((YEAR_MAX, 12, 31)), Some(4));
+    assert_eq!(checked_date_to_weekday((YEAR_MIN - 1, 12, 31)), None);
+    assert_eq!(checked_date_to_weekday((YEAR_MAX + 1, 1, 1)), None);
+    assert_eq!(checked_date_to_weekday((2023, 0, 1)), None);
+    assert_eq!(checked_date_to_weekday((2023, 13, 1)), None);
+    assert_eq!(checked_date_to_weekday((2023, 1, 0)), None);
+    assert_eq!(checked_date_to_weekday((2023, 2, 29)), None);
+}
+
+#[test]
+fn test_checked_next_date() {
+    assert_eq!(checked_next_date((2021, 12, 31)), Some((2022, 1, 1)));
+    assert_eq!(checked_next_date((2020, 2, 28)), Some((2020, 2, 29)));
+    assert_eq!(checked_next_date((YEAR_MAX, 12, 30)), Some((YEAR_MAX, 12, 31)));
+    assert_eq!(checked_next_date((YEAR_MIN, 1, 1)), Some((YEAR_MIN, 1, 2)));
+    assert_eq!(checked_next_date((YEAR_MAX, 12, 31)), None);
+    assert_eq!(checked_next_date((YEAR_MIN - 1, 12, 31)), None);
+    assert_eq!(checked_next_date((2021, 2, 29)), None);
+    assert_eq!(checked_next_date((2021, 0, 1)), None);
+    assert_eq!(checked_next_date((2021, 1, 0)), None);
+}
+
+#[test]
+fn test_checked_prev_date() {
+    assert_eq!(checked_prev_date((2021, 1, 1)), Some((2020, 12, 31)));
+    assert_eq!(checked_prev_date((2020, 3, 1)), Some((2020, 2, 29)));
+    assert_eq!(checked_prev_date((YEAR_MAX, 12, 31)), Some((YEAR_MAX, 12, 30)));
+    assert_eq!(checked_prev_date((YEAR_MIN, 1, 2)), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(checked_prev_date((YEAR_MIN, 1, 1)), None);
+    assert_eq!(checked_prev_date((YEAR_MAX + 1, 1, 1)), None);
+    assert_eq!(checked_prev_date((2021, 2, 29)), None);
+    assert_eq!(checked_prev_date((2021, 13, 1)), None);
+    assert_eq!(checked_prev_date((2021, 1, 0)), None);
+}
+
+#[test]
+fn test_checked_secs_to_dhms() {
+    assert_eq!(checked_secs_to_dhms(RD_SECONDS_MIN), Some((RD_MIN, 0, 0, 0)));
+    assert_eq!(checked_secs_to_dhms(RD_SECONDS_MAX), Some((RD_MAX, 23, 59, 59)));
+    assert_eq!(checked_secs_to_dhms(RD_SECONDS_MIN - 1), None);
+    assert_eq!(checked_secs_to_dhms(RD_SECONDS_MAX + 1), None);
+    assert_eq!(checked_secs_to_dhms(i64::MIN), None);
+    assert_eq!(checked_secs_to_dhms(i64::MAX), None);
+}
+
+#[test]
+fn test_checked_dhms_to_secs() {
+    assert_eq!(checked_dhms_to_secs((RD_MIN, 0, 0, 0)), Some(RD_SECONDS_MIN));
+    assert_eq!(checked_dhms_to_secs((RD_MAX, 23, 59, 59)), Some(RD_SECONDS_MAX));
+    assert_eq!(checked_dhms_to_secs((RD_MIN - 1, 23, 59, 59)), None);
+    assert_eq!(checked_dhms_to_secs((RD_MAX + 1, 0, 0, 0)), None);
+    assert_eq!(checked_dhms_to_secs((0, 24, 0, 0)), None);
+    assert_eq!(checked_dhms_to_secs((0, 0, 60, 0)), None);
+    assert_eq!(checked_dhms_to_secs((0, 0, 0, 60)), None);
+}
+
+#[test]
+fn test_checked_secs_to_datetime() {
+    assert_eq!(checked_secs_to_datetime(RD_SECONDS_MIN), Some((YEAR_MIN, 1, 1, 0, 0, 0)));
+    assert_eq!(checked_secs_to_datetime(RD_SECONDS_MAX), Some((YEAR_MAX, 12, 31, 23, 59, 59)));
+    assert_eq!(checked_secs_to_datetime(RD_SECONDS_MIN - 1), None);
+    assert_eq!(checked_secs_to_datetime(RD_SECONDS_MAX + 1), None);
+}
+
+#[test]
+fn test_checked_datetime_to_secs() {
+    assert_eq!(checked_datetime_to_secs((YEAR_MIN, 1, 1, 0, 0, 0)), Some(RD_SECONDS_MIN));
+    assert_eq!(checked_datetime_to_secs((YEAR_MAX, 12, 31, 23, 59, 59)), Some(RD_SECONDS_MAX));
+    assert_eq!(checked_datetime_to_secs((YEAR_MIN - 1, 12, 31, 23, 59, 59)), None);
+    assert_eq!(checked_datetime_to_secs((YEAR_MAX + 1, 1, 1, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_secs((2023, 0, 1, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_secs((2023, 2, 29, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_secs((2023, 1, 1, 24, 0, 0)), None);
+    assert_eq!(checked_datetime_to_secs((2023, 1, 1, 0, 60, 0)), None);
+    assert_eq!(checked_datetime_to_secs((2023, 1, 1, 0, 0, 60)), None);
+}
+
+#[test]
+fn test_checked_is_leap_year() {
+    assert_eq!(checked_is_leap_year(2000), Some(true));
+    assert_eq!(checked_is_leap_year(YEAR_MIN), Some(false));
+    assert_eq!(checked_is_leap_year(YEAR_MAX), Some(true));
+    assert_eq!(checked_is_leap_year(YEAR_MIN - 1), None);
+    assert_eq!(checked_is_leap_year(YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_checked_days_in_month() {
+    assert_eq!(checked_days_in_month(2023, 2), Some(28));
+    assert_eq!(checked_days_in_month(YEAR_MIN, 1), Some(31));
+    assert_eq!(checked_days_in_month(YEAR_MAX, 2), Some(29));
+    assert_eq!(checked_days_in_month(YEAR_MIN - 1, 1), None);
+    assert_eq!(checked_days_in_month(YEAR_MAX + 1, 1), None);
+    assert_eq!(checked_days_in_month(2023, 0), None);
+    assert_eq!(checked_days_in_month(2023, 13), None);
+}
+
+#[test]
+fn test_checked_rd_to_isoweekdate() {
+    assert_eq!(checked_rd_to_isoweekdate(RD_MIN), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(checked_rd_to_isoweekdate(RD_MAX), Some((YEAR_MAX, 53, 4)));
+    assert_eq!(checked_rd_to_isoweekdate(RD_MIN - 1), None);
+    assert_eq!(checked_rd_to_isoweekdate(RD_MAX + 1), None);
+}
+
+#[test]
+fn test_checked_isoweekdate_to_rd() {
+    assert_eq!(checked_isoweekdate_to_rd((1981, 53, 7)), Some(date_to_rd((1982, 1, 3))));
+    assert_eq!(checked_isoweekdate_to_rd((YEAR_MIN, 1, 1)), Some(RD_MIN));
+    assert_eq!(checked_isoweekdate_to_rd((YEAR_MAX, 53, 4)), Some(RD_MAX));
+    assert_eq!(checked_isoweekdate_to_rd((YEAR_MAX, 53, 5)), None);
+    assert_eq!(checked_isoweekdate_to_rd((YEAR_MIN - 1, 52, 7)), None);
+    assert_eq!(checked_isoweekdate_to_rd((YEAR_MAX + 1, 1, 1)), None);
+    assert_eq!(checked_isoweekdate_to_rd((1982, 53, 1)), None);
+    assert_eq!(checked_isoweekdate_to_rd((1982, 0, 1)), None);
+    assert_eq!(checked_isoweekdate_to_rd((1982, 1, 0)), None);
+    assert_eq!(checked_isoweekdate_to_rd((1982, 1, 8)), None);
+}
+
+#[test]
+fn test_checked_date_to_isoweekdate() {
+    assert_eq!(checked_date_to_isoweekdate((1982, 1, 1)), Some((1981, 53, 5)));
+    assert_eq!(checked_date_to_isoweekdate((YEAR_MIN, 1, 1)), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(checked_date_to_isoweekdate((YEAR_MAX, 12, 31)), Some((YEAR_MAX, 53, 4)));
+    assert_eq!(checked_date_to_isoweekdate((YEAR_MIN - 1, 12, 31)), None);
+    assert_eq!(checked_date_to_isoweekdate((YEAR_MAX + 1, 1, 1)), None);
+    assert_eq!(checked_date_to_isoweekdate((1982, 2, 29)), None);
+}
+
+#[test]
+fn test_checked_isoweekdate_to_date() {
+    assert_eq!(checked_isoweekdate_to_date((1981, 53, 5)), Some((1982, 1, 1)));
+    assert_eq!(checked_isoweekdate_to_date((YEAR_MIN, 1, 1)), Some((YEAR_MIN, 1, 1)));
+    assert_eq!(checked_isoweekdate_to_date((YEAR_MAX, 53, 4)), Some((YEAR_MAX, 12, 31)));
+    assert_eq!(checked_isoweekdate_to_date((YEAR_MAX, 53, 5)), None);
+    assert_eq!(checked_isoweekdate_to_date((1982, 53, 1)), None);
+    assert_eq!(checked_isoweekdate_to_date((1982, 1, 8)), None);
+}
+
+#[test]
+fn test_checked_isoweeks_in_year() {
+    assert_eq!(checked_isoweeks_in_year(2020), Some(53));
+    assert_eq!(checked_isoweeks_in_year(YEAR_MIN), Some(52));
+    assert_eq!(checked_isoweeks_in_year(YEAR_MAX), Some(53));
+    assert_eq!(checked_isoweeks_in_year(YEAR_MIN - 1), None);
+    assert_eq!(checked_isoweeks_in_year(YEAR_MAX + 1), None);
+}
+
+#[test]
+fn test_checked_secs_to_systemtime() {
+    assert_eq!(checked_secs_to_systemtime((0, 0)), Some(UNIX_EPOCH));
+    assert_eq!(
+        checked_secs_to_systemtime((RD_SECONDS_MAX, 999_999_999)),
+        UNIX_EPOCH.checked_add(Duration::new(RD_SECONDS_MAX as u64, 999_999_999))
+    );
+    assert_eq!(checked_secs_to_systemtime((RD_SECONDS_MAX + 1, 0)), None);
+    assert_eq!(checked_secs_to_systemtime((RD_SECONDS_MIN - 1, 0)), None);
+    assert_eq!(checked_secs_to_systemtime((0, 1_000_000_000)), None);
+}
+
+#[test]
+fn test_checked_datetime_to_systemtime() {
+    assert_eq!(checked_datetime_to_systemtime((1970, 1, 1, 0, 0, 0, 0)), Some(UNIX_EPOCH));
+    assert_eq!(
+        checked_datetime_to_systemtime((YEAR_MAX, 12, 31, 23, 59, 59, 0)),
+        UNIX_EPOCH.checked_add(Duration::from_secs(RD_SECONDS_MAX as u64))
+    );
+    assert_eq!(checked_datetime_to_systemtime((YEAR_MAX + 1, 1, 1, 0, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_systemtime((1970, 1, 1, 24, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_systemtime((1970, 1, 1, 0, 0, 0, 1_000_000_000)), None);
+}
+
+#[test]
+fn test_validate_date() {
+    assert_eq!(validate_date((1970, 1, 1)), Ok(()));
+    assert_eq!(validate_date((YEAR_MIN, 1, 1)), Ok(()));
+    assert_eq!(validate_date((YEAR_MAX, 12, 31)), Ok(()));
+    assert_eq!(validate_date((YEAR_MIN - 1, 12, 31)), Err(DateError::YearOutOfRange));
+    assert_eq!(validate_date((YEAR_MAX + 1, 1, 1)), Err(DateError::YearOutOfRange));
+    assert_eq!(validate_date((YEAR_MAX + 1, 13, 32)), Err(DateError::YearOutOfRange));
+    assert_eq!(validate_date((2023, 0, 1)), Err(DateError::MonthOutOfRange));
+    assert_eq!(validate_date((2023, 13, 1)), Err(DateError::MonthOutOfRange));
+    assert_eq!(validate_date((2023, 13, 32)), Err(DateError::MonthOutOfRange));
+    assert_eq!(validate_date((2023, 1, 0)), Err(DateError::DayOutOfRange { max: 31 }));
+    assert_eq!(validate_date((2023, 1, 32)), Err(DateError::DayOutOfRange { max: 31 }));
+    assert_eq!(validate_date((2023, 2, 29)), Err(DateError::DayOutOfRange { max: 28 }));
+    assert_eq!(validate_date((2024, 2, 29)), Ok(()));
+    assert_eq!(validate_date((2024, 2, 30)), Err(DateError::DayOutOfRange { max: 29 }));
+    assert_eq!(validate_date((2100, 2, 29)), Err(DateError::DayOutOfRange { max: 28 }));
+    assert_eq!(validate_date((2000, 2, 29)), Ok(()));
+    assert_eq!(validate_date((2023, 4, 31)), Err(DateError::DayOutOfRange { max: 30 }));
+}
+
+#[test]
+fn test_validate_datetime() {
+    assert_eq!(validate_datetime((1970, 1, 1, 0, 0, 0)), Ok(()));
+    assert_eq!(validate_datetime((YEAR_MIN, 1, 1, 0, 0, 0)), Ok(()));
+    assert_eq!(validate_datetime((YEAR_MAX, 12, 31, 23, 59, 59)), Ok(()));
+    assert_eq!(validate_datetime((YEAR_MAX + 1, 1, 1, 24, 0, 0)), Err(DateError::YearOutOfRange));
+    assert_eq!(validate_datetime((2023, 0, 1, 0, 0, 0)), Err(DateError::MonthOutOfRange));
+    assert_eq!(validate_datetime((2023, 2, 29, 0, 0, 0)), Err(DateError::DayOutOfRange { max: 28 }));
+    assert_eq!(validate_datetime((2023, 1, 1, 24, 0, 0)), Err(DateError::HourOutOfRange));
+    assert_eq!(validate_datetime((2023, 1, 1, 24, 60, 60)), Err(DateError::HourOutOfRange));
+    assert_eq!(validate_datetime((2023, 1, 1, 0, 60, 0)), Err(DateError::MinuteOutOfRange));
+    assert_eq!(validate_datetime((2023, 1, 1, 0, 60, 60)), Err(DateError::MinuteOutOfRange));
+    assert_eq!(validate_datetime((2023, 1, 1, 0, 0, 60)), Err(DateError::SecondOutOfRange));
+}
+
+#[test]
+fn test_validate_isoweekdate() {
+    assert_eq!(validate_isoweekdate((1970, 1, 4)), Ok(()));
+    assert_eq!(validate_isoweekdate((YEAR_MIN, 1, 1)), Ok(()));
+    assert_eq!(validate_isoweekdate((YEAR_MAX, 53, 4)), Ok(()));
+    assert_eq!(validate_isoweekdate((YEAR_MAX, 53, 5)), Err(DateError::WeekdayOutOfRange));
+    assert_eq!(validate_isoweekdate((YEAR_MIN - 1, 52, 7)), Err(DateError::YearOutOfRange));
+    assert_eq!(validate_isoweekdate((YEAR_MAX + 1, 1, 1)), Err(DateError::YearOutOfRange));
+    assert_eq!(
+        validate_isoweekdate((1981, 0, 1)),
+        Err(DateError::WeekOutOfRange { weeks_in_year: 53 })
+    );
+    assert_eq!(validate_isoweekdate((1981, 53, 7)), Ok(()));
+    assert_eq!(
+        validate_isoweekdate((1981, 54, 1)),
+        Err(DateError::WeekOutOfRange { weeks_in_year: 53 })
+    );
+    assert_eq!(validate_isoweekdate((1982, 52, 7)), Ok(()));
+    assert_eq!(
+        validate_isoweekdate((1982, 53, 1)),
+        Err(DateError::WeekOutOfRange { weeks_in_year: 52 })
+    );
+    assert_eq!(validate_isoweekdate((2020, 53, 7)), Ok(()));
+    assert_eq!(
+        validate_isoweekdate((2021, 53, 1)),
+        Err(DateError::WeekOutOfRange { weeks_in_year: 52 })
+    );
+    assert_eq!(validate_isoweekdate((2026, 53, 1)), Ok(()));
+    assert_eq!(validate_isoweekdate((1981, 1, 0)), Err(DateError::WeekdayOutOfRange));
+    assert_eq!(validate_isoweekdate((1981, 1, 8)), Err(DateError::WeekdayOutOfRange));
+}
+
+#[test]
+fn test_date_error_display() {
+    assert_eq!(DateError::YearOutOfRange.to_string(), "year is out of range");
+    assert_eq!(
+        DateError::DayOutOfRange { max: 28 }.to_string(),
+        "day is out of range (month has 28 days)"
+    );
+    assert_eq!(
+        DateError::WeekOutOfRange { weeks_in_year: 52 }.to_string(),
+        "week is out of range (year has 52 weeks)"
+    );
+    assert_eq!(DateError::SecondOutOfRange.to_string(), "second is out of range");
+}
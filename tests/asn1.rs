@@ -0,0 +1,140 @@
+use datealgo::asn1::*;
+use datealgo::{datetime_to_secs, ParseError};
+
+use chrono::DateTime;
+use quickcheck::quickcheck;
+
+mod common;
+
+const YEAR_0: i64 = -62167219200;
+const YEAR_9999: i64 = 253402300799;
+
+// Either side of the UTCTime range, and the limits of GeneralizedTime
+const EDGES: [i64; 6] = [YEAR_0, UTC_TIME_MIN - 1, UTC_TIME_MIN, UTC_TIME_MAX, UTC_TIME_MAX + 1, YEAR_9999];
+
+fn utc_time(secs: i64) -> String {
+    let mut out = [0u8; UTC_TIME_LEN];
+    format_utc_time(secs, &mut out);
+    String::from_utf8(out.to_vec()).unwrap()
+}
+
+fn generalized_time(secs: i64, nsec: u32) -> String {
+    let mut out = [0u8; GENERALIZED_TIME_MAX_LEN];
+    let len = format_generalized_time((secs, nsec), &mut out);
+    String::from_utf8(out[..len].to_vec()).unwrap()
+}
+
+#[test]
+fn test_utc_time_range() {
+    assert_eq!(UTC_TIME_MIN, datetime_to_secs((1950, 1, 1, 0, 0, 0)));
+    assert_eq!(UTC_TIME_MAX, datetime_to_secs((2049, 12, 31, 23, 59, 59)));
+}
+
+#[test]
+fn test_format_utc_time() {
+    assert_eq!(utc_time(UTC_TIME_MIN), "500101000000Z");
+    assert_eq!(utc_time(UTC_TIME_MAX), "491231235959Z");
+    assert_eq!(utc_time(0), "700101000000Z");
+    assert_eq!(utc_time(946684799), "991231235959Z");
+    assert_eq!(utc_time(946684800), "000101000000Z");
+}
+
+#[test]
+fn test_parse_utc_time() {
+    assert_eq!(parse_utc_time(b"500101000000Z"), Ok(UTC_TIME_MIN));
+    assert_eq!(parse_utc_time(b"491231235959Z"), Ok(UTC_TIME_MAX));
+    assert_eq!(parse_utc_time(b"000229000000Z"), Ok(datetime_to_secs((2000, 2, 29, 0, 0, 0))));
+    assert_eq!(parse_utc_time(b"000230000000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_utc_time(b"001301000000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_utc_time(b"000100000000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_utc_time(b"000101240000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_utc_time(b"000101235960Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_utc_time(b"000101000000z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"000101000000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"0001010000Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"000101000000.5Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"000101000000-0500"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"000101000000Z "), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"0001010000 0Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b"+00101000000Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_utc_time(b""), Err(ParseError::InvalidFormat));
+}
+
+#[test]
+fn test_format_generalized_time() {
+    assert_eq!(generalized_time(YEAR_0, 0), "00000101000000Z");
+    assert_eq!(generalized_time(YEAR_9999, 999_999_999), "99991231235959.999999999Z");
+    assert_eq!(generalized_time(0, 1), "19700101000000.000000001Z");
+    assert_eq!(generalized_time(0, 100_000_000), "19700101000000.1Z");
+    assert_eq!(generalized_time(0, 120_000), "19700101000000.00012Z");
+    assert_eq!(generalized_time(2524608000, 0), "20500101000000Z");
+}
+
+#[test]
+fn test_parse_generalized_time() {
+    assert_eq!(parse_generalized_time(b"00000101000000Z"), Ok((YEAR_0, 0)));
+    assert_eq!(parse_generalized_time(b"99991231235959.999999999Z"), Ok((YEAR_9999, 999_999_999)));
+    assert_eq!(parse_generalized_time(b"19700101000000.000000001Z"), Ok((0, 1)));
+    assert_eq!(parse_generalized_time(b"19700101000000.00012Z"), Ok((0, 120_000)));
+    assert_eq!(parse_generalized_time(b"19700101000000.1234567891Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_generalized_time(b"19700101000000.000000000Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.10Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000,5Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.5"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.5z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.-5Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"197001010000Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"1970010100Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000+0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700101000000.5+0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"1970-01-01T00:00:00Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_generalized_time(b"19700229000000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_generalized_time(b"19700101240000Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_generalized_time(b"19701231235960Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_generalized_time(b""), Err(ParseError::InvalidFormat));
+}
+
+quickcheck! {
+    fn quickcheck_format_utc_time(s: i64) -> bool {
+        let secs = s.rem_euclid(UTC_TIME_MAX - UTC_TIME_MIN + 1) + UTC_TIME_MIN;
+        let text = utc_time(secs);
+        let dt = DateTime::from_timestamp(secs, 0).unwrap();
+        text == dt.format("%y%m%d%H%M%SZ").to_string() && parse_utc_time(text.as_bytes()) == Ok(secs)
+    }
+
+    fn quickcheck_format_generalized_time(s: i64, n: u32, digits: u8) -> bool {
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let scale = 10u32.pow(digits as u32 % 10);
+        let nsec = n % 1_000_000_000 / scale * scale;
+        let text = generalized_time(secs, nsec);
+        let dt = DateTime::from_timestamp(secs, nsec).unwrap();
+        let fraction = format!("{:09}", nsec);
+        let fraction = fraction.trim_end_matches('0');
+        let expected = if nsec == 0 {
+            dt.format("%Y%m%d%H%M%SZ").to_string()
+        } else {
+            format!("{}.{}Z", dt.format("%Y%m%d%H%M%S"), fraction)
+        };
+        text == expected && parse_generalized_time(text.as_bytes()) == Ok((secs, nsec))
+    }
+
+    fn quickcheck_parse_asn1_garbage(input: Vec<u8>) -> bool {
+        common::check_parse(&input, parse_utc_time, |&secs| utc_time(secs))
+            && common::check_parse(&input, parse_generalized_time, |&(secs, nsec)| generalized_time(secs, nsec))
+    }
+
+    fn quickcheck_parse_asn1_mutated(k: usize, n: u32, pos: usize, byte: u8) -> bool {
+        let secs = EDGES[k % EDGES.len()];
+        let utc = secs.clamp(UTC_TIME_MIN, UTC_TIME_MAX);
+        common::check_mutated(&utc_time(utc), pos, byte, parse_utc_time, |&secs| utc_time(secs))
+            && common::check_mutated(
+                &generalized_time(secs, n % 1_000_000_000),
+                pos,
+                byte,
+                parse_generalized_time,
+                |&(secs, nsec)| generalized_time(secs, nsec),
+            )
+    }
+}
@@ -0,0 +1,28 @@
+//! Helpers shared by the parser tests
+
+/// Check that if the input is accepted, the value written back with the
+/// formatter parses to the same value
+pub fn check_parse<T: PartialEq, E>(input: &[u8], parse: impl Fn(&[u8]) -> Result<T, E>, format: impl Fn(&T) -> String) -> bool {
+    match parse(input) {
+        Ok(value) => parse(format(&value).as_bytes()).ok().as_ref() == Some(&value),
+        Err(_) => true,
+    }
+}
+
+/// Check that formatted text parses, and that every prefix of it with the
+/// byte at the given position replaced passes [check_parse]
+pub fn check_mutated<T: PartialEq, E>(
+    text: &str,
+    pos: usize,
+    byte: u8,
+    parse: impl Fn(&[u8]) -> Result<T, E>,
+    format: impl Fn(&T) -> String,
+) -> bool {
+    let mut text = text.as_bytes().to_vec();
+    if parse(&text).is_err() {
+        return false;
+    }
+    let pos = pos % text.len();
+    text[pos] = byte;
+    (0..=text.len()).all(|len| check_parse(&text[..len], &parse, &format))
+}
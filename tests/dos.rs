@@ -0,0 +1,66 @@
+use datealgo::dos::*;
+use datealgo::{datetime_to_secs, secs_to_datetime, DateError};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_dos_to_datetime() {
+    assert_eq!(dos_to_datetime((0xff9f, 0xbf7d)), Ok((2107, 12, 31, 23, 59, 58)));
+    assert_eq!(dos_to_datetime((0x0021, 0xc000)), Err(DateError::HourOutOfRange));
+    assert_eq!(dos_to_datetime((0x0021, 0x0780)), Err(DateError::MinuteOutOfRange));
+    assert_eq!(dos_to_datetime((0x0021, 0x001f)), Err(DateError::SecondOutOfRange));
+    assert_eq!(dos_to_datetime((0x0020, 0x0000)), Err(DateError::DayOutOfRange { max: 31 }));
+    assert_eq!(dos_to_datetime((0x0201, 0x0000)), Err(DateError::MonthOutOfRange));
+    assert_eq!(dos_to_datetime((0x01a1, 0x0000)), Err(DateError::MonthOutOfRange));
+    assert_eq!(dos_to_datetime((0x025d, 0x0000)), Err(DateError::DayOutOfRange { max: 28 }));
+    assert_eq!(dos_to_datetime((0x0000, 0xffff)), Err(DateError::MonthOutOfRange));
+}
+
+#[test]
+fn test_datetime_to_dos() {
+    assert_eq!(datetime_to_dos((2107, 12, 31, 23, 59, 59)), (0xff9f, 0xbf7d));
+    assert_eq!(datetime_to_dos((1980, 1, 1, 0, 0, 1)), (0x0021, 0x0000));
+    assert_eq!(checked_datetime_to_dos((2108, 1, 1, 0, 0, 0)), None);
+    assert_eq!(checked_datetime_to_dos((2107, 12, 31, 23, 59, 59)), Some((0xff9f, 0xbf7d)));
+    assert_eq!(checked_datetime_to_dos((2000, 1, 1, 24, 0, 0)), None);
+}
+
+#[test]
+fn test_dos_secs() {
+    assert_eq!(dos_to_secs((0xff9f, 0xbf7d)), Ok(datetime_to_secs((2107, 12, 31, 23, 59, 58))));
+    assert_eq!(dos_to_secs((0x0000, 0x0000)), Err(DateError::MonthOutOfRange));
+    assert_eq!(secs_to_dos(datetime_to_secs((2107, 12, 31, 23, 59, 59))), (0xff9f, 0xbf7d));
+}
+
+#[test]
+fn test_zip_extra() {
+    assert_eq!(zip_ntfs_to_datetime(0), (1601, 1, 1, 0, 0, 0, 0));
+    assert_eq!(datetime_to_zip_ntfs((1601, 1, 1, 0, 0, 0, 99)), 0);
+    assert_eq!(zip_unix_to_datetime(i32::MAX), (2038, 1, 19, 3, 14, 7));
+    assert_eq!(datetime_to_zip_unix((1901, 12, 13, 20, 45, 52)), i32::MIN);
+}
+
+quickcheck! {
+    fn quickcheck_dos_to_datetime(date: u16, time: u16) -> TestResult {
+        match dos_to_datetime((date, time)) {
+            Ok(dt) => TestResult::from_bool(datetime_to_dos(dt) == (date, time) && checked_datetime_to_dos(dt) == Some((date, time))),
+            Err(_) => TestResult::discard(),
+        }
+    }
+
+    fn quickcheck_secs_to_dos(s: u32) -> TestResult {
+        let min = datetime_to_secs((1980, 1, 1, 0, 0, 0));
+        let max = datetime_to_secs((2107, 12, 31, 23, 59, 59));
+        let secs = min + s as i64 % (max - min + 1);
+        TestResult::from_bool(dos_to_secs(secs_to_dos(secs)) == Ok(secs & !1))
+    }
+
+    fn quickcheck_zip_unix_to_datetime(t: i32) -> TestResult {
+        let dt = zip_unix_to_datetime(t);
+        TestResult::from_bool(dt == secs_to_datetime(t as i64) && datetime_to_zip_unix(dt) == t)
+    }
+
+    fn quickcheck_zip_ntfs_to_datetime(ft: u64) -> TestResult {
+        TestResult::from_bool(datetime_to_zip_ntfs(zip_ntfs_to_datetime(ft)) == ft)
+    }
+}
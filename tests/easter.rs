@@ -0,0 +1,189 @@
+use datealgo::easter::*;
+use datealgo::*;
+
+use quickcheck::quickcheck;
+
+/// Anonymous Gregorian algorithm by Meeus and Butcher, with floored division
+fn butcher(y: i32) -> (i32, u8, u8) {
+    let a = y.rem_euclid(19);
+    let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    (y, (n / 31) as u8, (n % 31 + 1) as u8)
+}
+
+/// Julian algorithm by Meeus, giving a Julian date
+fn meeus_julian(y: i32) -> (i32, u8, u8) {
+    let a = y.rem_euclid(4);
+    let b = y.rem_euclid(7);
+    let c = y.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let n = d + e + 114;
+    (y, (n / 31) as u8, (n % 31 + 1) as u8)
+}
+
+#[test]
+fn test_gregorian_easter() {
+    let dates = [
+        (2000, 4, 23),
+        (2001, 4, 15),
+        (2002, 3, 31),
+        (2003, 4, 20),
+        (2004, 4, 11),
+        (2005, 3, 27),
+        (2006, 4, 16),
+        (2007, 4, 8),
+        (2008, 3, 23),
+        (2009, 4, 12),
+        (2010, 4, 4),
+        (2011, 4, 24),
+        (2012, 4, 8),
+        (2013, 3, 31),
+        (2014, 4, 20),
+        (2015, 4, 5),
+        (2016, 3, 27),
+        (2017, 4, 16),
+        (2018, 4, 1),
+        (2019, 4, 21),
+        (2020, 4, 12),
+        (2021, 4, 4),
+        (2022, 4, 17),
+        (2023, 4, 9),
+        (2024, 3, 31),
+        (2025, 4, 20),
+    ];
+    for (y, m, d) in dates {
+        assert_eq!(gregorian_easter(y), (y, m, d));
+        assert_eq!(gregorian_easter_rd(y), date_to_rd((y, m, d)));
+    }
+    // earliest and latest possible dates
+    assert_eq!(gregorian_easter(1818), (1818, 3, 22));
+    assert_eq!(gregorian_easter(2285), (2285, 3, 22));
+    assert_eq!(gregorian_easter(1943), (1943, 4, 25));
+    assert_eq!(gregorian_easter(2038), (2038, 4, 25));
+    // exceptions to the full moon rule
+    assert_eq!(gregorian_easter(1954), (1954, 4, 18));
+    assert_eq!(gregorian_easter(1981), (1981, 4, 19));
+    assert_eq!(gregorian_easter(2049), (2049, 4, 18));
+    assert_eq!(gregorian_easter(2076), (2076, 4, 19));
+    assert_eq!(gregorian_easter(1583), (1583, 4, 10));
+    assert_eq!(gregorian_easter(0), (0, 4, 9));
+    assert_eq!(gregorian_easter(-1), (-1, 4, 18));
+    assert_eq!(gregorian_easter(YEAR_MIN), butcher(YEAR_MIN));
+    assert_eq!(gregorian_easter(YEAR_MAX), butcher(YEAR_MAX));
+}
+
+#[test]
+fn test_orthodox_easter() {
+    let dates = [
+        (2000, 4, 30),
+        (2001, 4, 15),
+        (2002, 5, 5),
+        (2003, 4, 27),
+        (2004, 4, 11),
+        (2005, 5, 1),
+        (2006, 4, 23),
+        (2007, 4, 8),
+        (2008, 4, 27),
+        (2009, 4, 19),
+        (2010, 4, 4),
+        (2011, 4, 24),
+        (2012, 4, 15),
+        (2013, 5, 5),
+        (2014, 4, 20),
+        (2015, 4, 12),
+        (2016, 5, 1),
+        (2017, 4, 16),
+        (2018, 4, 8),
+        (2019, 4, 28),
+        (2020, 4, 19),
+        (2021, 5, 2),
+        (2022, 4, 24),
+        (2023, 4, 16),
+        (2024, 5, 5),
+        (2025, 4, 20),
+    ];
+    for (y, m, d) in dates {
+        assert_eq!(orthodox_easter(y), (y, m, d));
+        assert_eq!(orthodox_easter_rd(y), date_to_rd((y, m, d)));
+    }
+    assert_eq!(orthodox_easter(1583), (1583, 4, 10));
+    assert_eq!(orthodox_easter(1582), (1582, 4, 25));
+    assert_eq!(orthodox_easter_rd(1582), julian_date_to_rd((1582, 4, 15)));
+    assert_eq!(orthodox_easter_rd(0), julian_date_to_rd((0, 4, 11)));
+    assert_eq!(orthodox_easter_rd(-1), julian_date_to_rd((-1, 4, 20)));
+    assert_eq!(
+        orthodox_easter_rd(JULIAN_YEAR_MIN),
+        julian_date_to_rd(meeus_julian(JULIAN_YEAR_MIN))
+    );
+    assert_eq!(
+        orthodox_easter_rd(JULIAN_YEAR_MAX),
+        julian_date_to_rd(meeus_julian(JULIAN_YEAR_MAX))
+    );
+}
+
+#[test]
+fn test_feasts() {
+    assert_eq!(rd_to_date(ash_wednesday_rd(2024)), (2024, 2, 14));
+    assert_eq!(rd_to_date(good_friday_rd(2024)), (2024, 3, 29));
+    assert_eq!(rd_to_date(ascension_rd(2024)), (2024, 5, 9));
+    assert_eq!(rd_to_date(pentecost_rd(2024)), (2024, 5, 19));
+    assert_eq!(rd_to_date(corpus_christi_rd(2024)), (2024, 5, 30));
+    assert_eq!(rd_to_date(gregorian_easter_rd(2024) + PALM_SUNDAY), (2024, 3, 24));
+    assert_eq!(rd_to_date(gregorian_easter_rd(2024) + MAUNDY_THURSDAY), (2024, 3, 28));
+    assert_eq!(rd_to_date(gregorian_easter_rd(2024) + HOLY_SATURDAY), (2024, 3, 30));
+    assert_eq!(rd_to_date(gregorian_easter_rd(2024) + EASTER_MONDAY), (2024, 4, 1));
+    assert_eq!(rd_to_date(gregorian_easter_rd(2024) + WHIT_MONDAY), (2024, 5, 20));
+    assert_eq!(rd_to_date(ash_wednesday_rd(2285)), (2285, 2, 4));
+    assert_eq!(rd_to_date(corpus_christi_rd(2038)), (2038, 6, 24));
+    assert_eq!(rd_to_date(orthodox_easter_rd(2024) + GOOD_FRIDAY), (2024, 5, 3));
+    assert_eq!(rd_to_date(orthodox_easter_rd(2024) + PENTECOST), (2024, 6, 23));
+}
+
+#[test]
+fn test_easter_full_range() {
+    for y in YEAR_MIN..=YEAR_MAX {
+        assert_eq!(gregorian_easter(y), butcher(y));
+    }
+    for y in JULIAN_YEAR_MIN..=JULIAN_YEAR_MAX {
+        let rd = julian_date_to_rd(meeus_julian(y));
+        assert_eq!(orthodox_easter_rd(y), rd);
+        assert_eq!(orthodox_easter(y), rd_to_date(rd));
+    }
+}
+
+quickcheck! {
+    fn quickcheck_gregorian_easter(y: i32) -> bool {
+        let y = y.rem_euclid(YEAR_MAX - YEAR_MIN + 1) + YEAR_MIN;
+        let (ey, m, d) = gregorian_easter(y);
+        let rd = gregorian_easter_rd(y);
+        ey == y
+            && (m, d) >= (3, 22)
+            && (m, d) <= (4, 25)
+            && rd == date_to_rd((y, m, d))
+            && rd_to_weekday(rd) == consts::SUNDAY
+            && rd_to_weekday(ash_wednesday_rd(y)) == consts::WEDNESDAY
+            && rd_to_weekday(good_friday_rd(y)) == consts::FRIDAY
+            && rd_to_weekday(ascension_rd(y)) == consts::THURSDAY
+            && rd_to_weekday(pentecost_rd(y)) == consts::SUNDAY
+            && rd_to_weekday(corpus_christi_rd(y)) == consts::THURSDAY
+    }
+
+    fn quickcheck_orthodox_easter(y: i32) -> bool {
+        let y = y.rem_euclid(JULIAN_YEAR_MAX - JULIAN_YEAR_MIN + 1) + JULIAN_YEAR_MIN;
+        let rd = orthodox_easter_rd(y);
+        let (jy, m, d) = rd_to_julian_date(rd);
+        jy == y
+            && (m, d) >= (3, 22)
+            && (m, d) <= (4, 25)
+            && orthodox_easter(y) == rd_to_date(rd)
+            && rd_to_weekday(rd) == consts::SUNDAY
+    }
+}
@@ -0,0 +1,198 @@
+use datealgo::epoch::*;
+use datealgo::{datetime_to_secs, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_epochs() {
+    assert_eq!(NTP_EPOCH, -2208988800);
+    assert_eq!(FILETIME_EPOCH, -11644473600);
+    assert_eq!(DOTNET_EPOCH, -62135596800);
+    assert_eq!(COCOA_EPOCH, 978307200);
+    assert_eq!(HFS_EPOCH, -2082844800);
+    assert_eq!(POSTGRES_EPOCH, 946684800);
+    assert_eq!(EXCEL_EPOCH, -2209161600);
+}
+
+#[test]
+fn test_ntp() {
+    assert_eq!(ntp_to_secs(0, 0), (NTP_EPOCH, 0));
+    assert_eq!(ntp_to_secs(u64::MAX, 0), (NTP_EPOCH + u32::MAX as i64, 999_999_999));
+    assert_eq!(ntp_to_secs(1, 0), (NTP_EPOCH, 0));
+    assert_eq!(ntp_to_secs(5, 0), (NTP_EPOCH, 1));
+    assert_eq!(ntp_to_secs(0xffffffff_00000000, -1), (NTP_EPOCH - 1, 0));
+    assert_eq!(secs_to_ntp((NTP_EPOCH, 1)), (0, 5));
+    assert_eq!(secs_to_ntp((NTP_EPOCH + u32::MAX as i64, 999_999_999)), (0, 0xffffffff_fffffffc));
+    assert_eq!(secs_to_ntp((NTP_EPOCH + (1 << 32), 0)), (1, 0));
+    assert_eq!(secs_to_ntp((RD_SECONDS_MIN, 0)).0, -10800);
+    assert_eq!(secs_to_ntp((RD_SECONDS_MAX, 0)).0, 10799);
+    let (era, ts) = secs_to_ntp((RD_SECONDS_MIN, 0));
+    assert_eq!(ntp_to_secs(ts, era), (RD_SECONDS_MIN, 0));
+    let (era, ts) = secs_to_ntp((RD_SECONDS_MAX, 999_999_999));
+    assert_eq!(ntp_to_secs(ts, era), (RD_SECONDS_MAX, 999_999_999));
+}
+
+#[test]
+fn test_resolve_ntp_era() {
+    assert_eq!(resolve_ntp_era(0, NTP_EPOCH), 0);
+    assert_eq!(resolve_ntp_era(0x7fffffff_00000000, NTP_EPOCH), 0);
+    assert_eq!(resolve_ntp_era(0x80000000_00000000, NTP_EPOCH), -1);
+    let rollover = datetime_to_secs((2036, 2, 7, 6, 28, 16));
+    assert_eq!(resolve_ntp_era(0xffffffff_00000000, rollover), 0);
+    assert_eq!(resolve_ntp_era(0, rollover), 1);
+    assert_eq!(resolve_ntp_era(0, rollover - 1), 1);
+    assert_eq!(resolve_ntp_era(0x80000000_00000000, rollover), 0);
+    assert_eq!(resolve_ntp_era(0x7fffffff_00000000, rollover), 1);
+}
+
+#[test]
+fn test_filetime() {
+    assert_eq!(filetime_to_secs(0), (FILETIME_EPOCH, 0));
+    assert_eq!(filetime_to_secs(1), (FILETIME_EPOCH, 100));
+    assert_eq!(
+        filetime_to_secs(u64::MAX),
+        (datetime_to_secs((60056, 5, 28, 5, 36, 10)), 955_161_500)
+    );
+    assert_eq!(secs_to_filetime((FILETIME_EPOCH, 99)), 0);
+    assert_eq!(
+        secs_to_filetime((datetime_to_secs((60056, 5, 28, 5, 36, 10)), 955_161_599)),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_ticks() {
+    assert_eq!(ticks_to_secs(-1), (DOTNET_EPOCH - 1, 999_999_900));
+    assert_eq!(
+        ticks_to_secs(i64::MIN),
+        (datetime_to_secs((-29227, 4, 19, 21, 11, 54)), 522_419_200)
+    );
+    assert_eq!(ticks_to_secs(i64::MAX), (datetime_to_secs((29228, 9, 14, 2, 48, 5)), 477_580_700));
+    assert_eq!(secs_to_ticks((DOTNET_EPOCH - 1, 999_999_999)), -1);
+    assert_eq!(secs_to_ticks(ticks_to_secs(i64::MIN)), i64::MIN);
+    assert_eq!(secs_to_ticks((datetime_to_secs((29228, 9, 14, 2, 48, 5)), 477_580_799)), i64::MAX);
+}
+
+#[test]
+fn test_cocoa() {
+    assert_eq!(cocoa_to_secs(0.000_000_000_4), (COCOA_EPOCH, 0));
+    assert_eq!(cocoa_to_secs(0.000_000_000_6), (COCOA_EPOCH, 1));
+    assert_eq!(cocoa_to_secs(-0.000_000_000_4), (COCOA_EPOCH, 0));
+    assert_eq!(cocoa_to_secs(-0.000_000_000_6), (COCOA_EPOCH - 1, 999_999_999));
+    assert_eq!(cocoa_to_secs(0.999_999_999_9), (COCOA_EPOCH + 1, 0));
+    assert_eq!(cocoa_to_secs(64.0 / 65536.0), (COCOA_EPOCH, 976_562));
+    assert_eq!(cocoa_to_secs(192.0 / 65536.0), (COCOA_EPOCH, 2_929_688));
+    assert_eq!(cocoa_to_secs(-64.0 / 65536.0), (COCOA_EPOCH - 1, 999_023_438));
+    assert_eq!(cocoa_to_secs((RD_SECONDS_MIN - COCOA_EPOCH) as f64), (RD_SECONDS_MIN, 0));
+    assert_eq!(cocoa_to_secs((RD_SECONDS_MAX - COCOA_EPOCH) as f64), (RD_SECONDS_MAX, 0));
+    assert_eq!(secs_to_cocoa((COCOA_EPOCH - 1, 0)), -1.0);
+    assert_eq!(secs_to_cocoa((RD_SECONDS_MIN, 0)), (RD_SECONDS_MIN - COCOA_EPOCH) as f64);
+}
+
+#[test]
+fn test_hfs() {
+    assert_eq!(hfs_to_secs(0), HFS_EPOCH);
+    assert_eq!(hfs_to_secs(u32::MAX), HFS_EPOCH + u32::MAX as i64);
+    assert_eq!(secs_to_hfs(HFS_EPOCH), 0);
+    assert_eq!(secs_to_hfs(HFS_EPOCH + u32::MAX as i64), u32::MAX);
+}
+
+#[test]
+fn test_postgres() {
+    assert_eq!(
+        postgres_to_secs(i64::MIN),
+        (datetime_to_secs((-290308, 12, 21, 19, 59, 5)) + POSTGRES_EPOCH, 224_192_000)
+    );
+    assert_eq!(
+        postgres_to_secs(i64::MAX),
+        (datetime_to_secs((294247, 1, 10, 4, 0, 54)) + POSTGRES_EPOCH, 775_807_000)
+    );
+    assert_eq!(secs_to_postgres(postgres_to_secs(i64::MIN)), i64::MIN);
+    assert_eq!(secs_to_postgres(postgres_to_secs(i64::MAX)), i64::MAX);
+    assert_eq!(secs_to_postgres((POSTGRES_EPOCH - 1, 0)), -1_000_000);
+}
+
+#[test]
+fn test_excel() {
+    assert_eq!(excel_to_secs(0.0), (EXCEL_EPOCH, 0));
+    assert_eq!(excel_to_secs(-0.25), (EXCEL_EPOCH - 21600, 0));
+    assert_eq!(excel_to_secs(2958465.5), (datetime_to_secs((9999, 12, 31, 12, 0, 0)), 0));
+    assert_eq!(excel_to_secs(0.999_999_999_999_999), (EXCEL_EPOCH + 86400, 0));
+    assert_eq!(secs_to_excel((EXCEL_EPOCH - 21600, 0)), -0.25);
+    assert_eq!(secs_to_excel((datetime_to_secs((1900, 1, 1, 12, 0, 0)), 0)), 2.5);
+}
+
+quickcheck! {
+    fn quickcheck_ntp_to_secs(ts: u64, era: i8) -> TestResult {
+        let (secs, nsecs) = ntp_to_secs(ts, era as i32);
+        let (era2, ts2) = secs_to_ntp((secs, nsecs));
+        let diff = ts.wrapping_sub(ts2);
+        TestResult::from_bool(nsecs < 1_000_000_000 && era2 == era as i32 && ts2 >> 32 == ts >> 32 && diff < 5)
+    }
+
+    fn quickcheck_secs_to_ntp(s: i64, nsecs: u32) -> TestResult {
+        let secs = s % RD_SECONDS_MAX;
+        let nsecs = nsecs % 1_000_000_000;
+        let (era, ts) = secs_to_ntp((secs, nsecs));
+        TestResult::from_bool(ntp_to_secs(ts, era) == (secs, nsecs) && resolve_ntp_era(ts, secs) == era)
+    }
+
+    fn quickcheck_resolve_ntp_era(s: i64, offset: i32) -> TestResult {
+        let secs = s % (RD_SECONDS_MAX - (1 << 32));
+        if offset == i32::MIN {
+            return TestResult::discard();
+        }
+        let (era, ts) = secs_to_ntp((secs, 0));
+        let reference = secs + offset as i64;
+        TestResult::from_bool(resolve_ntp_era(ts, reference) == era)
+    }
+
+    fn quickcheck_filetime_to_secs(ft: u64) -> TestResult {
+        let (secs, nsecs) = filetime_to_secs(ft);
+        TestResult::from_bool(nsecs < 1_000_000_000 && secs_to_filetime((secs, nsecs)) == ft)
+    }
+
+    fn quickcheck_ticks_to_secs(ticks: i64) -> TestResult {
+        let (secs, nsecs) = ticks_to_secs(ticks);
+        TestResult::from_bool(nsecs < 1_000_000_000 && secs_to_ticks((secs, nsecs)) == ticks)
+    }
+
+    fn quickcheck_secs_to_cocoa(s: i64, nsecs: u32) -> TestResult {
+        // f64 has enough precision for nanoseconds only within about 100 days of the epoch
+        let secs = COCOA_EPOCH + s % 8_000_000;
+        let nsecs = nsecs % 1_000_000_000;
+        TestResult::from_bool(cocoa_to_secs(secs_to_cocoa((secs, nsecs))) == (secs, nsecs))
+    }
+
+    fn quickcheck_cocoa_to_secs(t: i16, frac: u16) -> TestResult {
+        // exact nanoseconds of the binary fraction, rounded half to even
+        let (q, r) = (frac as u64 * 1_000_000_000 / 65536, frac as u64 * 1_000_000_000 % 65536);
+        let nsecs = if r > 32768 || (r == 32768 && q % 2 == 1) { q + 1 } else { q };
+        let expected = (COCOA_EPOCH + t as i64, nsecs as u32);
+        TestResult::from_bool(cocoa_to_secs(t as f64 + frac as f64 / 65536.0) == expected)
+    }
+
+    fn quickcheck_hfs_to_secs(t: u32) -> TestResult {
+        TestResult::from_bool(secs_to_hfs(hfs_to_secs(t)) == t)
+    }
+
+    fn quickcheck_postgres_to_secs(micros: i64) -> TestResult {
+        let (secs, nsecs) = postgres_to_secs(micros);
+        TestResult::from_bool(nsecs < 1_000_000_000 && secs_to_postgres((secs, nsecs)) == micros)
+    }
+
+    fn quickcheck_excel_to_secs(days: i32, frac: u16) -> TestResult {
+        let serial = (days % 3_000_000) as f64 + frac as f64 / 65536.0;
+        let (secs, nsecs) = excel_to_secs(serial);
+        TestResult::from_bool(nsecs < 1_000_000_000 && (secs_to_excel((secs, nsecs)) - serial).abs() < 1e-9)
+    }
+
+    fn quickcheck_secs_to_excel(s: i64, nsecs: u32) -> TestResult {
+        // f64 has a precision of about a microsecond for current serial dates
+        let secs = s % 250_000_000_000;
+        let nsecs = nsecs % 1_000_000_000;
+        let (secs2, nsecs2) = excel_to_secs(secs_to_excel((secs, nsecs)));
+        let diff = (secs2 as i128 - secs as i128) * 1_000_000_000 + nsecs2 as i128 - nsecs as i128;
+        TestResult::from_bool(diff.abs() < 1_000_000)
+    }
+}
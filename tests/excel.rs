@@ -0,0 +1,113 @@
+use datealgo::excel::*;
+use datealgo::{date_to_rd, rd_to_date, RD_MAX, RD_MIN, YEAR_MAX, YEAR_MIN};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_serial_1900_to_date() {
+    assert_eq!(serial_1900_to_date(0), (1900, 1, 0));
+    assert_eq!(serial_1900_to_date(31), (1900, 1, 31));
+    assert_eq!(serial_1900_to_date(32), (1900, 2, 1));
+    assert_eq!(serial_1900_to_date(367), (1901, 1, 1));
+    assert_eq!(serial_1900_to_date(25569), (1970, 1, 1));
+    assert_eq!(serial_1900_to_date(2958465), (9999, 12, 31));
+    assert_eq!(serial_1900_to_date(RD_MAX - date_to_rd((1899, 12, 31)) + 1), (YEAR_MAX, 12, 31));
+}
+
+#[test]
+fn test_date_to_serial_1900() {
+    assert_eq!(date_to_serial_1900((1900, 1, 31)), 31);
+    assert_eq!(date_to_serial_1900((1900, 2, 28)), 59);
+    assert_eq!(date_to_serial_1900((1901, 1, 1)), 367);
+    assert_eq!(date_to_serial_1900((1970, 1, 1)), 25569);
+    assert_eq!(date_to_serial_1900((9999, 12, 31)), 2958465);
+    assert_eq!(date_to_serial_1900((YEAR_MAX, 12, 31)), RD_MAX - date_to_rd((1899, 12, 31)) + 1);
+}
+
+#[test]
+fn test_serial_1904() {
+    assert_eq!(serial_1904_to_date(2957003), (9999, 12, 31));
+    assert_eq!(serial_1904_to_date(RD_MIN - date_to_rd((1904, 1, 1))), (YEAR_MIN, 1, 1));
+    assert_eq!(serial_1904_to_date(RD_MAX - date_to_rd((1904, 1, 1))), (YEAR_MAX, 12, 31));
+    assert_eq!(date_to_serial_1904((9999, 12, 31)), 2957003);
+    assert_eq!(
+        date_to_serial_1900((2023, 5, 7)) - date_to_serial_1904((2023, 5, 7)),
+        DATE_1904_OFFSET
+    );
+    assert_eq!(date_to_serial_1900((1904, 1, 1)), DATE_1904_OFFSET);
+}
+
+#[test]
+fn test_serial_1900_to_datetime() {
+    assert_eq!(serial_1900_to_datetime(0.0), (1900, 1, 0, 0, 0, 0, 0));
+    assert_eq!(serial_1900_to_datetime(59.999_999_999), (1900, 2, 29, 0, 0, 0, 0));
+    assert_eq!(serial_1900_to_datetime(60.999_999), (1900, 2, 29, 23, 59, 59, 914_000_000));
+    assert_eq!(serial_1900_to_datetime(45053.5 + 0.4999 / 86_400_000.0), (2023, 5, 7, 12, 0, 0, 0));
+    assert_eq!(
+        serial_1900_to_datetime(45053.5 + 0.5001 / 86_400_000.0),
+        (2023, 5, 7, 12, 0, 0, 1_000_000)
+    );
+    assert_eq!(
+        serial_1900_to_datetime(45053.0 + 86_399_999.0 / 86_400_000.0),
+        (2023, 5, 7, 23, 59, 59, 999_000_000)
+    );
+    assert_eq!(serial_1900_to_datetime(2958465.999_999), (9999, 12, 31, 23, 59, 59, 914_000_000));
+}
+
+#[test]
+fn test_datetime_to_serial_1900() {
+    assert_eq!(datetime_to_serial_1900((1900, 1, 0, 0, 0, 0, 0)), 0.0);
+    assert_eq!(datetime_to_serial_1900((1900, 3, 1, 0, 0, 0, 0)), 61.0);
+    assert_eq!(datetime_to_serial_1900((2023, 5, 7, 12, 0, 0, 499_999)), 45053.5);
+    assert_eq!(
+        datetime_to_serial_1900((2023, 5, 7, 12, 0, 0, 500_000)),
+        45053.5 + 1.0 / 86_400_000.0
+    );
+    assert_eq!(datetime_to_serial_1900((2023, 5, 7, 23, 59, 59, 999_500_000)), 45054.0);
+}
+
+#[test]
+fn test_serial_1904_to_datetime() {
+    assert_eq!(serial_1904_to_datetime(-1.0), (1903, 12, 31, 0, 0, 0, 0));
+    assert_eq!(serial_1904_to_datetime(-0.000_000_000_1), (1904, 1, 1, 0, 0, 0, 0));
+    assert_eq!(datetime_to_serial_1904((1903, 12, 31, 0, 0, 0, 0)), -1.0);
+}
+
+quickcheck! {
+    fn quickcheck_serial_1900_to_date(s: u32) -> TestResult {
+        let serial = (s % 3_000_000) as i32;
+        TestResult::from_bool(date_to_serial_1900(serial_1900_to_date(serial)) == serial)
+    }
+
+    fn quickcheck_date_to_serial_1900(d: i32) -> TestResult {
+        let rd = date_to_rd((1900, 1, 1)) + (d % 1_000_000).abs();
+        let date = rd_to_date(rd);
+        let serial = date_to_serial_1900(date);
+        TestResult::from_bool(serial_1900_to_date(serial) == date && (serial <= 60 || serial - 1 == rd - date_to_rd((1899, 12, 31))))
+    }
+
+    fn quickcheck_serial_1904_to_date(s: i32) -> TestResult {
+        let serial = s % 100_000_000;
+        TestResult::from_bool(date_to_serial_1904(serial_1904_to_date(serial)) == serial)
+    }
+
+    fn quickcheck_serial_1900_to_datetime(s: u32, ms: u32) -> TestResult {
+        let days = (s % 3_000_000) as i32;
+        let ms = ms % 86_400_000;
+        let serial = days as f64 + ms as f64 / 86_400_000.0;
+        let (y, m, d, hh, mm, ss, nsec) = serial_1900_to_datetime(serial);
+        let ok = date_to_serial_1900((y, m, d)) == days
+            && ((hh as u32 * 60 + mm as u32) * 60 + ss as u32) * 1000 + nsec / 1_000_000 == ms
+            && nsec % 1_000_000 == 0
+            && datetime_to_serial_1900((y, m, d, hh, mm, ss, nsec)) == serial;
+        TestResult::from_bool(ok)
+    }
+
+    fn quickcheck_serial_1904_to_datetime(s: i32, ms: u32) -> TestResult {
+        let days = s % 3_000_000;
+        let ms = ms % 86_400_000;
+        let serial = days as f64 + ms as f64 / 86_400_000.0;
+        let dt = serial_1904_to_datetime(serial);
+        TestResult::from_bool(date_to_serial_1904((dt.0, dt.1, dt.2)) == days && datetime_to_serial_1904(dt) == serial)
+    }
+}
@@ -72,6 +72,36 @@ quickcheck! {
         TestResult::passed()
     }
 
+    fn quickcheck_weekday_on_or_after(d: Val<-536895152, 536824288>, wd: Val<1, 7>) -> TestResult {
+        let n = datealgo::weekday_on_or_after(d.i32(), wd.u8());
+        assert!(n >= d.i32() && n < d.i32() + 7);
+        assert_eq!(datealgo::rd_to_weekday(n), wd.u8());
+        assert_eq!(datealgo::next_weekday(d.i32(), wd.u8()), if n == d.i32() { n + 7 } else { n });
+        TestResult::passed()
+    }
+
+    fn quickcheck_weekday_on_or_before(d: Val<-536895145, 536824295>, wd: Val<1, 7>) -> TestResult {
+        let p = datealgo::weekday_on_or_before(d.i32(), wd.u8());
+        assert!(p <= d.i32() && p > d.i32() - 7);
+        assert_eq!(datealgo::rd_to_weekday(p), wd.u8());
+        assert_eq!(datealgo::prev_weekday(d.i32(), wd.u8()), if p == d.i32() { p - 7 } else { p });
+        TestResult::passed()
+    }
+
+    fn quickcheck_nth_weekday_of_month(y: Val<-1467999, 1471744>, m: Val<1, 12>, wd: Val<1, 7>, n: Val<1, 5>) -> TestResult {
+        let last = datealgo::last_weekday_of_month(y.i32(), m.u8(), wd.u8());
+        assert!(last + 7 > datealgo::days_in_month(y.i32(), m.u8()));
+        assert_eq!(datealgo::date_to_weekday((y.i32(), m.u8(), last)), wd.u8());
+        match datealgo::nth_weekday_of_month(y.i32(), m.u8(), wd.u8(), n.u8()) {
+            Some(d) => {
+                assert!(d <= last && (d - 1) / 7 + 1 == n.u8());
+                assert_eq!(datealgo::date_to_weekday((y.i32(), m.u8(), d)), wd.u8());
+            }
+            None => assert!(n.u8() == 5 && last <= 28),
+        }
+        TestResult::passed()
+    }
+
     fn quickcheck_next_date(y: Val<-1467999, 1471744>, m: Val<1, 12>, d: Val<1, 31>) -> TestResult {
         if d.u8() > datealgo::days_in_month(y.i32(), m.u8()) {
             return TestResult::discard();
@@ -100,6 +130,72 @@ quickcheck! {
         TestResult::passed()
     }
 
+    fn quickcheck_add_months(y: Val<-1467999, 1471744>, m: Val<1, 12>, d: Val<1, 31>, n: Val<-2147483648, 2147483647>, shift: Val<0, 31>) -> TestResult {
+        if d.u8() > datealgo::days_in_month(y.i32(), m.u8()) {
+            return TestResult::discard();
+        }
+        let date = (y.i32(), m.u8(), d.u8());
+        // exercise both small and large deltas
+        let n = n.i32() >> shift.u32();
+        let clamp = datealgo::add_months(date, n, datealgo::DayOverflow::Clamp);
+        let roll = datealgo::add_months(date, n, datealgo::DayOverflow::RollOver);
+        let reject = datealgo::add_months(date, n, datealgo::DayOverflow::Reject);
+        let Some((cy, cm, cd)) = clamp else {
+            assert!(roll.is_none() && reject.is_none());
+            let months = y.i64() * 12 + m.i64() - 1 + n as i64;
+            assert!(months < datealgo::YEAR_MIN as i64 * 12 || months >= (datealgo::YEAR_MAX as i64 + 1) * 12);
+            return TestResult::passed();
+        };
+        assert!((datealgo::YEAR_MIN..=datealgo::YEAR_MAX).contains(&cy));
+        assert_eq!(cy as i64 * 12 + cm as i64, y.i64() * 12 + m.i64() + n as i64);
+        assert_eq!(cd, d.u8().min(datealgo::days_in_month(cy, cm)));
+        let (ry, rm, rd) = roll.unwrap();
+        assert_eq!(datealgo::date_to_rd((ry, rm, rd)), datealgo::date_to_rd((cy, cm, 1)) + d.i32() - 1);
+        assert_eq!(reject, if cd == d.u8() { clamp } else { None });
+        if reject.is_some() {
+            assert_eq!(datealgo::add_months(reject.unwrap(), -n, datealgo::DayOverflow::Reject), Some(date));
+        }
+        TestResult::passed()
+    }
+
+    fn quickcheck_add_years(y: Val<-1467999, 1471744>, m: Val<1, 12>, d: Val<1, 31>, n: Val<-2147483648, 2147483647>, shift: Val<0, 31>) -> TestResult {
+        if d.u8() > datealgo::days_in_month(y.i32(), m.u8()) {
+            return TestResult::discard();
+        }
+        let date = (y.i32(), m.u8(), d.u8());
+        let n = n.i32() >> shift.u32();
+        for overflow in [datealgo::DayOverflow::Clamp, datealgo::DayOverflow::RollOver, datealgo::DayOverflow::Reject] {
+            let expected = if n.unsigned_abs() <= i32::MAX as u32 / 12 {
+                datealgo::add_months(date, n * 12, overflow)
+            } else {
+                None
+            };
+            assert_eq!(datealgo::add_years(date, n, overflow), expected);
+        }
+        TestResult::passed()
+    }
+
+    fn quickcheck_date_diff_ymd(ya: Val<-1467999, 1471744>, ma: Val<1, 12>, da: Val<1, 31>, yb: Val<-1467999, 1471744>, mb: Val<1, 12>, db: Val<1, 31>) -> TestResult {
+        if da.u8() > datealgo::days_in_month(ya.i32(), ma.u8()) || db.u8() > datealgo::days_in_month(yb.i32(), mb.u8()) {
+            return TestResult::discard();
+        }
+        let a = (ya.i32(), ma.u8(), da.u8());
+        let b = (yb.i32(), mb.u8(), db.u8());
+        let (y, m, d) = datealgo::date_diff_ymd(a, b);
+        let n = datealgo::months_between(a, b);
+        assert!(m > -12 && m < 12 && d > -31 && d < 31);
+        assert!((y >= 0 && m >= 0 && d >= 0) || (y <= 0 && m <= 0 && d <= 0));
+        assert_eq!(y * 12 + m as i32, n);
+        assert_eq!(datealgo::whole_years_between(a, b), y);
+        let anchor = datealgo::add_months(a, n, datealgo::DayOverflow::Clamp).unwrap();
+        assert_eq!(datealgo::date_to_rd(anchor) + d as i32, datealgo::date_to_rd(b));
+        if d != 0 {
+            let next = datealgo::add_months(a, n + d.signum() as i32, datealgo::DayOverflow::Clamp);
+            assert!(next.is_none_or(|next| (datealgo::date_to_rd(next) - datealgo::date_to_rd(b)).signum() == d.signum() as i32));
+        }
+        TestResult::passed()
+    }
+
     fn quickcheck_secs_to_dhms(s: Val<-46387741132800, 46381619174399 >) -> TestResult {
         let (d, h, m, s) = datealgo::secs_to_dhms(s.i64());
         assert!(d >= datealgo::RD_MIN && d <= datealgo::RD_MAX);
@@ -194,6 +290,126 @@ quickcheck! {
         TestResult::passed()
     }
 
+    fn quickcheck_days_in_year(y: Val<-1467999, 1471744>) -> TestResult {
+        let days = datealgo::days_in_year(y.i32());
+        assert!((365..=366).contains(&days));
+        TestResult::passed()
+    }
+
+    fn quickcheck_rd_to_ordinal(d: Val<-536895152, 536824295>) -> TestResult {
+        let (y, o) = datealgo::rd_to_ordinal(d.i32());
+        assert!((datealgo::YEAR_MIN..=datealgo::YEAR_MAX).contains(&y));
+        assert!(o >= 1 && o <= datealgo::days_in_year(y));
+        assert_eq!(datealgo::ordinal_to_rd((y, o)), d.i32());
+        TestResult::passed()
+    }
+
+    fn quickcheck_ordinal_to_rd(y: Val<-1467999, 1471744>, o: Val<1, 366>) -> TestResult {
+        if o.u32() > datealgo::days_in_year(y.i32()) as u32 {
+            return TestResult::discard();
+        }
+        let rd = datealgo::ordinal_to_rd((y.i32(), o.u32() as u16));
+        assert!((datealgo::RD_MIN..=datealgo::RD_MAX).contains(&rd));
+        assert_eq!(datealgo::rd_to_ordinal(rd), (y.i32(), o.u32() as u16));
+        TestResult::passed()
+    }
+
+    fn quickcheck_date_to_ordinal(y: Val<-1467999, 1471744>, m: Val<1, 12>, d: Val<1, 31>) -> TestResult {
+        if d.u8() > datealgo::days_in_month(y.i32(), m.u8()) {
+            return TestResult::discard();
+        }
+        let (oy, o) = datealgo::date_to_ordinal((y.i32(), m.u8(), d.u8()));
+        assert_eq!(oy, y.i32());
+        assert!(o >= 1 && o <= datealgo::days_in_year(oy));
+        assert_eq!(datealgo::ordinal_to_date((oy, o)), (y.i32(), m.u8(), d.u8()));
+        TestResult::passed()
+    }
+
+    fn quickcheck_ordinal_to_date(y: Val<-1467999, 1471744>, o: Val<1, 366>) -> TestResult {
+        if o.u32() > datealgo::days_in_year(y.i32()) as u32 {
+            return TestResult::discard();
+        }
+        let (dy, dm, dd) = datealgo::ordinal_to_date((y.i32(), o.u32() as u16));
+        assert_eq!(dy, y.i32());
+        assert!((datealgo::consts::MONTH_MIN..=datealgo::consts::MONTH_MAX).contains(&dm));
+        assert!(dd >= datealgo::consts::DAY_MIN && dd <= datealgo::days_in_month(dy, dm));
+        assert_eq!(datealgo::ordinal_to_rd((y.i32(), o.u32() as u16)), datealgo::date_to_rd((dy, dm, dd)));
+        TestResult::passed()
+    }
+
+    fn quickcheck_rd_to_julian_date(d: Val<-536894842, 536824008>) -> TestResult {
+        let (y, m, d) = datealgo::rd_to_julian_date(d.i32());
+        assert!((datealgo::JULIAN_YEAR_MIN..=datealgo::JULIAN_YEAR_MAX).contains(&y));
+        assert!((datealgo::consts::MONTH_MIN..=datealgo::consts::MONTH_MAX).contains(&m));
+        assert!(d >= datealgo::consts::DAY_MIN && d <= datealgo::julian_days_in_month(y, m));
+        TestResult::passed()
+    }
+
+    fn quickcheck_julian_date_to_rd(y: Val<-1467968, 1471713>, m: Val<1, 12>, d: Val<1, 31>) -> TestResult {
+        if d.u8() > datealgo::julian_days_in_month(y.i32(), m.u8()) {
+            return TestResult::discard();
+        }
+        let rd = datealgo::julian_date_to_rd((y.i32(), m.u8(), d.u8()));
+        assert!((datealgo::JULIAN_RD_MIN..=datealgo::JULIAN_RD_MAX).contains(&rd));
+        assert_eq!(datealgo::rd_to_julian_date(rd), (y.i32(), m.u8(), d.u8()));
+        TestResult::passed()
+    }
+
+    fn quickcheck_rd_to_historical_date(d: Val<-536894842, 536824295>) -> TestResult {
+        let (y, m, day) = datealgo::rd_to_historical_date(d.i32(), datealgo::reform::GREAT_BRITAIN);
+        assert!((datealgo::consts::DAY_MIN..=datealgo::consts::DAY_MAX).contains(&day));
+        assert_eq!(datealgo::historical_date_to_rd((y, m, day), datealgo::reform::GREAT_BRITAIN), d.i32());
+        TestResult::passed()
+    }
+
+    fn quickcheck_historical_days_in_month(y: Val<1000, 2000>, m: Val<1, 12>) -> TestResult {
+        let reform = datealgo::reform::RUSSIA;
+        let (ny, nm) = if m.u8() == 12 { (y.i32() + 1, 1) } else { (y.i32(), m.u8() + 1) };
+        let start = datealgo::date_to_rd((y.i32(), m.u8(), 1));
+        let end = datealgo::julian_date_to_rd((ny, nm, 1));
+        let days = (start..end).filter(|&n| datealgo::rd_to_historical_date(n, reform) < (ny, nm, 1) && datealgo::rd_to_historical_date(n, reform) >= (y.i32(), m.u8(), 1)).count();
+        assert_eq!(datealgo::historical_days_in_month(y.i32(), m.u8(), reform) as usize, days);
+        TestResult::passed()
+    }
+
+    fn quickcheck_secs_to_jd_nanos(s: Val<-46387741132800, 46381619174399 >, nsecs: Val<0, 999_999_999>) -> TestResult {
+        let (jdn, nanos) = datealgo::secs_to_jd_nanos((s.i64(), nsecs.u32()));
+        assert!(nanos < 86_400_000_000_000);
+        assert_eq!(datealgo::jd_nanos_to_secs((jdn, nanos)), (s.i64(), nsecs.u32()));
+        TestResult::passed()
+    }
+
+    fn quickcheck_jd_to_secs(s: Val<-46387741132800, 46381619174399 >, nsecs: Val<0, 999_999_999>) -> TestResult {
+        let jd = datealgo::secs_to_jd((s.i64(), nsecs.u32()));
+        let (secs, ns) = datealgo::jd_to_secs(jd);
+        let diff = (secs as i128 * 1_000_000_000 + ns as i128) - (s.0 * 1_000_000_000 + nsecs.0);
+        assert!(diff.abs() <= 10_000_000);
+        TestResult::passed()
+    }
+
+    fn quickcheck_millis_to_datetime(ms: Val<-46387741132800000, 46381619174399999>) -> TestResult {
+        let (y, m, d, h, min, sec, nsec) = datealgo::millis_to_datetime(ms.i64());
+        assert_eq!(nsec % 1_000_000, 0);
+        assert_eq!(datealgo::datetime_to_secs((y, m, d, h, min, sec)), ms.i64().div_euclid(1000));
+        assert_eq!(datealgo::datetime_to_millis((y, m, d, h, min, sec, nsec)), ms.i64());
+        TestResult::passed()
+    }
+
+    fn quickcheck_micros_to_datetime(us: i64) -> TestResult {
+        let (y, m, d, h, min, sec, nsec) = datealgo::micros_to_datetime(us);
+        assert_eq!(nsec % 1_000, 0);
+        assert_eq!(datealgo::datetime_to_secs((y, m, d, h, min, sec)), us.div_euclid(1_000_000));
+        assert_eq!(datealgo::datetime_to_micros((y, m, d, h, min, sec, nsec)), us);
+        TestResult::passed()
+    }
+
+    fn quickcheck_nanos_to_datetime(ns: Val<-46387741132800000000000, 46381619174399999999999>) -> TestResult {
+        let (y, m, d, h, min, sec, nsec) = datealgo::nanos_to_datetime(ns.0);
+        assert_eq!(datealgo::datetime_to_secs((y, m, d, h, min, sec)) as i128, ns.0.div_euclid(1_000_000_000));
+        assert_eq!(datealgo::datetime_to_nanos((y, m, d, h, min, sec, nsec)), ns.0);
+        TestResult::passed()
+    }
+
     fn quickcheck_systemtime_to_secs(st: SystemTime) -> TestResult {
         let (secs, nsecs) = datealgo::systemtime_to_secs(st).unwrap();
         assert!(secs >= datealgo::RD_SECONDS_MIN && secs <= datealgo::RD_SECONDS_MAX);
@@ -228,3 +444,59 @@ quickcheck! {
         TestResult::passed()
     }
 }
+
+quickcheck! {
+    fn quickcheck_checked_rd_to_date(d: Val<-600000000, 600000000>) -> TestResult {
+        let r = datealgo::checked_rd_to_date(d.i32());
+        if d.i32() >= datealgo::RD_MIN && d.i32() <= datealgo::RD_MAX {
+            assert_eq!(r, Some(datealgo::rd_to_date(d.i32())));
+        } else {
+            assert_eq!(r, None);
+        }
+        TestResult::passed()
+    }
+
+    fn quickcheck_checked_date_to_rd(y: Val<-1500000, 1500000>, m: Val<0, 13>, d: Val<0, 32>) -> TestResult {
+        let r = datealgo::checked_date_to_rd((y.i32(), m.u8(), d.u8()));
+        let valid = y.i32() >= datealgo::YEAR_MIN
+            && y.i32() <= datealgo::YEAR_MAX
+            && m.u8() >= datealgo::consts::MONTH_MIN
+            && m.u8() <= datealgo::consts::MONTH_MAX
+            && d.u8() >= datealgo::consts::DAY_MIN
+            && d.u8() <= datealgo::days_in_month(y.i32(), m.u8());
+        if valid {
+            assert_eq!(r, Some(datealgo::date_to_rd((y.i32(), m.u8(), d.u8()))));
+        } else {
+            assert_eq!(r, None);
+        }
+        TestResult::passed()
+    }
+
+    fn quickcheck_checked_datetime_to_secs(y: Val<-1500000, 1500000>, m: Val<1, 12>, d: Val<1, 31>, h: Val<0, 24>, min: Val<0, 60>, sec: Val<0, 60>) -> TestResult {
+        let r = datealgo::checked_datetime_to_secs((y.i32(), m.u8(), d.u8(), h.u8(), min.u8(), sec.u8()));
+        let valid = y.i32() >= datealgo::YEAR_MIN
+            && y.i32() <= datealgo::YEAR_MAX
+            && d.u8() <= datealgo::days_in_month(y.i32(), m.u8())
+            && h.u8() <= datealgo::consts::HOUR_MAX
+            && min.u8() <= datealgo::consts::MINUTE_MAX
+            && sec.u8() <= datealgo::consts::SECOND_MAX;
+        if valid {
+            assert_eq!(r, Some(datealgo::datetime_to_secs((y.i32(), m.u8(), d.u8(), h.u8(), min.u8(), sec.u8()))));
+        } else {
+            assert_eq!(r, None);
+        }
+        TestResult::passed()
+    }
+}
+
+quickcheck! {
+    fn quickcheck_validate_isoweekdate(y: Val<-1500000, 1500000>, w: Val<0, 54>, wd: Val<0, 8>) -> TestResult {
+        let r = datealgo::validate_isoweekdate((y.i32(), w.u8(), wd.u8()));
+        if r.is_ok() {
+            let rd = datealgo::isoweekdate_to_rd((y.i32(), w.u8(), wd.u8()));
+            assert!((datealgo::RD_MIN..=datealgo::RD_MAX).contains(&rd));
+            assert_eq!(datealgo::rd_to_isoweekdate(rd), (y.i32(), w.u8(), wd.u8()));
+        }
+        TestResult::passed()
+    }
+}
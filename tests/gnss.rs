@@ -0,0 +1,168 @@
+use datealgo::gnss::*;
+use datealgo::leapsec::LEAP_SECONDS;
+use datealgo::{date_to_rd, datetime_to_secs, rd_to_weekday, RD_MAX, RD_MIN};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_epochs() {
+    assert_eq!(GPS_EPOCH, 315964800);
+    assert_eq!(GST_EPOCH, 935279987);
+    assert_eq!(BDT_EPOCH, 1136073600);
+    assert_eq!(utc_secs_to_gps_secs(GST_EPOCH, LEAP_SECONDS), week_tow_to_secs((1024, 0)));
+    assert_eq!(utc_secs_to_gps_secs(BDT_EPOCH, LEAP_SECONDS), week_tow_to_secs((1356, 14)));
+}
+
+#[test]
+fn test_secs_to_week_tow() {
+    assert_eq!(secs_to_week_tow(0), (0, 0));
+    assert_eq!(secs_to_week_tow(604799), (0, 604799));
+    assert_eq!(secs_to_week_tow(604800), (1, 0));
+    assert_eq!(secs_to_week_tow(-1), (-1, 604799));
+    assert_eq!(secs_to_week_tow(-604800), (-1, 0));
+    assert_eq!(secs_to_week_tow(-604801), (-2, 604799));
+}
+
+#[test]
+fn test_week_tow_to_secs() {
+    assert_eq!(week_tow_to_secs((0, 0)), 0);
+    assert_eq!(week_tow_to_secs((0, 604799)), 604799);
+    assert_eq!(week_tow_to_secs((1, 0)), 604800);
+    assert_eq!(week_tow_to_secs((-1, 604799)), -1);
+    assert_eq!(week_tow_to_secs((-2, 604799)), -604801);
+}
+
+#[test]
+fn test_resolve_week() {
+    assert_eq!(resolve_week(0, 10, 0), 0);
+    assert_eq!(resolve_week(1023, 10, 0), 1023);
+    assert_eq!(resolve_week(0, 10, 1), 1024);
+    assert_eq!(resolve_week(0, 10, 1024), 1024);
+    assert_eq!(resolve_week(1023, 10, 1024), 2047);
+    assert_eq!(resolve_week(0, 13, 1), 8192);
+    assert_eq!(resolve_week(5, 12, -10), 5);
+    assert_eq!(resolve_week(65535, 16, 0), 65535);
+    // second GPS week rollover on 2019-04-07
+    let reference = rd_to_gps_week(date_to_rd((2019, 4, 7)));
+    assert_eq!(reference, 2048);
+    assert_eq!(resolve_week(0, 10, reference), 2048);
+    assert_eq!(resolve_week(1023, 10, reference), 3071);
+}
+
+#[test]
+fn test_rd_to_gps_week() {
+    assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 5))), -1);
+    assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 6))), 0);
+    assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 12))), 0);
+    assert_eq!(rd_to_gps_week(date_to_rd((1980, 1, 13))), 1);
+    assert_eq!(rd_to_gps_week(date_to_rd((1999, 8, 21))), 1023);
+    assert_eq!(rd_to_gps_week(date_to_rd((1999, 8, 22))), 1024);
+    assert_eq!(rd_to_gps_week(0), -523);
+    assert_eq!(rd_to_gps_week(RD_MIN), (RD_MIN - 1 - date_to_rd((1980, 1, 6))) / 7);
+    assert_eq!(rd_to_gps_week(RD_MAX), (RD_MAX - 4 - date_to_rd((1980, 1, 6))) / 7);
+}
+
+#[test]
+fn test_rd_to_gst_week() {
+    assert_eq!(rd_to_gst_week(date_to_rd((1999, 8, 21))), -1);
+    assert_eq!(rd_to_gst_week(date_to_rd((1999, 8, 22))), 0);
+}
+
+#[test]
+fn test_rd_to_bdt_week() {
+    assert_eq!(rd_to_bdt_week(date_to_rd((2005, 12, 31))), -1);
+    assert_eq!(rd_to_bdt_week(date_to_rd((2006, 1, 1))), 0);
+}
+
+#[test]
+fn test_gps_secs() {
+    assert_eq!(utc_secs_to_gps_secs(GPS_EPOCH, LEAP_SECONDS), 0);
+    assert_eq!(
+        utc_secs_to_gps_secs(datetime_to_secs((2016, 12, 31, 23, 59, 59)), LEAP_SECONDS),
+        week_tow_to_secs((1930, 16))
+    );
+    assert_eq!(
+        utc_secs_to_gps_secs(datetime_to_secs((2017, 1, 1, 0, 0, 0)), LEAP_SECONDS),
+        week_tow_to_secs((1930, 18))
+    );
+    assert_eq!(gps_secs_to_utc_secs(0, LEAP_SECONDS), GPS_EPOCH);
+    assert_eq!(
+        gps_secs_to_utc_secs(week_tow_to_secs((1930, 17)), LEAP_SECONDS),
+        datetime_to_secs((2017, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(gps_secs_to_datetime(-1, LEAP_SECONDS), (1980, 1, 5, 23, 59, 59));
+    assert_eq!(
+        gps_secs_to_datetime(week_tow_to_secs((1930, 16)), LEAP_SECONDS),
+        (2016, 12, 31, 23, 59, 59)
+    );
+    assert_eq!(
+        gps_secs_to_datetime(week_tow_to_secs((1930, 17)), LEAP_SECONDS),
+        (2016, 12, 31, 23, 59, 60)
+    );
+    assert_eq!(
+        gps_secs_to_datetime(week_tow_to_secs((1930, 18)), LEAP_SECONDS),
+        (2017, 1, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        datetime_to_gps_secs((2016, 12, 31, 23, 59, 60), LEAP_SECONDS),
+        week_tow_to_secs((1930, 17))
+    );
+}
+
+#[test]
+fn test_gst_secs() {
+    assert_eq!(utc_secs_to_gst_secs(GST_EPOCH, LEAP_SECONDS), 0);
+    assert_eq!(gst_secs_to_utc_secs(0, LEAP_SECONDS), GST_EPOCH);
+    assert_eq!(
+        gst_secs_to_datetime(week_tow_to_secs((906, 17)), LEAP_SECONDS),
+        (2016, 12, 31, 23, 59, 60)
+    );
+    assert_eq!(
+        datetime_to_gst_secs((2016, 12, 31, 23, 59, 60), LEAP_SECONDS),
+        week_tow_to_secs((906, 17))
+    );
+}
+
+#[test]
+fn test_bdt_secs() {
+    assert_eq!(utc_secs_to_bdt_secs(BDT_EPOCH, LEAP_SECONDS), 0);
+    assert_eq!(bdt_secs_to_utc_secs(0, LEAP_SECONDS), BDT_EPOCH);
+    assert_eq!(
+        bdt_secs_to_datetime(week_tow_to_secs((574, 3)), LEAP_SECONDS),
+        (2016, 12, 31, 23, 59, 60)
+    );
+    assert_eq!(
+        datetime_to_bdt_secs((2016, 12, 31, 23, 59, 60), LEAP_SECONDS),
+        week_tow_to_secs((574, 3))
+    );
+}
+
+quickcheck! {
+    fn quickcheck_secs_to_week_tow(s: i64) -> TestResult {
+        if s.unsigned_abs() > 1 << 50 {
+            return TestResult::discard();
+        }
+        let (week, tow) = secs_to_week_tow(s);
+        TestResult::from_bool(tow < SECS_IN_WEEK && week_tow_to_secs((week, tow)) == s)
+    }
+
+    fn quickcheck_rd_to_gps_week(d: i32) -> TestResult {
+        let rd = date_to_rd((1980, 1, 6)) + d % 1_000_000;
+        let week = rd_to_gps_week(rd);
+        let start = date_to_rd((1980, 1, 6)) + week * 7;
+        TestResult::from_bool(start <= rd && rd < start + 7 && rd_to_weekday(start) == 7)
+    }
+
+    fn quickcheck_resolve_week(week: u16, bits: u8, reference: i32) -> TestResult {
+        let bits = bits % 16 + 1;
+        let week = week & ((1u32 << bits) - 1) as u16;
+        let reference = reference % 1_000_000;
+        let full = resolve_week(week, bits, reference);
+        TestResult::from_bool(full >= reference && full - reference < (1 << bits) && full.rem_euclid(1 << bits) == week as i32)
+    }
+
+    fn quickcheck_gps_secs_to_datetime(s: u32) -> TestResult {
+        let gps = s as i64;
+        TestResult::from_bool(datetime_to_gps_secs(gps_secs_to_datetime(gps, LEAP_SECONDS), LEAP_SECONDS) == gps)
+    }
+}
@@ -0,0 +1,324 @@
+use datealgo::consts::*;
+use datealgo::holiday::*;
+use datealgo::*;
+
+use quickcheck::quickcheck;
+
+fn holidays(y: i32, rules: &[HolidayRule]) -> Vec<((i32, u8, u8), &'static str, bool)> {
+    let mut out = [(0, "", false); 64];
+    let len = holidays_in_year(y, rules, &mut out).unwrap();
+    out[..len]
+        .iter()
+        .map(|&(rd, name, substitute)| (rd_to_date(rd), name, substitute))
+        .collect()
+}
+
+fn dates(y: i32, rules: &[HolidayRule]) -> Vec<(u8, u8)> {
+    holidays(y, rules).into_iter().map(|((_, m, d), _, _)| (m, d)).collect()
+}
+
+#[test]
+fn test_us_federal() {
+    assert_eq!(
+        holidays(2021, US_FEDERAL),
+        [
+            ((2021, 1, 1), "New Year's Day", false),
+            ((2021, 1, 18), "Birthday of Martin Luther King, Jr.", false),
+            ((2021, 2, 15), "Washington's Birthday", false),
+            ((2021, 5, 31), "Memorial Day", false),
+            ((2021, 6, 18), "Juneteenth National Independence Day", true),
+            ((2021, 6, 19), "Juneteenth National Independence Day", false),
+            ((2021, 7, 4), "Independence Day", false),
+            ((2021, 7, 5), "Independence Day", true),
+            ((2021, 9, 6), "Labor Day", false),
+            ((2021, 10, 11), "Columbus Day", false),
+            ((2021, 11, 11), "Veterans Day", false),
+            ((2021, 11, 25), "Thanksgiving Day", false),
+            ((2021, 12, 24), "Christmas Day", true),
+            ((2021, 12, 25), "Christmas Day", false),
+            ((2021, 12, 31), "New Year's Day", true),
+        ]
+    );
+    assert_eq!(
+        dates(2022, US_FEDERAL),
+        [
+            (1, 1),
+            (1, 17),
+            (2, 21),
+            (5, 30),
+            (6, 19),
+            (6, 20),
+            (7, 4),
+            (9, 5),
+            (10, 10),
+            (11, 11),
+            (11, 24),
+            (12, 25),
+            (12, 26)
+        ]
+    );
+    assert_eq!(dates(2020, US_FEDERAL).len(), 11);
+    assert_eq!(dates(1985, US_FEDERAL).len(), 9);
+    assert_eq!(dates(1975, US_FEDERAL)[6], (10, 27));
+    assert_eq!(dates(1970, US_FEDERAL), []);
+    assert!(is_holiday(date_to_rd((2023, 11, 23)), US_FEDERAL));
+    assert!(is_holiday(date_to_rd((2021, 12, 31)), US_FEDERAL));
+    assert!(is_holiday(date_to_rd((2023, 11, 10)), US_FEDERAL));
+    assert!(!is_holiday(date_to_rd((2023, 11, 24)), US_FEDERAL));
+    assert!(!is_holiday(date_to_rd((2020, 6, 19)), US_FEDERAL));
+}
+
+#[test]
+fn test_england_and_wales() {
+    assert_eq!(
+        holidays(2022, ENGLAND_AND_WALES),
+        [
+            ((2022, 1, 1), "New Year's Day", false),
+            ((2022, 1, 3), "New Year's Day", true),
+            ((2022, 4, 15), "Good Friday", false),
+            ((2022, 4, 18), "Easter Monday", false),
+            ((2022, 5, 2), "Early May bank holiday", false),
+            ((2022, 6, 2), "Spring bank holiday", false),
+            ((2022, 6, 3), "Platinum Jubilee of Elizabeth II", false),
+            ((2022, 8, 29), "Summer bank holiday", false),
+            ((2022, 9, 19), "State Funeral of Queen Elizabeth II", false),
+            ((2022, 12, 25), "Christmas Day", false),
+            ((2022, 12, 26), "Boxing Day", false),
+            ((2022, 12, 27), "Christmas Day", true),
+        ]
+    );
+    assert_eq!(
+        holidays(2021, ENGLAND_AND_WALES)[6..],
+        [
+            ((2021, 12, 25), "Christmas Day", false),
+            ((2021, 12, 26), "Boxing Day", false),
+            ((2021, 12, 27), "Christmas Day", true),
+            ((2021, 12, 28), "Boxing Day", true),
+        ]
+    );
+    assert_eq!(
+        dates(2020, ENGLAND_AND_WALES),
+        [(1, 1), (4, 10), (4, 13), (5, 8), (5, 25), (8, 31), (12, 25), (12, 26), (12, 28)]
+    );
+    assert_eq!(
+        dates(2023, ENGLAND_AND_WALES),
+        [
+            (1, 1),
+            (1, 2),
+            (4, 7),
+            (4, 10),
+            (5, 1),
+            (5, 8),
+            (5, 29),
+            (8, 28),
+            (12, 25),
+            (12, 26)
+        ]
+    );
+    assert_eq!(
+        dates(1999, ENGLAND_AND_WALES)[6..],
+        [(12, 25), (12, 26), (12, 27), (12, 28), (12, 31)]
+    );
+    assert_eq!(dates(2000, ENGLAND_AND_WALES)[..2], [(1, 1), (1, 3)]);
+    assert_eq!(dates(2002, ENGLAND_AND_WALES)[4..6], [(6, 3), (6, 4)]);
+    assert_eq!(dates(2012, ENGLAND_AND_WALES)[5..7], [(6, 4), (6, 5)]);
+    assert_eq!(dates(1995, ENGLAND_AND_WALES)[4], (5, 8));
+    assert_eq!(dates(1977, ENGLAND_AND_WALES), []);
+    assert!(is_holiday(date_to_rd((2022, 12, 27)), ENGLAND_AND_WALES));
+    assert!(is_holiday(date_to_rd((2011, 4, 29)), ENGLAND_AND_WALES));
+    assert!(!is_holiday(date_to_rd((2022, 5, 30)), ENGLAND_AND_WALES));
+    assert!(!is_holiday(date_to_rd((2020, 5, 4)), ENGLAND_AND_WALES));
+}
+
+#[test]
+fn test_germany() {
+    assert_eq!(
+        holidays(2017, GERMANY),
+        [
+            ((2017, 1, 1), "New Year's Day", false),
+            ((2017, 4, 14), "Good Friday", false),
+            ((2017, 4, 17), "Easter Monday", false),
+            ((2017, 5, 1), "Labour Day", false),
+            ((2017, 5, 25), "Ascension Day", false),
+            ((2017, 6, 5), "Whit Monday", false),
+            ((2017, 10, 3), "Day of German Unity", false),
+            ((2017, 10, 31), "Reformation Day", false),
+            ((2017, 12, 25), "Christmas Day", false),
+            ((2017, 12, 26), "Second Day of Christmas", false),
+        ]
+    );
+    assert_eq!(dates(1994, GERMANY)[7], (11, 16));
+    assert_eq!(dates(1990, GERMANY)[7], (11, 21));
+    assert_eq!(dates(1995, GERMANY).len(), 9);
+    assert_eq!(dates(1989, GERMANY), []);
+    assert!(is_holiday(date_to_rd((2024, 5, 20)), GERMANY));
+    assert!(!is_holiday(date_to_rd((2024, 10, 31)), GERMANY));
+}
+
+#[test]
+fn test_finland() {
+    assert_eq!(
+        holidays(2024, FINLAND),
+        [
+            ((2024, 1, 1), "New Year's Day", false),
+            ((2024, 1, 6), "Epiphany", false),
+            ((2024, 3, 29), "Good Friday", false),
+            ((2024, 3, 31), "Easter Day", false),
+            ((2024, 4, 1), "Easter Monday", false),
+            ((2024, 5, 1), "May Day", false),
+            ((2024, 5, 9), "Ascension Day", false),
+            ((2024, 5, 19), "Whitsunday", false),
+            ((2024, 6, 22), "Midsummer Day", false),
+            ((2024, 11, 2), "All Saints' Day", false),
+            ((2024, 12, 6), "Independence Day", false),
+            ((2024, 12, 25), "Christmas Day", false),
+            ((2024, 12, 26), "St. Stephen's Day", false),
+        ]
+    );
+    assert_eq!(
+        dates(1980, FINLAND),
+        [
+            (1, 1),
+            (1, 12),
+            (4, 4),
+            (4, 6),
+            (4, 7),
+            (5, 1),
+            (5, 17),
+            (5, 25),
+            (6, 21),
+            (11, 1),
+            (12, 6),
+            (12, 25),
+            (12, 26)
+        ]
+    );
+    assert_eq!(dates(2025, FINLAND)[8..10], [(6, 21), (11, 1)]);
+    assert_eq!(dates(1954, FINLAND), []);
+    assert!(is_holiday(date_to_rd((2024, 6, 22)), FINLAND));
+    assert!(!is_holiday(date_to_rd((2024, 6, 21)), FINLAND));
+}
+
+#[test]
+fn test_custom_rules() {
+    const RULES: &[HolidayRule] = &[
+        HolidayRule {
+            name: "Leap Day",
+            date: DateRule::Fixed { month: 2, day: 29 },
+            observed: Observed::NearestWeekday,
+            years: (YEAR_MIN, YEAR_MAX),
+        },
+        HolidayRule {
+            name: "Fifth Friday of January",
+            date: DateRule::NthWeekday {
+                month: 1,
+                weekday: FRIDAY,
+                n: 5,
+            },
+            observed: Observed::Never,
+            years: (YEAR_MIN, YEAR_MAX),
+        },
+        HolidayRule {
+            name: "First Sunday of the Year",
+            date: DateRule::WeekdayOnOrAfter {
+                month: 12,
+                day: 26,
+                weekday: SUNDAY,
+            },
+            observed: Observed::FollowingWeekday,
+            years: (YEAR_MIN, YEAR_MAX),
+        },
+        HolidayRule {
+            name: "Orthodox Easter Monday",
+            date: DateRule::OrthodoxEaster { offset: 1 },
+            observed: Observed::Never,
+            years: (YEAR_MIN, YEAR_MAX),
+        },
+    ];
+    assert_eq!(
+        holidays(2024, RULES),
+        [
+            ((2024, 1, 1), "First Sunday of the Year", true),
+            ((2024, 2, 29), "Leap Day", false),
+            ((2024, 5, 6), "Orthodox Easter Monday", false),
+            ((2024, 12, 29), "First Sunday of the Year", false),
+            ((2024, 12, 30), "First Sunday of the Year", true),
+        ]
+    );
+    assert_eq!(
+        holidays(2023, RULES),
+        [
+            ((2023, 1, 1), "First Sunday of the Year", false),
+            ((2023, 1, 2), "First Sunday of the Year", true),
+            ((2023, 4, 17), "Orthodox Easter Monday", false),
+            ((2023, 12, 31), "First Sunday of the Year", false),
+        ]
+    );
+    assert_eq!(dates(2021, RULES)[0], (1, 29));
+    assert_eq!(dates(2020, RULES)[..3], [(1, 31), (2, 28), (2, 29)]);
+    assert_eq!(dates(2032, RULES)[..3], [(1, 30), (2, 29), (3, 1)]);
+    let mut out = [(0, "", false); 3];
+    assert_eq!(holidays_in_year(2024, RULES, &mut out), None);
+    assert_eq!(holidays_in_year(2024, RULES, &mut []), None);
+    assert_eq!(holidays_in_year(2024, &[], &mut []), Some(0));
+    for y in [YEAR_MIN, YEAR_MAX] {
+        let list = holidays(y, RULES);
+        for rd in date_to_rd((y, 1, 1))..=date_to_rd((y, 12, 31)) {
+            assert_eq!(is_holiday(rd, RULES), list.iter().any(|h| h.0 == rd_to_date(rd)));
+        }
+    }
+    assert!(!is_holiday(RD_MIN, RULES));
+    assert!(!is_holiday(RD_MAX, RULES));
+}
+
+#[test]
+fn test_substitute_chain() {
+    // every substitute day depends on all the earlier ones
+    const NAMES: [&str; 24] = [
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23",
+        "24",
+    ];
+    let rules: Vec<HolidayRule> = NAMES
+        .iter()
+        .map(|&name| HolidayRule {
+            name,
+            date: DateRule::Fixed { month: 12, day: 2 },
+            observed: Observed::FollowingWeekday,
+            years: (YEAR_MIN, YEAR_MAX),
+        })
+        .collect();
+    let list = holidays(2023, &rules);
+    assert_eq!(list.len(), 44);
+    assert_eq!(list[24], ((2023, 12, 4), "1", true));
+    assert_eq!(list[28], ((2023, 12, 8), "5", true));
+    assert_eq!(list[29], ((2023, 12, 11), "6", true));
+    assert_eq!(list[43], ((2023, 12, 29), "20", true));
+    assert_eq!(
+        holidays(2024, &rules)[..4],
+        [
+            ((2024, 1, 1), "21", true),
+            ((2024, 1, 2), "22", true),
+            ((2024, 1, 3), "23", true),
+            ((2024, 1, 4), "24", true)
+        ]
+    );
+    assert!(is_holiday(date_to_rd((2024, 1, 4)), &rules));
+    assert!(!is_holiday(date_to_rd((2024, 1, 5)), &rules));
+    assert!(!is_holiday(date_to_rd((2023, 12, 30)), &rules));
+}
+
+quickcheck! {
+    fn quickcheck_holidays_in_year(y: i32) -> bool {
+        let y = y.rem_euclid(300) + 1900;
+        let (first, last) = (date_to_rd((y, 1, 1)), date_to_rd((y, 12, 31)));
+        [US_FEDERAL, ENGLAND_AND_WALES, GERMANY, FINLAND].iter().all(|rules| {
+            let mut out = [(0, "", false); 64];
+            let len = holidays_in_year(y, rules, &mut out).unwrap();
+            let out = &out[..len];
+            out.windows(2).all(|w| w[0].0 <= w[1].0)
+                && out.iter().all(|&(rd, _, _)| rd >= first && rd <= last)
+                && out.iter().all(|&(rd, _, substitute)| !substitute || rd_to_weekday(rd) <= FRIDAY)
+                && (first..=last).all(|rd| is_holiday(rd, rules) == out.iter().any(|h| h.0 == rd))
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use datealgo::http::*;
+use datealgo::{datetime_to_secs, secs_to_datetime, validate_date, ParseError};
+
+use chrono::DateTime;
+use quickcheck::quickcheck;
+
+mod common;
+
+const YEAR_0: i64 = -62167219200;
+const YEAR_9999: i64 = 253402300799;
+
+// Current time for the mutated dates, in 2023
+const NOW: i64 = 1683451478;
+
+// Either side of the years 1974 and 2073 that RFC 850 two digit years are
+// taken to be in at NOW
+const WINDOW: [i64; 4] = [126230399, 126230400, 3281990399, 3281990400];
+
+fn http_date(secs: i64) -> String {
+    let mut out = [0u8; HTTP_DATE_LEN];
+    format_http_date(secs, &mut out);
+    String::from_utf8(out.to_vec()).unwrap()
+}
+
+#[test]
+fn test_format_http_date() {
+    assert_eq!(http_date(YEAR_0), "Sat, 01 Jan 0000 00:00:00 GMT");
+    assert_eq!(http_date(YEAR_9999), "Fri, 31 Dec 9999 23:59:59 GMT");
+    assert_eq!(http_date(951782400), "Tue, 29 Feb 2000 00:00:00 GMT");
+    assert_eq!(http_date(-1), "Wed, 31 Dec 1969 23:59:59 GMT");
+}
+
+#[test]
+fn test_parse_http_date() {
+    let now = datetime_to_secs((2023, 5, 7, 0, 0, 0));
+    assert_eq!(parse_http_date(b"Sat, 01 Jan 0000 00:00:00 GMT", now), Ok(YEAR_0));
+    assert_eq!(parse_http_date(b"Fri, 31 Dec 9999 23:59:59 GMT", now), Ok(YEAR_9999));
+    assert_eq!(parse_http_date(b" \tsun, 06 NOV 1994 08:49:37 gmt\r\n", now), Ok(784111777));
+    assert_eq!(parse_http_date(b"Mon, 06 Nov 1994 08:49:37 GMT", now), Ok(784111777));
+    assert_eq!(parse_http_date(b"Sun Nov 06 08:49:37 1994", now), Ok(784111777));
+    assert_eq!(parse_http_date(b"Sun Nov 16 08:49:37 1994", now), Ok(784111777 + 10 * 86400));
+    assert_eq!(parse_http_date(b"Sunday, 06-Nov-1994 08:49:37 GMT", now), Ok(784111777));
+    assert_eq!(parse_http_date(b"SUNDAY, 06-nov-94 08:49:37 GMT", now), Ok(784111777));
+    assert_eq!(parse_http_date(b"Sun, 06 Nov 1994 24:00:00 GMT", now), Err(ParseError::OutOfRange));
+    assert_eq!(parse_http_date(b"Sun Nov  0 08:49:37 1994", now), Err(ParseError::OutOfRange));
+    assert_eq!(
+        parse_http_date(b"Sun, 06 Nov 1994 08:49:37 UTC", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(
+        parse_http_date(b"Sun, 6 Nov 1994 08:49:37 GMT", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(parse_http_date(b"Sun, 06 Nov 1994 08:49 GMT", now), Err(ParseError::InvalidFormat));
+    assert_eq!(
+        parse_http_date(b"Sun, 06 Now 1994 08:49:37 GMT", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(
+        parse_http_date(b"Sun, 06 Nov 19x4 08:49:37 GMT", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(parse_http_date(b"Sun, 06-Nov-94 08:49:37 GMT", now), Err(ParseError::InvalidFormat));
+    assert_eq!(
+        parse_http_date(b"Sunday, 06-Nov-994 08:49:37 GMT", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(
+        parse_http_date(b"Sun Nov  6 08:49:37 1994 GMT", now),
+        Err(ParseError::InvalidFormat)
+    );
+    assert_eq!(parse_http_date(b"Sun", now), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_http_date(b"", now), Err(ParseError::InvalidFormat));
+}
+
+#[test]
+fn test_parse_http_date_rfc850_year() {
+    let now = datetime_to_secs((2023, 5, 7, 0, 0, 0));
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-70 00:00:00 GMT", now),
+        Ok(datetime_to_secs((2070, 1, 1, 0, 0, 0)))
+    );
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-73 00:00:00 GMT", now),
+        Ok(datetime_to_secs((2073, 1, 1, 0, 0, 0)))
+    );
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-74 00:00:00 GMT", now),
+        Ok(datetime_to_secs((1974, 1, 1, 0, 0, 0)))
+    );
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-00 00:00:00 GMT", now),
+        Ok(datetime_to_secs((2000, 1, 1, 0, 0, 0)))
+    );
+    let now = datetime_to_secs((2090, 1, 1, 0, 0, 0));
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-10 00:00:00 GMT", now),
+        Ok(datetime_to_secs((2110, 1, 1, 0, 0, 0)))
+    );
+    assert_eq!(
+        parse_http_date(b"Thursday, 01-Jan-41 00:00:00 GMT", now),
+        Ok(datetime_to_secs((2041, 1, 1, 0, 0, 0)))
+    );
+}
+
+quickcheck! {
+    fn quickcheck_format_http_date(s: i64) -> bool {
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let text = http_date(secs);
+        let expected = DateTime::from_timestamp(secs, 0).unwrap().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+        text == expected && parse_http_date(text.as_bytes(), 0) == Ok(secs)
+    }
+
+    fn quickcheck_parse_http_date_formats(s: i64, now: i64) -> bool {
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let now = now.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let dt = DateTime::from_timestamp(secs, 0).unwrap();
+        let asctime = dt.format("%a %b %e %H:%M:%S %Y").to_string();
+        let rfc850 = dt.format("%A, %d-%b-%y %H:%M:%S GMT").to_string();
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(secs);
+        let (ny, _, _, _, _, _) = secs_to_datetime(now);
+        let ry = (ny - 49..=ny + 50).find(|ry| ry.rem_euclid(100) == y.rem_euclid(100)).unwrap();
+        let expected = match validate_date((ry, m, d)) {
+            Ok(()) => Ok(datetime_to_secs((ry, m, d, hh, mm, ss))),
+            Err(_) => Err(ParseError::OutOfRange),
+        };
+        parse_http_date(asctime.as_bytes(), now) == Ok(secs) && parse_http_date(rfc850.as_bytes(), now) == expected
+    }
+
+    fn quickcheck_parse_http_date_garbage(input: Vec<u8>, now: i32) -> bool {
+        common::check_parse(&input, |s| parse_http_date(s, now as i64), |&secs| http_date(secs))
+    }
+
+    fn quickcheck_parse_http_date_mutated(k: usize, pos: usize, byte: u8) -> bool {
+        let dt = DateTime::from_timestamp(WINDOW[k % WINDOW.len()], 0).unwrap();
+        let text = dt.format("%A, %d-%b-%y %H:%M:%S GMT").to_string();
+        common::check_mutated(&text, pos, byte, |s| parse_http_date(s, NOW), |&secs| http_date(secs))
+    }
+}
@@ -0,0 +1,254 @@
+use datealgo::iso8601::*;
+use datealgo::{date_to_isoweekdate, date_to_rd, secs_to_datetime, ParseError, RD_SECONDS_MAX, RD_SECONDS_MIN, YEAR_MAX, YEAR_MIN};
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Timelike};
+use quickcheck::{quickcheck, TestResult};
+
+mod common;
+
+fn rfc3339(dt: (i32, u8, u8, u8, u8, u8, u32), digits: u8, offset: Option<i32>) -> String {
+    let mut out = [0u8; RFC3339_MAX_LEN];
+    let len = format_rfc3339(dt, digits, offset, &mut out).unwrap();
+    String::from_utf8(out[..len].to_vec()).unwrap()
+}
+
+fn date(&dt: &(i32, u8, u8)) -> String {
+    let mut out = [0u8; DATE_MAX_LEN];
+    let len = format_date(dt, &mut out).unwrap();
+    String::from_utf8(out[..len].to_vec()).unwrap()
+}
+
+// RFC 3339 only has years 0 to 9999, so the date is written separately to
+// allow expanded years
+#[allow(clippy::type_complexity)]
+fn datetime(&((y, m, d, hh, mm, ss, nsec), offset): &((i32, u8, u8, u8, u8, u8, u32), Option<i32>)) -> String {
+    let text = rfc3339((2000, 1, 1, hh, mm, ss, nsec), 9, offset);
+    let time = if offset.is_some() {
+        &text[10..]
+    } else {
+        text[10..].trim_end_matches('Z')
+    };
+    date(&(y, m, d)) + time
+}
+
+// Dates at the limits of four digit and expanded years
+const EDGES: [(i32, u8, u8); 6] = [
+    (YEAR_MIN, 1, 1),
+    (-1, 12, 31),
+    (0, 1, 1),
+    (9999, 12, 31),
+    (10000, 1, 1),
+    (YEAR_MAX, 12, 31),
+];
+
+#[test]
+fn test_format_date() {
+    let mut out = [0u8; DATE_MAX_LEN];
+    let len = format_date((0, 1, 1), &mut out).unwrap();
+    assert_eq!(&out[..len], b"0000-01-01");
+    let len = format_date((9999, 12, 31), &mut out).unwrap();
+    assert_eq!(&out[..len], b"9999-12-31");
+    let len = format_date((YEAR_MIN, 1, 1), &mut out).unwrap();
+    assert_eq!(&out[..len], b"-1467999-01-01");
+    let len = format_date((YEAR_MAX, 12, 31), &mut out).unwrap();
+    assert_eq!(len, DATE_MAX_LEN);
+    assert_eq!(&out[..len], b"+1471744-12-31");
+    assert_eq!(format_date((2023, 5, 7), &mut [0u8; 10]), Some(10));
+    assert_eq!(format_date((2023, 5, 7), &mut [0u8; 9]), None);
+    assert_eq!(format_date((-10, 5, 7), &mut [0u8; 10]), None);
+}
+
+#[test]
+fn test_format_rfc3339() {
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 0), 0, None), "1970-01-01T00:00:00Z");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 0), 0, Some(0)), "1970-01-01T00:00:00+00:00");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 1), 9, None), "1970-01-01T00:00:00.000000001Z");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 999_999_999), 1, None), "1970-01-01T00:00:00.9Z");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 999_999_999), 6, None), "1970-01-01T00:00:00.999999Z");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 0), 0, Some(86340)), "1970-01-01T00:00:00+23:59");
+    assert_eq!(rfc3339((1970, 1, 1, 0, 0, 0, 0), 0, Some(-86340)), "1970-01-01T00:00:00-23:59");
+    assert_eq!(
+        rfc3339((YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999), 9, Some(-86340)),
+        "+1471744-12-31T23:59:59.999999999-23:59"
+    );
+    assert_eq!(
+        rfc3339((YEAR_MAX, 12, 31, 23, 59, 59, 999_999_999), 9, Some(-86340)).len(),
+        RFC3339_MAX_LEN
+    );
+    assert_eq!(rfc3339((YEAR_MIN, 1, 1, 0, 0, 0, 0), 3, None), "-1467999-01-01T00:00:00.000Z");
+    assert_eq!(format_rfc3339((2023, 5, 7, 9, 24, 38, 0), 3, Some(0), &mut [0u8; 29]), Some(29));
+    assert_eq!(format_rfc3339((2023, 5, 7, 9, 24, 38, 0), 3, Some(0), &mut [0u8; 28]), None);
+}
+
+#[test]
+fn test_parse_date() {
+    assert_eq!(parse_date(b"0000-01-01"), Ok((0, 1, 1)));
+    assert_eq!(parse_date(b"99991231"), Ok((9999, 12, 31)));
+    assert_eq!(parse_date(b"2024-366"), Ok((2024, 12, 31)));
+    assert_eq!(parse_date(b"2023365"), Ok((2023, 12, 31)));
+    assert_eq!(parse_date(b"2023-366"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"2023-000"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"2020-W53-5"), Ok((2021, 1, 1)));
+    assert_eq!(parse_date(b"2020W535"), Ok((2021, 1, 1)));
+    assert_eq!(parse_date(b"2021-W53-1"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"2021-W01-8"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"+1471744-12-31"), Ok((YEAR_MAX, 12, 31)));
+    assert_eq!(parse_date(b"-1467999-01-01"), Ok((YEAR_MIN, 1, 1)));
+    assert_eq!(parse_date(b"+1471745-01-01"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"+999999999-01-01"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"+1000000000-01-01"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_date(b"+10000-001"), Ok((10000, 1, 1)));
+    assert_eq!(parse_date(b"+100000101"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"+123-01-01"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b""), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023-05"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023-05-07 "), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023-0a-07"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023/05/07"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023-W1-1"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_date(b"2023-13-01"), Err(ParseError::OutOfRange));
+}
+
+#[test]
+fn test_parse_datetime() {
+    assert_eq!(parse_datetime(b"2023-05-07T09"), Ok(((2023, 5, 7, 9, 0, 0, 0), None)));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24"), Ok(((2023, 5, 7, 9, 24, 0, 0), None)));
+    assert_eq!(parse_datetime(b"2023-05-07t092438z"), Ok(((2023, 5, 7, 9, 24, 38, 0), Some(0))));
+    assert_eq!(
+        parse_datetime(b"2023-05-07 09:24:38.1234567891-00:30"),
+        Ok(((2023, 5, 7, 9, 24, 38, 123_456_789), Some(-1800)))
+    );
+    assert_eq!(
+        parse_datetime(b"2023-127T09:24:38+23:59"),
+        Ok(((2023, 5, 7, 9, 24, 38, 0), Some(86340)))
+    );
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38+24:00"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38+05:60"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:60"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_datetime(b"2023-05-07T09:60"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24.5"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38."), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T9"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38+"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38+05:"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07T09:24:38Zx"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-05-07X09:24:38"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_datetime(b"2023-02-29T09:24:38Zx"), Err(ParseError::InvalidFormat));
+}
+
+#[test]
+fn test_parse_rfc3339() {
+    assert_eq!(parse_rfc3339(b"0000-01-01T00:00:00Z"), Ok(((0, 1, 1, 0, 0, 0, 0), 0)));
+    assert_eq!(
+        parse_rfc3339(b"9999-12-31T23:59:59.999999999z"),
+        Ok(((9999, 12, 31, 23, 59, 59, 999_999_999), 0))
+    );
+    assert_eq!(
+        parse_rfc3339(b"2023-05-07t09:24:38.1-00:00"),
+        Ok(((2023, 5, 7, 9, 24, 38, 100_000_000), 0))
+    );
+    assert_eq!(
+        parse_rfc3339(b"2023-05-07T09:24:38-23:59"),
+        Ok(((2023, 5, 7, 9, 24, 38, 0), -86340))
+    );
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38+24:00"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc3339(b"2023-02-29T09:24:38Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc3339(b"2023-05-07T24:00:00Z"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38.Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38+0300"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24:38+03:00 "), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"2023-05-07X09:24:38Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"2023-05-07T09:24:3aZ"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"+2023-05-07T09:24:38Z"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc3339(b"20230507T092438Z"), Err(ParseError::InvalidFormat));
+}
+
+quickcheck! {
+    fn quickcheck_format_rfc3339(s: i64, nsec: u32, digits: u8, offset: i16) -> TestResult {
+        let nsec = nsec % 1_000_000_000;
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(s % (260_000 * 31_556_952));
+        let digits = [0, 3, 6, 9][digits as usize % 4];
+        let offset = offset as i32 % 1440 * 60;
+        let dt = NaiveDate::from_ymd_opt(y, m as u32, d as u32).unwrap().and_hms_nano_opt(hh as u32, mm as u32, ss as u32, nsec).unwrap();
+        let fmt = match digits {
+            0 => "%Y-%m-%dT%H:%M:%S%:z".to_string(),
+            3 => "%Y-%m-%dT%H:%M:%S%.3f%:z".to_string(),
+            6 => "%Y-%m-%dT%H:%M:%S%.6f%:z".to_string(),
+            9 => "%Y-%m-%dT%H:%M:%S%.9f%:z".to_string(),
+            _ => return TestResult::discard(),
+        };
+        let expected = FixedOffset::east_opt(offset).unwrap().from_local_datetime(&dt).unwrap().format(&fmt).to_string();
+        TestResult::from_bool(rfc3339((y, m, d, hh, mm, ss, nsec), digits, Some(offset)) == expected)
+    }
+
+    fn quickcheck_format_rfc3339_digits(nsec: u32, digits: u8) -> TestResult {
+        let nsec = nsec % 1_000_000_000;
+        let digits = digits % 10;
+        let s = rfc3339((2023, 5, 7, 9, 24, 38, nsec), digits, None);
+        let expected = format!("{:09}", nsec);
+        let frac = s.strip_prefix("2023-05-07T09:24:38").unwrap().strip_suffix('Z').unwrap();
+        TestResult::from_bool(match digits {
+            0 => frac.is_empty(),
+            _ => frac.strip_prefix('.') == Some(&expected[..digits as usize]),
+        })
+    }
+
+    fn quickcheck_parse_datetime(s: i64, nsec: u32, digits: u8, offset: i16) -> bool {
+        let nsec = nsec % 1_000_000_000;
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(s.rem_euclid(RD_SECONDS_MAX - RD_SECONDS_MIN + 1) + RD_SECONDS_MIN);
+        let digits = digits % 10;
+        let offset = offset as i32 % 1440 * 60;
+        let text = rfc3339((y, m, d, hh, mm, ss, nsec), digits, Some(offset));
+        let nsec = nsec / 10u32.pow(9 - digits as u32) * 10u32.pow(9 - digits as u32);
+        parse_datetime(text.as_bytes()) == Ok(((y, m, d, hh, mm, ss, nsec), Some(offset)))
+    }
+
+    fn quickcheck_parse_rfc3339(s: u32, nsec: u32, digits: u8, offset: i16) -> bool {
+        let nsec = nsec % 1_000_000_000;
+        let (y, m, d, hh, mm, ss) = secs_to_datetime(s as i64 * 57);
+        let digits = digits % 10;
+        let offset = offset as i32 % 1440 * 60;
+        let text = rfc3339((y, m, d, hh, mm, ss, nsec), digits, Some(offset));
+        let dt = DateTime::parse_from_rfc3339(&text).unwrap();
+        let expected = (
+            (dt.year(), dt.month() as u8, dt.day() as u8, dt.hour() as u8, dt.minute() as u8, dt.second() as u8, dt.nanosecond()),
+            dt.offset().local_minus_utc(),
+        );
+        parse_rfc3339(text.as_bytes()) == Ok(expected)
+    }
+
+    fn quickcheck_parse_date_forms(s: i64) -> bool {
+        let (y, m, d, _, _, _) = secs_to_datetime(s.rem_euclid(RD_SECONDS_MAX - RD_SECONDS_MIN + 1) + RD_SECONDS_MIN);
+        let (wy, w, wd) = date_to_isoweekdate((y, m, d));
+        let ord = date_to_rd((y, m, d)) - date_to_rd((y, 1, 1)) + 1;
+        let sign = |y: i32| if (0..=9999).contains(&y) { format!("{:04}", y) } else { format!("{:+05}", y) };
+        let ok = parse_date(format!("{}-{:02}-{:02}", sign(y), m, d).as_bytes()) == Ok((y, m, d))
+            && parse_date(format!("{}-{:03}", sign(y), ord).as_bytes()) == Ok((y, m, d))
+            && parse_date(format!("{}-W{:02}-{}", sign(wy), w, wd).as_bytes()) == Ok((y, m, d));
+        if !(0..=9999).contains(&y) || !(0..=9999).contains(&wy) {
+            return ok;
+        }
+        ok && parse_date(format!("{:04}{:02}{:02}", y, m, d).as_bytes()) == Ok((y, m, d))
+            && parse_date(format!("{:04}{:03}", y, ord).as_bytes()) == Ok((y, m, d))
+            && parse_date(format!("{:04}W{:02}{}", wy, w, wd).as_bytes()) == Ok((y, m, d))
+    }
+
+    fn quickcheck_parse_garbage(input: Vec<u8>) -> bool {
+        common::check_parse(&input, parse_date, date)
+            && common::check_parse(&input, parse_datetime, datetime)
+            && common::check_parse(&input, parse_rfc3339, |&(dt, offset)| rfc3339(dt, 9, Some(offset)))
+    }
+
+    fn quickcheck_parse_mutated(k: usize, pos: usize, byte: u8) -> bool {
+        let (y, m, d) = EDGES[k % EDGES.len()];
+        let text = datetime(&((y, m, d, 23, 59, 59, 123_456_789), Some(-19800)));
+        let date_len = text.find('T').unwrap();
+        common::check_mutated(&text, pos, byte, parse_datetime, datetime)
+            && common::check_mutated(&text[..date_len], pos, byte, parse_date, date)
+            && (!(0..=9999).contains(&y)
+                || common::check_mutated(&text, pos, byte, parse_rfc3339, |&(dt, offset)| rfc3339(dt, 9, Some(offset))))
+    }
+}
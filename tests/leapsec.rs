@@ -0,0 +1,285 @@
+use datealgo::leapsec::*;
+use datealgo::{date_to_rd, datetime_to_secs, ParseError, RD_SECONDS_MAX, RD_SECONDS_MIN};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_leap_seconds() {
+    assert_eq!(LEAP_SECONDS.len(), 28);
+    assert_eq!(LEAP_SECONDS[0], (63072000, 10));
+    assert_eq!(LEAP_SECONDS[27], (1483228800, 37));
+    for w in LEAP_SECONDS.windows(2) {
+        assert!(w[0].0 < w[1].0);
+        assert_eq!(w[0].1 + 1, w[1].1);
+        assert_eq!(w[1].0 % 86400, 0);
+    }
+    assert!(LEAP_SECONDS_EXPIRES > LEAP_SECONDS[27].0);
+}
+
+#[test]
+fn test_tai_utc_offset() {
+    assert_eq!(tai_utc_offset(RD_SECONDS_MIN, LEAP_SECONDS), 10);
+    assert_eq!(tai_utc_offset(0, LEAP_SECONDS), 10);
+    assert_eq!(tai_utc_offset(datetime_to_secs((1972, 6, 30, 23, 59, 59)), LEAP_SECONDS), 10);
+    assert_eq!(tai_utc_offset(datetime_to_secs((1972, 7, 1, 0, 0, 0)), LEAP_SECONDS), 11);
+    assert_eq!(tai_utc_offset(datetime_to_secs((1999, 1, 1, 0, 0, 0)), LEAP_SECONDS), 32);
+    assert_eq!(tai_utc_offset(RD_SECONDS_MAX, LEAP_SECONDS), 37);
+    assert_eq!(tai_utc_offset(0, &[]), 0);
+    assert_eq!(tai_utc_offset(0, &[(100, 5)]), 5);
+    assert_eq!(tai_utc_offset(0, &[(-100, 5), (0, 6)]), 6);
+    assert_eq!(tai_utc_offset(-1, &[(-100, 5), (0, 6)]), 5);
+}
+
+#[test]
+fn test_utc_secs_to_tai_secs() {
+    assert_eq!(utc_secs_to_tai_secs(0, LEAP_SECONDS), 10);
+    assert_eq!(utc_secs_to_tai_secs(63072000, LEAP_SECONDS), 63072010);
+    assert_eq!(utc_secs_to_tai_secs(78796799, LEAP_SECONDS), 78796809);
+    assert_eq!(utc_secs_to_tai_secs(78796800, LEAP_SECONDS), 78796811);
+    assert_eq!(utc_secs_to_tai_secs(1483228799, LEAP_SECONDS), 1483228835);
+    assert_eq!(utc_secs_to_tai_secs(1483228800, LEAP_SECONDS), 1483228837);
+    assert_eq!(utc_secs_to_tai_secs(0, &[]), 0);
+}
+
+#[test]
+fn test_tai_secs_to_utc_secs() {
+    assert_eq!(tai_secs_to_utc_secs(10, LEAP_SECONDS), 0);
+    assert_eq!(tai_secs_to_utc_secs(63072010, LEAP_SECONDS), 63072000);
+    assert_eq!(tai_secs_to_utc_secs(78796809, LEAP_SECONDS), 78796799);
+    assert_eq!(tai_secs_to_utc_secs(78796810, LEAP_SECONDS), 78796800);
+    assert_eq!(tai_secs_to_utc_secs(78796811, LEAP_SECONDS), 78796800);
+    assert_eq!(tai_secs_to_utc_secs(1483228835, LEAP_SECONDS), 1483228799);
+    assert_eq!(tai_secs_to_utc_secs(1483228836, LEAP_SECONDS), 1483228800);
+    assert_eq!(tai_secs_to_utc_secs(1483228837, LEAP_SECONDS), 1483228800);
+    assert_eq!(tai_secs_to_utc_secs(RD_SECONDS_MIN + 10, LEAP_SECONDS), RD_SECONDS_MIN);
+    assert_eq!(tai_secs_to_utc_secs(RD_SECONDS_MAX + 37, LEAP_SECONDS), RD_SECONDS_MAX);
+    assert_eq!(tai_secs_to_utc_secs(0, &[]), 0);
+}
+
+#[test]
+fn test_is_leap_second_day() {
+    assert!(!is_leap_second_day(date_to_rd((1971, 12, 31)), LEAP_SECONDS));
+    assert!(is_leap_second_day(date_to_rd((1972, 6, 30)), LEAP_SECONDS));
+    assert!(!is_leap_second_day(date_to_rd((1972, 7, 1)), LEAP_SECONDS));
+    assert!(!is_leap_second_day(date_to_rd((1984, 12, 31)), LEAP_SECONDS));
+    assert!(is_leap_second_day(date_to_rd((2016, 12, 31)), LEAP_SECONDS));
+    assert!(!is_leap_second_day(date_to_rd((2023, 12, 31)), LEAP_SECONDS));
+    assert!(!is_leap_second_day(0, &[]));
+    assert!(!is_leap_second_day(0, &[(0, 1), (86400, 1)]));
+    assert!(is_leap_second_day(0, &[(0, 1), (86400, 0)]));
+}
+
+#[test]
+fn test_tai_secs_to_datetime() {
+    assert_eq!(tai_secs_to_datetime(10, LEAP_SECONDS), (1970, 1, 1, 0, 0, 0));
+    assert_eq!(tai_secs_to_datetime(78796809, LEAP_SECONDS), (1972, 6, 30, 23, 59, 59));
+    assert_eq!(tai_secs_to_datetime(78796810, LEAP_SECONDS), (1972, 6, 30, 23, 59, 60));
+    assert_eq!(tai_secs_to_datetime(78796811, LEAP_SECONDS), (1972, 7, 1, 0, 0, 0));
+    assert_eq!(tai_secs_to_datetime(1483228836, LEAP_SECONDS), (2016, 12, 31, 23, 59, 60));
+}
+
+#[test]
+fn test_datetime_to_tai_secs() {
+    assert_eq!(datetime_to_tai_secs((1970, 1, 1, 0, 0, 0), LEAP_SECONDS), 10);
+    assert_eq!(datetime_to_tai_secs((1972, 6, 30, 23, 59, 59), LEAP_SECONDS), 78796809);
+    assert_eq!(datetime_to_tai_secs((1972, 6, 30, 23, 59, 60), LEAP_SECONDS), 78796810);
+    assert_eq!(datetime_to_tai_secs((1972, 7, 1, 0, 0, 0), LEAP_SECONDS), 78796811);
+    assert_eq!(datetime_to_tai_secs((2016, 12, 31, 23, 59, 60), LEAP_SECONDS), 1483228836);
+}
+
+quickcheck! {
+    fn quickcheck_utc_secs_to_tai_secs(s: i64) -> TestResult {
+        if !(RD_SECONDS_MIN..=RD_SECONDS_MAX).contains(&s) {
+            return TestResult::discard();
+        }
+        let tai = utc_secs_to_tai_secs(s, LEAP_SECONDS);
+        TestResult::from_bool(tai_secs_to_utc_secs(tai, LEAP_SECONDS) == s)
+    }
+
+    fn quickcheck_tai_secs_to_datetime(s: u32) -> TestResult {
+        let tai = s as i64;
+        let dt = tai_secs_to_datetime(tai, LEAP_SECONDS);
+        TestResult::from_bool(datetime_to_tai_secs(dt, LEAP_SECONDS) == tai)
+    }
+}
+
+const LEAP_SECONDS_LIST: &[u8] = include_bytes!("data/leap-seconds.list");
+const TZDATA_LEAPSECONDS: &[u8] = include_bytes!("data/leapseconds");
+
+#[test]
+fn test_parse_leap_seconds_list() {
+    let mut table = [(0, 0); 64];
+    let (count, expires) = parse_leap_seconds_list(LEAP_SECONDS_LIST, &mut table).unwrap();
+    assert_eq!(&table[..count], LEAP_SECONDS);
+    assert_eq!(expires, LEAP_SECONDS_EXPIRES);
+    let crlf = String::from_utf8(LEAP_SECONDS_LIST.to_vec()).unwrap().replace('\n', "\r\n");
+    assert_eq!(parse_leap_seconds_list(crlf.as_bytes(), &mut table), Ok((count, expires)));
+}
+
+#[test]
+fn test_parse_leap_seconds_list_errors() {
+    let mut table = [(0, 0); 64];
+    let text = String::from_utf8(LEAP_SECONDS_LIST.to_vec()).unwrap();
+    let modified = text.replace("3692217600      37", "3692217600      38");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::HashMismatch)
+    );
+    let modified = text.replace("#@\t4007404800", "#@\t4007404801");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::HashMismatch)
+    );
+    let modified = text.replace("#h", "# h");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::MissingHash)
+    );
+    let modified = text.replace("#h\t2e101270", "#h\t2e1012x0");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::InvalidFormat)
+    );
+    let modified = text.replace("3692217600      37", "3692217601      37");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let modified = text.replace("3692217600      37", "3692217600      370");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let modified = text.replace("3692217600      37", "3692217600");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::InvalidFormat)
+    );
+    let modified = text.replace("3692217600      37", "3644697600      37");
+    assert_eq!(
+        parse_leap_seconds_list(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let mut small = [(0, 0); 27];
+    assert_eq!(parse_leap_seconds_list(LEAP_SECONDS_LIST, &mut small), Err(ParseError::TableFull));
+    let input = b"#$\t3676924800\n2272060800\t10\n#h\t0 0 0 0 0\n";
+    assert_eq!(parse_leap_seconds_list(input, &mut table), Err(ParseError::HashMismatch));
+    assert_eq!(parse_leap_seconds_list(b"", &mut table), Err(ParseError::MissingHash));
+}
+
+#[test]
+fn test_parse_tzdata_leapseconds() {
+    let mut table = [(0, 0); 64];
+    let (count, expires) = parse_tzdata_leapseconds(TZDATA_LEAPSECONDS, &mut table).unwrap();
+    assert_eq!(&table[..count], LEAP_SECONDS);
+    assert_eq!(expires, LEAP_SECONDS_EXPIRES);
+    let input = b"Leap 1972 Jun 30 23:59:60 + S\nLeap 1972 Dec 31 23:59:59 - R\n#expires 94348800 (1972-12-28)\n";
+    let (count, expires) = parse_tzdata_leapseconds(input, &mut table).unwrap();
+    assert_eq!(&table[..count], &[(63072000, 10), (78796800, 11), (94694400, 10)]);
+    assert_eq!(expires, 94348800);
+    let input = b"#expires 94348800\nExpires 1973 Jun 28 00:00:00\n#expires 94348800\n";
+    assert_eq!(parse_tzdata_leapseconds(input, &mut table), Ok((1, 94348800)));
+    let input = b"#expires 94348800\nExpires 1973 Jun 28 00:00:00\n";
+    assert_eq!(parse_tzdata_leapseconds(input, &mut table), Ok((1, 110073600)));
+}
+
+#[test]
+fn test_parse_tzdata_leapseconds_errors() {
+    let mut table = [(0, 0); 64];
+    let text = String::from_utf8(TZDATA_LEAPSECONDS.to_vec()).unwrap();
+    let modified = text.replace("#Expires", "#").replace("#expires", "#");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::MissingExpiry)
+    );
+    let modified = text.replace("Leap\t2016\tDec\t31\t23:59:60\t+", "Leap\t2016\tDec\t31\t23:59:59\t+");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::InvalidFormat)
+    );
+    let modified = text.replace("Leap\t2016\tDec\t31", "Leap\t2016\tFoo\t31");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::InvalidFormat)
+    );
+    let modified = text.replace("Leap\t2016\tDec\t31", "Leap\t2016\tNov\t31");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let modified = text.replace("Leap\t2016\tDec\t31", "Leap\t2015\tJun\t30");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let modified = text.replace("Leap\t2016\tDec\t31", "Leap\t1471744\tDec\t31");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::OutOfRange)
+    );
+    let modified = text.replace("Leap\t2016", "Leap2016");
+    assert_eq!(
+        parse_tzdata_leapseconds(modified.as_bytes(), &mut table),
+        Err(ParseError::InvalidFormat)
+    );
+    let mut small = [(0, 0); 27];
+    assert_eq!(parse_tzdata_leapseconds(TZDATA_LEAPSECONDS, &mut small), Err(ParseError::TableFull));
+}
+
+#[test]
+fn test_format_leap_seconds_list() {
+    let mut out = [0u8; 4096];
+    let len = format_leap_seconds_list(LEAP_SECONDS, 1767698058, LEAP_SECONDS_EXPIRES, &mut out).unwrap();
+    let text = std::str::from_utf8(&out[..len]).unwrap();
+    assert!(text.starts_with("#$\t3976686858\n#@\t4007404800\n2272060800\t10\t# 1 Jan 1972\n"));
+    assert!(text.ends_with("3692217600\t37\t# 1 Jan 2017\n#h\t2e101270 4e6749f8 2f1792b7 14a0c188 36bb19d6\n"));
+    let mut table = [(0, 0); 64];
+    assert_eq!(
+        parse_leap_seconds_list(&out[..len], &mut table),
+        Ok((LEAP_SECONDS.len(), LEAP_SECONDS_EXPIRES))
+    );
+    assert_eq!(format_leap_seconds_list(LEAP_SECONDS, 0, 0, &mut out[..len - 1]), None);
+}
+
+#[test]
+fn test_format_tzdata_leapseconds() {
+    let mut out = [0u8; 4096];
+    let len = format_tzdata_leapseconds(LEAP_SECONDS, LEAP_SECONDS_EXPIRES, &mut out).unwrap();
+    let text = std::str::from_utf8(&out[..len]).unwrap();
+    assert!(text.starts_with("Leap\t1972\tJun\t30\t23:59:60\t+\tS\n"));
+    assert!(text.ends_with("Leap\t2016\tDec\t31\t23:59:60\t+\tS\nExpires\t2026\tDec\t28\t00:00:00\n"));
+    let mut table = [(0, 0); 64];
+    assert_eq!(
+        parse_tzdata_leapseconds(&out[..len], &mut table),
+        Ok((LEAP_SECONDS.len(), LEAP_SECONDS_EXPIRES))
+    );
+    let negative = [(63072000, 10), (78796800, 9)];
+    let len = format_tzdata_leapseconds(&negative, 94348800, &mut out).unwrap();
+    assert_eq!(
+        &out[..len],
+        b"Leap\t1972\tJun\t30\t23:59:59\t-\tS\nExpires\t1972\tDec\t28\t00:00:00\n"
+    );
+    assert_eq!(format_tzdata_leapseconds(LEAP_SECONDS, 0, &mut out[..100]), None);
+}
+
+#[test]
+fn test_days_until_expiry() {
+    assert_eq!(days_until_expiry(LEAP_SECONDS_EXPIRES, LEAP_SECONDS_EXPIRES - 1), 1);
+    assert_eq!(days_until_expiry(LEAP_SECONDS_EXPIRES, LEAP_SECONDS_EXPIRES), 0);
+    assert_eq!(days_until_expiry(LEAP_SECONDS_EXPIRES, LEAP_SECONDS_EXPIRES + 86399), 0);
+    assert_eq!(days_until_expiry(LEAP_SECONDS_EXPIRES, LEAP_SECONDS_EXPIRES + 86400), -1);
+    assert_eq!(
+        days_until_expiry(datetime_to_secs((2024, 3, 1, 0, 0, 0)), datetime_to_secs((2024, 2, 1, 0, 0, 0))),
+        29
+    );
+    assert_eq!(
+        days_until_expiry(RD_SECONDS_MAX, RD_SECONDS_MIN),
+        date_to_rd((datealgo::YEAR_MAX, 12, 31)) - date_to_rd((datealgo::YEAR_MIN, 1, 1))
+    );
+}
+
+#[test]
+fn test_parse_error_display() {
+    assert_eq!(ParseError::InvalidFormat.to_string(), "invalid format");
+    assert_eq!(ParseError::HashMismatch.to_string(), "hash does not match");
+}
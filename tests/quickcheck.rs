@@ -1,3 +1,4 @@
+use chrono::Datelike;
 use datealgo::*;
 
 use quickcheck::{quickcheck, TestResult};
@@ -74,6 +75,37 @@ quickcheck! {
         TestResult::from_bool(prev_date == expected_date)
     }
 
+    fn quickcheck_next_weekday(d: time::Date, wd: u8) -> TestResult {
+        let wd = wd % 7 + 1;
+        let weekday = time::Weekday::Sunday.nth_next(wd);
+        let Some(expected) = d.checked_add(time::Duration::days(7)).map(|_| d.next_occurrence(weekday)) else {
+            return TestResult::discard();
+        };
+        let rd = date_to_rd((d.year(), d.month() as u8, d.day()));
+        TestResult::from_bool(next_weekday(rd, wd) == expected.to_julian_day() - 2440588)
+    }
+
+    fn quickcheck_prev_weekday(d: time::Date, wd: u8) -> TestResult {
+        let wd = wd % 7 + 1;
+        let weekday = time::Weekday::Sunday.nth_next(wd);
+        let Some(expected) = d.checked_sub(time::Duration::days(7)).map(|_| d.prev_occurrence(weekday)) else {
+            return TestResult::discard();
+        };
+        let rd = date_to_rd((d.year(), d.month() as u8, d.day()));
+        TestResult::from_bool(prev_weekday(rd, wd) == expected.to_julian_day() - 2440588)
+    }
+
+    fn quickcheck_nth_weekday_of_month(d: time::Date, wd: u8, n: u8) -> TestResult {
+        let wd = wd % 7 + 1;
+        let n = n % 5 + 1;
+        let weekday = chrono::Weekday::try_from(wd - 1).unwrap();
+        if chrono::NaiveDate::from_ymd_opt(d.year(), d.month() as u32, 1).is_none() {
+            return TestResult::discard();
+        }
+        let expected = chrono::NaiveDate::from_weekday_of_month_opt(d.year(), d.month() as u32, weekday, n).map(|e| e.day() as u8);
+        TestResult::from_bool(nth_weekday_of_month(d.year(), d.month() as u8, wd, n) == expected)
+    }
+
     fn quickcheck_rd_to_isoweekdate(d: time::Date) -> TestResult {
         let rd = d.to_julian_day() - 2440588;
         let a = rd_to_isoweekdate(rd);
@@ -96,6 +128,115 @@ quickcheck! {
         TestResult::from_bool(weeks_a == weeks_b)
     }
 
+    fn quickcheck_rd_to_ordinal(d: time::Date) -> TestResult {
+        let rd = d.to_julian_day() - 2440588;
+        let a = rd_to_ordinal(rd);
+        TestResult::from_bool(a == d.to_ordinal_date())
+    }
+
+    fn quickcheck_ordinal_to_rd(d: time::Date) -> TestResult {
+        let rd = ordinal_to_rd(d.to_ordinal_date());
+        TestResult::from_bool(rd == d.to_julian_day() - 2440588)
+    }
+
+    fn quickcheck_date_to_ordinal(d: time::Date) -> TestResult {
+        let a = date_to_ordinal((d.year(), d.month() as u8, d.day()));
+        TestResult::from_bool(a == d.to_ordinal_date())
+    }
+
+    fn quickcheck_ordinal_to_date(d: time::Date) -> TestResult {
+        let a = ordinal_to_date(d.to_ordinal_date());
+        TestResult::from_bool(a == (d.year(), d.month() as u8, d.day()))
+    }
+
+    fn quickcheck_days_in_year(y: i32) -> TestResult {
+        if !(datealgo::YEAR_MIN..=datealgo::YEAR_MAX).contains(&y) {
+            return TestResult::discard();
+        }
+        TestResult::from_bool(datealgo::days_in_year(y) == time::util::days_in_year(y))
+    }
+
+    fn quickcheck_add_months(d: time::Date, n: i16) -> TestResult {
+        let Some(nd) = chrono::NaiveDate::from_ymd_opt(d.year(), d.month() as u32, d.day() as u32) else {
+            return TestResult::discard();
+        };
+        let months = chrono::Months::new(n.unsigned_abs() as u32);
+        let expected = if n >= 0 { nd.checked_add_months(months) } else { nd.checked_sub_months(months) };
+        let Some(expected) = expected else {
+            return TestResult::discard();
+        };
+        let a = add_months((d.year(), d.month() as u8, d.day()), n as i32, DayOverflow::Clamp);
+        TestResult::from_bool(a == Some((expected.year(), expected.month() as u8, expected.day() as u8)))
+    }
+
+    fn quickcheck_add_years(d: time::Date, n: i16) -> TestResult {
+        let y = d.year() + n as i32;
+        if y < time::Date::MIN.year() || y > time::Date::MAX.year() {
+            return TestResult::discard();
+        }
+        let expected = d.replace_year(y).ok().map(|e| (e.year(), e.month() as u8, e.day()));
+        let a = add_years((d.year(), d.month() as u8, d.day()), n as i32, DayOverflow::Reject);
+        TestResult::from_bool(a == expected)
+    }
+
+    fn quickcheck_whole_years_between(a: time::Date, b: time::Date) -> TestResult {
+        let (Some(na), Some(nb)) = (
+            chrono::NaiveDate::from_ymd_opt(a.year(), a.month() as u32, a.day() as u32),
+            chrono::NaiveDate::from_ymd_opt(b.year(), b.month() as u32, b.day() as u32),
+        ) else {
+            return TestResult::discard();
+        };
+        // chrono does not count February 29th as reached on February 28th
+        if (a.month() as u8, a.day()) == (2, 29) {
+            return TestResult::discard();
+        }
+        let expected = match nb.years_since(na) {
+            Some(y) => y as i32,
+            None => -(na.years_since(nb).unwrap() as i32),
+        };
+        let years = whole_years_between((a.year(), a.month() as u8, a.day()), (b.year(), b.month() as u8, b.day()));
+        TestResult::from_bool(years == expected)
+    }
+
+    fn quickcheck_rd_to_jdn(d: time::Date) -> TestResult {
+        let rd = date_to_rd((d.year(), d.month() as u8, d.day()));
+        TestResult::from_bool(rd_to_jdn(rd) == d.to_julian_day())
+    }
+
+    fn quickcheck_jdn_to_rd(d: time::Date) -> TestResult {
+        let rd = jdn_to_rd(d.to_julian_day());
+        TestResult::from_bool(rd_to_date(rd) == ({ d.year() }, d.month() as u8, { d.day() }))
+    }
+
+    fn quickcheck_nanos_to_datetime(s: time::PrimitiveDateTime) -> TestResult {
+        let s = s.assume_utc();
+        let a = nanos_to_datetime(s.unix_timestamp_nanos());
+        let b = (
+            s.year(),
+            s.month() as u8,
+            s.day(),
+            s.hour(),
+            s.minute(),
+            s.second(),
+            s.nanosecond(),
+        );
+        TestResult::from_bool(a == b)
+    }
+
+    fn quickcheck_datetime_to_nanos(s: time::PrimitiveDateTime) -> TestResult {
+        let s = s.assume_utc();
+        let dt = (
+            s.year(),
+            s.month() as u8,
+            s.day(),
+            s.hour(),
+            s.minute(),
+            s.second(),
+            s.nanosecond(),
+        );
+        TestResult::from_bool(datetime_to_nanos(dt) == s.unix_timestamp_nanos())
+    }
+
     fn quickcheck_systemtime_to_datetime(s: time::PrimitiveDateTime) -> TestResult {
         let s = s.assume_utc();
         let a = systemtime_to_datetime(s.into()).unwrap();
@@ -0,0 +1,135 @@
+use datealgo::rfc2822::*;
+use datealgo::{datetime_to_secs, secs_to_datetime, ParseError};
+
+use chrono::{DateTime, FixedOffset};
+use quickcheck::quickcheck;
+
+mod common;
+
+const YEAR_1900: i64 = -2208988800;
+const YEAR_9999: i64 = 253402300799;
+
+// Limits of the years the formatter writes, and either side of where two
+// digit years change from the 2000s to the 1900s
+const EDGES: [&str; 4] = [
+    "Mon, 01 Jan 1900 00:00:00 -0530",
+    "Fri, 31 Dec 9999 23:59:59 +0530",
+    "Fri, 31 Dec 49 23:59:59 +0000",
+    "Sun, 01 Jan 50 00:00:00 +0000",
+];
+
+fn rfc2822(secs: i64, offset: i32) -> String {
+    let mut out = [0u8; RFC2822_LEN];
+    format_rfc2822(secs, offset, &mut out);
+    String::from_utf8(out.to_vec()).unwrap()
+}
+
+fn any_year(&(secs, offset): &(i64, i32)) -> String {
+    let local = secs + offset as i64;
+    if (YEAR_1900..=YEAR_9999).contains(&local) {
+        return rfc2822(secs, offset);
+    }
+    // the parser accepts years that the formatter does not write
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let (y, m, d, hh, mm, ss) = secs_to_datetime(local);
+    let sign = if offset < 0 { '-' } else { '+' };
+    let o = offset.unsigned_abs() / 60;
+    format!(
+        "{} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        d,
+        MONTHS[m as usize - 1],
+        y,
+        hh,
+        mm,
+        ss,
+        sign,
+        o / 60,
+        o % 60
+    )
+}
+
+#[test]
+fn test_format_rfc2822() {
+    assert_eq!(rfc2822(YEAR_1900, 0), "Mon, 01 Jan 1900 00:00:00 +0000");
+    assert_eq!(rfc2822(YEAR_9999, 0), "Fri, 31 Dec 9999 23:59:59 +0000");
+    assert_eq!(rfc2822(0, 86340), "Thu, 01 Jan 1970 23:59:00 +2359");
+    assert_eq!(rfc2822(0, -60), "Wed, 31 Dec 1969 23:59:00 -0001");
+    assert_eq!(rfc2822(86400, -86340), "Thu, 01 Jan 1970 00:01:00 -2359");
+}
+
+#[test]
+fn test_parse_rfc2822() {
+    let secs = datetime_to_secs((1997, 11, 21, 9, 55, 6));
+    assert_eq!(parse_rfc2822(b"21 Nov 1997 09:55:06 +0000"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"  fri, 21 NOV 1997 09:55:06 gmt  "), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri , 21 Nov 1997 09 : 55 : 06 UT"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 097 09:55:06 Z"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 09:55:06 A"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 09:55:06 CEST"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 09:55:06 -0000"), Ok((secs, 0)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 04:55:06 EST"), Ok((secs, -18000)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 03:55:06 CST"), Ok((secs, -21600)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 01:55:06 PST"), Ok((secs, -28800)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 02:55:06 PDT"), Ok((secs, -25200)));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Nov 1997 11:25:06 +0130"), Ok((secs, 5400)));
+    assert_eq!(
+        parse_rfc2822(b"(Fri)Fri,(x(y)\\)z)21(1)Nov(2)1997(3)09:55:06(4)+0000(5)"),
+        Ok((secs, 0))
+    );
+    assert_eq!(
+        parse_rfc2822(b"Sat, 1 Jan 00 00:00 +0000"),
+        Ok((datetime_to_secs((2000, 1, 1, 0, 0, 0)), 0))
+    );
+    assert_eq!(parse_rfc2822(b"Sat, 1 Jan 49 00:00 +0000"), Err(ParseError::WeekdayMismatch));
+    assert_eq!(
+        parse_rfc2822(b"1 Jan 49 00:00 +0000"),
+        Ok((datetime_to_secs((2049, 1, 1, 0, 0, 0)), 0))
+    );
+    assert_eq!(
+        parse_rfc2822(b"1 Jan 50 00:00 +0000"),
+        Ok((datetime_to_secs((1950, 1, 1, 0, 0, 0)), 0))
+    );
+    assert_eq!(
+        parse_rfc2822(b"1 Jan 10000 00:00 +0000"),
+        Ok((datetime_to_secs((10000, 1, 1, 0, 0, 0)), 0))
+    );
+    assert_eq!(parse_rfc2822(b"1 Jan 123456789 00:00 +0000"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc2822(b"29 Feb 1900 00:00 +0000"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 24:00 +0000"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 23:59:60 +0000"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00 +2400"), Err(ParseError::OutOfRange));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00 +000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00 +00000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 0:00 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00 +0000 x"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2000 00:00 +0000 (x"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"1 Jan 2 00:00 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"Fri 21 Nov 1997 09:55:06 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"Fry, 21 Nov 1997 09:55:06 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b"Fri, 21 Now 1997 09:55:06 +0000"), Err(ParseError::InvalidFormat));
+    assert_eq!(parse_rfc2822(b""), Err(ParseError::InvalidFormat));
+    assert_eq!(ParseError::WeekdayMismatch.to_string(), "day of week does not match date");
+}
+
+quickcheck! {
+    fn quickcheck_format_rfc2822(s: i64, offset: i16) -> bool {
+        let offset = offset as i32 % 1440 * 60;
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_1900 - 2 * 86400 + 1) + YEAR_1900 + 86400;
+        let text = rfc2822(secs, offset);
+        let dt = DateTime::from_timestamp(secs, 0).unwrap().with_timezone(&FixedOffset::east_opt(offset).unwrap());
+        let parsed = DateTime::parse_from_rfc2822(&text).unwrap();
+        text == dt.format("%a, %d %b %Y %H:%M:%S %z").to_string()
+            && parsed == dt
+            && parse_rfc2822(text.as_bytes()) == Ok((secs, offset))
+    }
+
+    fn quickcheck_parse_rfc2822_garbage(input: Vec<u8>) -> bool {
+        common::check_parse(&input, parse_rfc2822, any_year)
+    }
+
+    fn quickcheck_parse_rfc2822_mutated(k: usize, pos: usize, byte: u8) -> bool {
+        common::check_mutated(EDGES[k % EDGES.len()], pos, byte, parse_rfc2822, any_year)
+    }
+}
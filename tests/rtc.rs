@@ -0,0 +1,159 @@
+use datealgo::rtc::*;
+use datealgo::{date_to_weekday, DateError};
+
+use quickcheck::{quickcheck, TestResult};
+
+#[test]
+fn test_bcd() {
+    assert_eq!(bcd_to_bin(0x99), Some(99));
+    assert_eq!(bcd_to_bin(0xa0), None);
+    assert_eq!(bcd_to_bin(0x0a), None);
+    assert_eq!(bin_to_bcd(99), 0x99);
+    for v in 0..=99 {
+        assert_eq!(bcd_to_bin(bin_to_bcd(v)), Some(v));
+    }
+}
+
+#[test]
+fn test_ds3231() {
+    assert_eq!(
+        ds3231_to_datetime([0x59, 0x59, 0x23, 0x07, 0x31, 0x92, 0x99]),
+        Ok((2199, 12, 31, 23, 59, 59))
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x52, 0x01, 0x01, 0x01, 0x00]),
+        Ok((2000, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x72, 0x01, 0x01, 0x01, 0x00]),
+        Ok((2000, 1, 1, 12, 0, 0))
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x80, 0x00, 0x00, 0x01, 0x29, 0x02, 0x24]),
+        Ok((2024, 2, 29, 0, 0, 0))
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0xa0]),
+        Err(DateError::YearOutOfRange)
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00]),
+        Err(DateError::MonthOutOfRange)
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x00, 0x01, 0x1a, 0x01, 0x00]),
+        Err(DateError::DayOutOfRange { max: 31 })
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x24, 0x01, 0x01, 0x01, 0x00]),
+        Err(DateError::HourOutOfRange)
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x00, 0x40, 0x01, 0x01, 0x01, 0x00]),
+        Err(DateError::HourOutOfRange)
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x00, 0x60, 0x00, 0x01, 0x01, 0x01, 0x00]),
+        Err(DateError::MinuteOutOfRange)
+    );
+    assert_eq!(
+        ds3231_to_datetime([0x5f, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00]),
+        Err(DateError::SecondOutOfRange)
+    );
+    assert_eq!(
+        datetime_to_ds3231((2199, 12, 31, 23, 59, 59)),
+        [0x59, 0x59, 0x23, 0x02, 0x31, 0x92, 0x99]
+    );
+}
+
+#[test]
+fn test_pcf8563() {
+    assert_eq!(
+        pcf8563_to_datetime([0x59, 0x59, 0x23, 0x31, 0x02, 0x92, 0x99]),
+        Ok((2199, 12, 31, 23, 59, 59))
+    );
+    assert_eq!(
+        pcf8563_to_datetime([0x00, 0x00, 0x00, 0x29, 0x04, 0x02, 0x00]),
+        Ok((2000, 2, 29, 0, 0, 0))
+    );
+    assert_eq!(
+        pcf8563_to_datetime([0x00, 0x00, 0x00, 0x29, 0x00, 0x82, 0x00]),
+        Err(DateError::DayOutOfRange { max: 28 })
+    );
+    assert_eq!(
+        pcf8563_to_datetime([0x00, 0x00, 0x24, 0x01, 0x00, 0x01, 0x00]),
+        Err(DateError::HourOutOfRange)
+    );
+    assert_eq!(
+        datetime_to_pcf8563((2000, 2, 29, 0, 0, 0)),
+        [0x00, 0x00, 0x00, 0x29, 0x02, 0x02, 0x00]
+    );
+    assert_eq!(datetime_to_pcf8563((2023, 5, 6, 0, 0, 0))[4], 6);
+}
+
+#[test]
+fn test_mc146818() {
+    assert_eq!(
+        mc146818_to_datetime([0x00, 0x00, 0x12, 0x01, 0x01, 0x01, 0x70], 0x19, 0),
+        Ok((1970, 1, 1, 0, 0, 0))
+    );
+    assert_eq!(
+        mc146818_to_datetime([0x00, 0x00, 0x92, 0x01, 0x01, 0x01, 0x70], 0x19, 0),
+        Ok((1970, 1, 1, 12, 0, 0))
+    );
+    assert_eq!(
+        mc146818_to_datetime([0x00, 0x00, 0x93, 0x01, 0x01, 0x01, 0x70], 0x19, 0),
+        Err(DateError::HourOutOfRange)
+    );
+    assert_eq!(
+        mc146818_to_datetime([0, 0, 0, 1, 1, 1, 100], 19, MC146818_BINARY),
+        Err(DateError::YearOutOfRange)
+    );
+    assert_eq!(
+        mc146818_to_datetime([0, 0, 0, 1, 1, 1, 0], 100, MC146818_BINARY),
+        Err(DateError::YearOutOfRange)
+    );
+    assert_eq!(
+        mc146818_to_datetime([0, 0, 0x8c, 1, 1, 1, 0], 20, MC146818_BINARY),
+        Ok((2000, 1, 1, 12, 0, 0))
+    );
+    assert_eq!(
+        mc146818_to_datetime([0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00], 0x1a, MC146818_24H),
+        Err(DateError::YearOutOfRange)
+    );
+    assert_eq!(
+        datetime_to_mc146818((1970, 1, 1, 0, 0, 0), 0),
+        ([0x00, 0x00, 0x12, 0x05, 0x01, 0x01, 0x70], 0x19)
+    );
+    assert_eq!(
+        datetime_to_mc146818((1970, 1, 1, 12, 0, 0), MC146818_BINARY),
+        ([0, 0, 0x8c, 5, 1, 1, 70], 19)
+    );
+}
+
+quickcheck! {
+    fn quickcheck_ds3231(regs: (u8, u8, u8, u8, u8, u8, u8)) -> TestResult {
+        let (a, b, c, d, e, f, g) = regs;
+        match ds3231_to_datetime([a, b, c, d, e, f, g]) {
+            Ok(dt) => {
+                let out = datetime_to_ds3231(dt);
+                TestResult::from_bool(ds3231_to_datetime(out) == Ok(dt) && out[3] == date_to_weekday((dt.0, dt.1, dt.2)))
+            }
+            Err(_) => TestResult::discard(),
+        }
+    }
+
+    fn quickcheck_pcf8563(s: u32) -> bool {
+        let secs = datealgo::datetime_to_secs((2000, 1, 1, 0, 0, 0)) + (s as i64 % (200 * 365 * 86400));
+        let dt = datealgo::secs_to_datetime(secs);
+        let regs = datetime_to_pcf8563(dt);
+        pcf8563_to_datetime(regs) == Ok(dt) && regs[4] == date_to_weekday((dt.0, dt.1, dt.2)) % 7
+    }
+
+    fn quickcheck_mc146818(s: u32, status_b: u8) -> bool {
+        let secs = datealgo::datetime_to_secs((1900, 1, 1, 0, 0, 0)) + s as i64 * 2;
+        let dt = datealgo::secs_to_datetime(secs);
+        let (regs, century) = datetime_to_mc146818(dt, status_b);
+        mc146818_to_datetime(regs, century, status_b) == Ok(dt)
+    }
+}
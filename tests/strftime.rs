@@ -0,0 +1,185 @@
+use datealgo::strftime::*;
+use datealgo::{datetime_to_secs, secs_to_datetime, ParseError, YEAR_MAX, YEAR_MIN};
+
+use chrono::DateTime;
+use quickcheck::quickcheck;
+
+mod common;
+
+const YEAR_0: i64 = -62167219200;
+const YEAR_9999: i64 = 253402300799;
+
+const ALL: &str = "%Y %C %y %m %b %B %d %e %j %a %A %u %w %G %V %U %W %H %M %S %s %%";
+
+// %C and %y are only read back for years 0 to 9999, and %s would take
+// precedence over all the other fields
+const FIELDS: &str = "%Y %m %b %B %d %e %j %a %A %u %w %G %V %U %W %H %M %S %%";
+
+// Dates where years need a sign or more than four digits
+const EDGES: [(i32, u8, u8, u8, u8, u8); 6] = [
+    (YEAR_MIN, 1, 1, 0, 0, 0),
+    (-1, 12, 31, 23, 59, 59),
+    (0, 1, 1, 0, 0, 0),
+    (9999, 12, 31, 23, 59, 59),
+    (10000, 1, 1, 0, 0, 0),
+    (YEAR_MAX, 12, 31, 23, 59, 59),
+];
+
+fn format(fmt: &str, dt: (i32, u8, u8, u8, u8, u8)) -> String {
+    let mut s = String::new();
+    strftime(fmt, dt, &mut s).unwrap();
+    s
+}
+
+#[test]
+fn test_strftime() {
+    let dt = (2023, 5, 7, 9, 4, 8);
+    assert_eq!(
+        format(ALL, dt),
+        "2023 20 23 05 May May 07  7 127 Sun Sunday 7 0 2023 18 19 18 09 04 08 1683450248 %"
+    );
+    assert_eq!(format("%-m/%-d %-H:%-M:%-S %-j %-e", dt), "5/7 9:4:8 127 7");
+    assert_eq!(format("%_m/%_d %_H:%_j %_Y", dt), " 5/ 7  9:127 2023");
+    assert_eq!(format("%0e %0s", dt), "07 1683450248");
+    assert_eq!(format("", dt), "");
+    assert_eq!(format("no specs", dt), "no specs");
+    assert_eq!(format("ä%Yö", dt), "ä2023ö");
+    assert_eq!(format("%Y %C %y %G", (-5, 1, 1, 0, 0, 0)), "-0005 -01 95 -0006");
+    assert_eq!(format("%_Y %-Y", (-5, 1, 1, 0, 0, 0)), "  -5 -5");
+    assert_eq!(format("%Y %C %y", (12345, 1, 1, 0, 0, 0)), "+12345 123 45");
+    assert_eq!(format("%Y %_Y %-G", (10000, 1, 3, 0, 0, 0)), "+10000 +10000 +10000");
+    assert_eq!(format("%Y %G", (9999, 12, 31, 0, 0, 0)), "9999 9999");
+    assert_eq!(format("%G-W%V-%u %U %W %j", (2021, 1, 1, 0, 0, 0)), "2020-W53-5 00 00 001");
+    assert_eq!(format("%G-W%V-%u %U %W %j", (2024, 12, 31, 0, 0, 0)), "2025-W01-2 52 53 366");
+    assert_eq!(format("%s", (1969, 12, 31, 23, 59, 59)), "-1");
+    let mut s = String::new();
+    assert!(strftime("%Q", dt, &mut s).is_err());
+    assert!(strftime("%", dt, &mut s).is_err());
+    assert!(strftime("%-", dt, &mut s).is_err());
+}
+
+#[test]
+fn test_strftime_to_slice() {
+    let dt = (2023, 5, 7, 9, 4, 8);
+    let mut out = [0u8; 10];
+    assert_eq!(strftime_to_slice("%Y-%m-%d", dt, &mut out), Some(10));
+    assert_eq!(&out, b"2023-05-07");
+    assert_eq!(strftime_to_slice("%Y-%m-%dT", dt, &mut out), None);
+    assert_eq!(strftime_to_slice("%B %d", dt, &mut out), Some(6));
+    assert_eq!(&out[..6], b"May 07");
+    assert_eq!(strftime_to_slice("%x", dt, &mut out), None);
+}
+
+#[test]
+fn test_strptime() {
+    let dt = Ok((2023, 5, 7, 9, 4, 8));
+    assert_eq!(strptime(b"2023-05-07 09:04:08", "%Y-%m-%d %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023-5-7   9:4:8", "%Y-%m-%d %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023-05-0709:04:08", "%Y-%m-%d %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023-05- 7 09:04:08", "%-Y-%_m-%e %0H:%M:%S"), dt);
+    assert_eq!(strptime(b"20230507090408", "%Y%m%d%H%M%S"), dt);
+    assert_eq!(strptime(b"sunday, may 7 2023 09:04:08", "%A, %B %d %Y %H:%M:%S"), dt);
+    assert_eq!(strptime(b"SUN MAY 7 2023 09:04:08", "%a %b %d %Y %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023 127 09:04:08", "%Y %j %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023-W18-7 09:04:08", "%G-W%V-%u %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023 19 0 09:04:08", "%Y %U %w %H:%M:%S"), dt);
+    assert_eq!(strptime(b"2023 18 7 09:04:08", "%Y %W %u %H:%M:%S"), dt);
+    assert_eq!(strptime(b"1683450248", "%s"), dt);
+    assert_eq!(strptime(b"-1", "%s"), Ok((1969, 12, 31, 23, 59, 59)));
+    assert_eq!(strptime(b"100%", "%Y%%"), Ok((100, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"20 23 05 07", "%C %y %m %d"), Ok((2023, 5, 7, 0, 0, 0)));
+    assert_eq!(strptime(b"20", "%C"), Ok((2000, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"68", "%y"), Ok((2068, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"69", "%y"), Ok((1969, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"2023-05", "%Y-%m"), Ok((2023, 5, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"-0005", "%Y"), Ok((-5, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"+12345-01-01", "%Y-%m-%d"), Ok((12345, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"  -5", "%_Y"), Ok((-5, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"2020-W53", "%G-W%V"), Ok((2020, 12, 28, 0, 0, 0)));
+    assert_eq!(strptime(b"2021 0 5", "%Y %W %u"), Ok((2021, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"2021 0 5", "%Y %U %u"), Ok((2021, 1, 1, 0, 0, 0)));
+    assert_eq!(strptime(b"2024 53 2", "%Y %W %u"), Ok((2024, 12, 31, 0, 0, 0)));
+    assert_eq!(strptime(b"Mon 2023-05-07", "%a %Y-%m-%d"), Err(ParseError::WeekdayMismatch));
+    assert_eq!(strptime(b"Mon 2023 127", "%a %Y %j"), Err(ParseError::WeekdayMismatch));
+    assert_eq!(strptime(b"2023-02-29", "%Y-%m-%d"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023-13-01", "%Y-%m-%d"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 366", "%Y %j"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 0", "%Y %j"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023-W53", "%G-W%V"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2021 0 4", "%Y %W %u"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2024 53 3", "%Y %W %u"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 54", "%Y %U"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 8", "%Y %u"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 7", "%Y %w"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 24:00", "%Y %H:%M"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"2023 23:59:60", "%Y %H:%M:%S"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"+9999999", "%Y"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"999999999999999999", "%s"), Err(ParseError::OutOfRange));
+    assert_eq!(strptime(b"05-07", "%m-%d"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"09:04", "%H:%M"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023-05-07x", "%Y-%m-%d"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023/05/07", "%Y-%m-%d"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023-05-", "%Y-%m-%d"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023 Mai", "%Y %b"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023 %", "%Y %Q"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023 x", "%Y %%"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"2023", "%Y %"), Err(ParseError::InvalidFormat));
+    assert_eq!(strptime(b"", ""), Err(ParseError::InvalidFormat));
+}
+
+#[test]
+fn test_strptime_years() {
+    let formats = ["%Y-%m-%d %H:%M:%S", "%_Y %j", "%-Y %U %w", "%G-W%V-%u", FIELDS];
+    for dt in [
+        (10000, 1, 1, 0, 0, 0),
+        (YEAR_MAX, 12, 31, 0, 0, 0),
+        (-1, 12, 31, 0, 0, 0),
+        (YEAR_MIN, 1, 1, 0, 0, 0),
+    ] {
+        for fmt in formats {
+            assert_eq!(strptime(format(fmt, dt).as_bytes(), fmt), Ok(dt), "{fmt} {dt:?}");
+        }
+    }
+    assert_eq!(format("%Y-%m-%d", (YEAR_MAX, 12, 31, 0, 0, 0)), "+1471744-12-31");
+    assert_eq!(format("%Y-%m-%d", (YEAR_MIN, 1, 1, 0, 0, 0)), "-1467999-01-01");
+    assert_eq!(format("%Y-%m-%d", (-1, 12, 31, 0, 0, 0)), "-0001-12-31");
+}
+
+quickcheck! {
+    fn quickcheck_strftime(s: i64) -> bool {
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let dt = secs_to_datetime(secs);
+        let expected = DateTime::from_timestamp(secs, 0).unwrap();
+        let flags = "%-d %_m %0e %-j %_H";
+        format(ALL, dt) == expected.format(ALL).to_string() && format(flags, dt) == expected.format(flags).to_string()
+    }
+
+    fn quickcheck_strptime(s: i64) -> bool {
+        let secs = s.rem_euclid(YEAR_9999 - YEAR_0 + 1) + YEAR_0;
+        let dt = secs_to_datetime(secs);
+        let formats = [
+            "%Y-%m-%d %H:%M:%S",
+            "%a %b %e %H:%M:%S %Y",
+            "%A, %d %B %Y %H:%M:%S",
+            "%Y.%j %H:%M:%S",
+            "%G-W%V-%u %H:%M:%S",
+            "%Y %U %w %H:%M:%S",
+            "%Y %W %u %H:%M:%S",
+            "%C%y%m%d%H%M%S",
+            "%s",
+            ALL,
+        ];
+        formats.iter().all(|fmt| strptime(format(fmt, dt).as_bytes(), fmt) == Ok(dt))
+            && datetime_to_secs(dt) == secs
+    }
+
+    fn quickcheck_strptime_garbage(input: Vec<u8>, fmt: String) -> bool {
+        let _ = strptime(&input, &fmt);
+        common::check_parse(&input, |s| strptime(s, FIELDS), |&dt| format(FIELDS, dt))
+    }
+
+    fn quickcheck_strptime_mutated(k: usize, pos: usize, byte: u8) -> bool {
+        let text = format(FIELDS, EDGES[k % EDGES.len()]);
+        common::check_mutated(&text, pos, byte, |s| strptime(s, FIELDS), |&dt| format(FIELDS, dt))
+    }
+}